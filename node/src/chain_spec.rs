@@ -19,13 +19,12 @@
                                // Example:  voting_period: 1 * DAY
 
 use node_runtime::{
-    versioned_store::InputValidationLengthConstraint as VsInputValidation, ActorsConfig,
-    AuthorityDiscoveryConfig, BabeConfig, Balance, BalancesConfig, ContentWorkingGroupConfig,
-    CouncilConfig, CouncilElectionConfig, DataObjectStorageRegistryConfig,
-    DataObjectTypeRegistryConfig, ElectionParameters, GrandpaConfig, ImOnlineConfig, IndicesConfig,
-    MembersConfig, MigrationConfig, Perbill, ProposalsCodexConfig, SessionConfig, SessionKeys,
-    Signature, StakerStatus, StakingConfig, SudoConfig, SystemConfig, VersionedStoreConfig, DAYS,
-    WASM_BINARY,
+    ActorsConfig, AuthorityDiscoveryConfig, BabeConfig, Balance, BalancesConfig,
+    ContentWorkingGroupConfig, CouncilConfig, CouncilElectionConfig,
+    DataObjectStorageRegistryConfig, DataObjectTypeRegistryConfig, ElectionParameters,
+    GrandpaConfig, ImOnlineConfig, IndicesConfig, MembersConfig, MigrationConfig, Perbill,
+    ProposalsCodexConfig, SessionConfig, SessionKeys, Signature, StakerStatus, StakingConfig,
+    SudoConfig, SystemConfig, DAYS, WASM_BINARY,
 };
 pub use node_runtime::{AccountId, GenesisConfig};
 use primitives::{sr25519, Pair, Public};
@@ -157,10 +156,6 @@ impl Alternative {
     }
 }
 
-fn new_vs_validation(min: u16, max_min_diff: u16) -> VsInputValidation {
-    VsInputValidation { min, max_min_diff }
-}
-
 pub fn chain_spec_properties() -> json::map::Map<String, json::Value> {
     let mut properties: json::map::Map<String, json::Value> = json::map::Map::new();
     properties.insert(
@@ -268,16 +263,10 @@ pub fn testnet_genesis(
             enable_storage_role: true,
             request_life_time: 300,
         }),
-        versioned_store: Some(VersionedStoreConfig {
-            class_by_id: vec![],
-            entity_by_id: vec![],
-            next_class_id: 1,
-            next_entity_id: 1,
-            property_name_constraint: new_vs_validation(1, 99),
-            property_description_constraint: new_vs_validation(1, 999),
-            class_name_constraint: new_vs_validation(1, 99),
-            class_description_constraint: new_vs_validation(1, 999),
-        }),
+        versioned_store: Some(crate::versioned_store_config::create_versioned_store()),
+        versioned_store_permissions: Some(
+            crate::versioned_store_config::create_versioned_store_permissions(),
+        ),
         content_wg: Some(ContentWorkingGroupConfig {
             mint_capacity: 100_000,
             curator_opening_by_id: vec![],
@@ -332,6 +321,12 @@ pub fn testnet_genesis(
                 .set_storage_role_parameters_proposal_voting_period,
             set_storage_role_parameters_proposal_grace_period: cpcp
                 .set_storage_role_parameters_proposal_grace_period,
+            set_storage_provider_reward_rate_proposal_voting_period: cpcp
+                .set_storage_provider_reward_rate_proposal_voting_period,
+            set_storage_provider_reward_rate_proposal_grace_period: cpcp
+                .set_storage_provider_reward_rate_proposal_grace_period,
+            slash_appeal_proposal_voting_period: cpcp.slash_appeal_proposal_voting_period,
+            slash_appeal_proposal_grace_period: cpcp.slash_appeal_proposal_grace_period,
         }),
     }
 }
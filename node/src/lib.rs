@@ -3,3 +3,4 @@ pub mod cli;
 pub mod forum_config;
 pub mod members_config;
 pub mod service;
+pub mod versioned_store_config;
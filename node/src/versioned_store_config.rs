@@ -0,0 +1,80 @@
+#![allow(clippy::type_complexity)]
+
+use node_runtime::{
+    versioned_store::{Class, ClassId, Entity, EntityId, InputValidationLengthConstraint},
+    versioned_store_permissions::{ClassPermissionsType, CredentialSet},
+    Credential, Runtime, VersionedStoreConfig, VersionedStorePermissionsConfig,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Result;
+
+/// Snapshot of the versioned store and its permissions. Exported from a chain's state
+/// with the `versioned-store-snapshot` tool in `utils/`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct VersionedStoreData {
+    pub classes: Vec<(ClassId, Class)>,
+    pub entities: Vec<(EntityId, Entity)>,
+    pub class_permissions: Vec<(ClassId, ClassPermissionsType<Runtime>)>,
+    pub entity_maintainers: Vec<(EntityId, Credential)>,
+    pub entity_co_maintainers: Vec<(EntityId, CredentialSet<Credential>)>,
+}
+
+fn parse_versioned_store_json() -> Result<VersionedStoreData> {
+    let data = include_str!("../res/versioned_store_data.json");
+    serde_json::from_str(data)
+}
+
+pub fn new_validation(min: u16, max_min_diff: u16) -> InputValidationLengthConstraint {
+    InputValidationLengthConstraint { min, max_min_diff }
+}
+
+/// Genesis config of the versioned store holding the snapshot. The snapshot is not necessarily
+/// ordered by id, so the next ids follow the highest ids in use.
+pub fn versioned_store_config_from(data: VersionedStoreData) -> VersionedStoreConfig {
+    let next_class_id: ClassId = data
+        .classes
+        .iter()
+        .map(|(class_id, _)| *class_id)
+        .max()
+        .map_or(1, |class_id| class_id + 1);
+    let next_entity_id: EntityId = data
+        .entities
+        .iter()
+        .map(|(entity_id, _)| *entity_id)
+        .max()
+        .map_or(1, |entity_id| entity_id + 1);
+
+    VersionedStoreConfig {
+        class_by_id: data.classes,
+        entity_by_id: data.entities,
+        next_class_id,
+        next_entity_id,
+        property_name_constraint: new_validation(1, 99),
+        property_description_constraint: new_validation(1, 999),
+        class_name_constraint: new_validation(1, 99),
+        class_description_constraint: new_validation(1, 999),
+    }
+}
+
+/// Genesis config of the versioned store permissions holding the snapshot.
+pub fn versioned_store_permissions_config_from(
+    data: VersionedStoreData,
+) -> VersionedStorePermissionsConfig {
+    VersionedStorePermissionsConfig {
+        class_permissions_by_class_id: data.class_permissions,
+        entity_maintainer_by_entity_id: data.entity_maintainers,
+        entity_co_maintainers_by_entity_id: data.entity_co_maintainers,
+    }
+}
+
+pub fn create_versioned_store() -> VersionedStoreConfig {
+    versioned_store_config_from(
+        parse_versioned_store_json().expect("failed loading versioned store data"),
+    )
+}
+
+pub fn create_versioned_store_permissions() -> VersionedStorePermissionsConfig {
+    versioned_store_permissions_config_from(
+        parse_versioned_store_json().expect("failed loading versioned store data"),
+    )
+}
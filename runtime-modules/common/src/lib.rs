@@ -3,3 +3,4 @@
 
 pub mod currency;
 pub mod origin_validator;
+pub mod storage_migration;
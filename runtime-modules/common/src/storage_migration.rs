@@ -0,0 +1,84 @@
+use codec::{Decode, Encode, FullCodec};
+use srml_support::storage::{generator, unhashed};
+
+/// Re-encodes the value stored under the final storage `key` from the `Old` layout to the
+/// `New` layout. Bytes stored after the value, like the linkage of linked map entries, are kept.
+/// Returns false, leaving storage untouched, if nothing is stored under the key or the value
+/// does not decode as the `Old` layout.
+pub fn translate_value<Old, New, F>(key: &[u8], translate: F) -> bool
+where
+    Old: Decode,
+    New: Encode,
+    F: FnOnce(Old) -> New,
+{
+    let raw = match unhashed::get_raw(key) {
+        Some(raw) => raw,
+        None => return false,
+    };
+
+    let mut remaining = &raw[..];
+
+    let old = match Old::decode(&mut remaining) {
+        Ok(old) => old,
+        Err(_) => return false,
+    };
+
+    let mut translated = translate(old).encode();
+    translated.extend_from_slice(remaining);
+
+    unhashed::put_raw(key, &translated);
+
+    true
+}
+
+/// Translates the value of an entry of the `Map` storage map, see `translate_value`.
+pub fn translate_map_value<Map, K, V, Old, New, F>(key: &K, translate: F) -> bool
+where
+    Map: generator::StorageMap<K, V>,
+    K: FullCodec,
+    V: FullCodec,
+    Old: Decode,
+    New: Encode,
+    F: FnOnce(Old) -> New,
+{
+    translate_value(Map::storage_map_final_key(key).as_ref(), translate)
+}
+
+/// Translates the value of an entry of the `Map` storage linked map, keeping its linkage,
+/// see `translate_value`.
+pub fn translate_linked_map_value<Map, K, V, Old, New, F>(key: &K, translate: F) -> bool
+where
+    Map: generator::StorageLinkedMap<K, V>,
+    K: FullCodec,
+    V: FullCodec,
+    Old: Decode,
+    New: Encode,
+    F: FnOnce(Old) -> New,
+{
+    translate_value(Map::storage_linked_map_final_key(key).as_ref(), translate)
+}
+
+/// Stores `value`, encoded in whatever layout it has, under `key` of the `Map` storage map.
+/// Allows setting up values stored in a previous layout, to test their migration.
+pub fn put_map_value<Map, K, V, Value>(key: &K, value: &Value)
+where
+    Map: generator::StorageMap<K, V>,
+    K: FullCodec,
+    V: FullCodec,
+    Value: Encode,
+{
+    unhashed::put(Map::storage_map_final_key(key).as_ref(), value);
+}
+
+/// Replaces the value of an existing entry of the `Map` storage linked map with `value`, encoded
+/// in whatever layout it has, keeping the linkage of the entry. Allows setting up values stored
+/// in a previous layout, to test their migration.
+pub fn replace_linked_map_value<Map, K, V, Value>(key: &K, value: Value) -> bool
+where
+    Map: generator::StorageLinkedMap<K, V>,
+    K: FullCodec,
+    V: FullCodec,
+    Value: Encode,
+{
+    translate_linked_map_value::<Map, K, V, V, Value, _>(key, |_| value)
+}
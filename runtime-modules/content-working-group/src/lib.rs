@@ -16,6 +16,8 @@ mod mock;
 
 pub mod genesis;
 
+mod migration;
+
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
@@ -28,6 +30,7 @@ use rstd::collections::btree_set::BTreeSet;
 use rstd::convert::From;
 use rstd::prelude::*;
 use sr_primitives::traits::{One, Zero}; // Member, SimpleArithmetic, MaybeSerialize
+use sr_primitives::Percent;
 use srml_support::traits::{Currency, ExistenceRequirement, WithdrawReasons};
 use srml_support::{
     decl_event,
@@ -232,6 +235,31 @@ pub static MSG_ADD_CURATOR_OPENING_NEW_APPLICATION_WAS_CROWDED_OUT: &str =
 pub static MSG_ADD_CURATOR_OPENING_ZERO_MAX_APPLICANT_COUNT: &str =
     "Application rationing has zero max active applicants";
 
+// Errors for `contribute_to_curator_application_role_stake`
+pub static MSG_CONTRIBUTE_TO_CURATOR_APPLICATION_ROLE_STAKE_APPLICATION_NOT_ACTIVE: &str =
+    "Curator application is not active";
+pub static MSG_CONTRIBUTE_TO_CURATOR_APPLICATION_ROLE_STAKE_NO_ROLE_STAKE: &str =
+    "Curator application has no role stake";
+
+// Errors for changing role stakes, in `contribute_to_curator_application_role_stake`,
+// `increase_curator_role_stake` and `decrease_curator_role_stake`
+pub static MSG_ROLE_STAKE_NOT_FOUND: &str = "Role stake not found";
+pub static MSG_ROLE_STAKE_NOT_STAKED: &str = "Role stake is not staked";
+pub static MSG_ROLE_STAKE_CANNOT_CHANGE_BY_ZERO: &str = "Cannot change role stake by zero";
+pub static MSG_ROLE_STAKE_IS_UNSTAKING: &str = "Role stake is being unstaked";
+pub static MSG_ROLE_STAKE_HAS_ONGOING_SLASHES: &str =
+    "Cannot decrease role stake while slashes are ongoing";
+pub static MSG_ROLE_STAKE_INSUFFICIENT: &str = "Insufficient role stake";
+pub static MSG_ROLE_STAKE_BELOW_CONTRIBUTIONS: &str =
+    "Cannot decrease role stake below contributed amount";
+
+// Errors for `increase_curator_role_stake` and `decrease_curator_role_stake`
+pub static MSG_CURATOR_HAS_NO_ROLE_STAKE: &str = "Curator has no role stake";
+pub static MSG_CURATOR_ROLE_STAKE_BELOW_MINIMUM: &str =
+    "Role stake cannot be decreased below the opening minimum";
+pub static MSG_CURATOR_ROLE_STAKE_AMOUNT_IS_EXACT: &str =
+    "Role stake cannot be changed when the opening requires an exact amount";
+
 // Errors for `apply_on_curator_opening`
 pub static MSG_APPLY_ON_CURATOR_OPENING_UNSIGNED_ORIGIN: &str = "Unsigned origin";
 pub static MSG_APPLY_ON_CURATOR_OPENING_MEMBER_ID_INVALID: &str = "Member id is invalid";
@@ -380,6 +408,17 @@ impl<LeadId: Clone, CuratorApplicationId: Clone, BlockNumber: Clone>
     }
 }
 
+/// Penalty terms for a curator leaving the role before serving a minimum period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq, Eq)]
+pub struct EarlyExitTerms<BlockNumber> {
+    /// Number of blocks, counted from induction, which must pass before the curator can leave without penalty.
+    pub minimum_service_period: BlockNumber,
+
+    /// Share of the role stake which is slashed when leaving early.
+    pub slash_percentage: Percent,
+}
+
 /// Role stake information for a curator.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
@@ -392,6 +431,9 @@ pub struct CuratorRoleStakeProfile<StakeId, BlockNumber> {
 
     /// Unstaking period when exiting.
     pub exit_unstaking_period: Option<BlockNumber>,
+
+    /// Penalty applied to role stake when exiting before the minimum service period.
+    pub early_exit_terms: Option<EarlyExitTerms<BlockNumber>>,
 }
 
 impl<StakeId: Clone, BlockNumber: Clone> CuratorRoleStakeProfile<StakeId, BlockNumber> {
@@ -399,11 +441,13 @@ impl<StakeId: Clone, BlockNumber: Clone> CuratorRoleStakeProfile<StakeId, BlockN
         stake_id: &StakeId,
         termination_unstaking_period: &Option<BlockNumber>,
         exit_unstaking_period: &Option<BlockNumber>,
+        early_exit_terms: &Option<EarlyExitTerms<BlockNumber>>,
     ) -> Self {
         Self {
             stake_id: (*stake_id).clone(),
             termination_unstaking_period: (*termination_unstaking_period).clone(),
             exit_unstaking_period: (*exit_unstaking_period).clone(),
+            early_exit_terms: (*early_exit_terms).clone(),
         }
     }
 }
@@ -751,6 +795,9 @@ pub struct OpeningPolicyCommitment<BlockNumber, Balance> {
 
     /// When a curator exists: ..
     pub exit_curator_role_stake_unstaking_period: Option<BlockNumber>,
+
+    /// When a curator exits before serving a minimum period: penalty applied to role stake
+    pub exit_curator_role_early_exit_terms: Option<EarlyExitTerms<BlockNumber>>,
 }
 
 /// Represents a possible unstaker in working group.
@@ -906,6 +953,76 @@ impl<T: hiring::Trait> rstd::convert::From<WrappedError<hiring::FillOpeningError
     }
 }
 
+impl
+    rstd::convert::From<
+        WrappedError<stake::StakeActionError<stake::IncreasingStakeFromAccountError>>,
+    > for &str
+{
+    fn from(
+        wrapper: WrappedError<stake::StakeActionError<stake::IncreasingStakeFromAccountError>>,
+    ) -> Self {
+        match wrapper.error {
+            stake::StakeActionError::StakeNotFound => MSG_ROLE_STAKE_NOT_FOUND,
+            stake::StakeActionError::Error(
+                stake::IncreasingStakeFromAccountError::InsufficientBalanceInSourceAccount,
+            ) => MSG_INSUFFICIENT_BALANCE_TO_COVER_STAKE,
+            stake::StakeActionError::Error(
+                stake::IncreasingStakeFromAccountError::IncreasingStakeError(error),
+            ) => match error {
+                stake::IncreasingStakeError::NotStaked => MSG_ROLE_STAKE_NOT_STAKED,
+                stake::IncreasingStakeError::CannotChangeStakeByZero => {
+                    MSG_ROLE_STAKE_CANNOT_CHANGE_BY_ZERO
+                }
+                stake::IncreasingStakeError::CannotIncreaseStakeWhileUnstaking => {
+                    MSG_ROLE_STAKE_IS_UNSTAKING
+                }
+            },
+        }
+    }
+}
+
+impl rstd::convert::From<WrappedError<stake::StakeActionError<stake::DecreasingStakeError>>>
+    for &str
+{
+    fn from(wrapper: WrappedError<stake::StakeActionError<stake::DecreasingStakeError>>) -> Self {
+        match wrapper.error {
+            stake::StakeActionError::StakeNotFound => MSG_ROLE_STAKE_NOT_FOUND,
+            stake::StakeActionError::Error(error) => match error {
+                stake::DecreasingStakeError::NotStaked => MSG_ROLE_STAKE_NOT_STAKED,
+                stake::DecreasingStakeError::CannotChangeStakeByZero => {
+                    MSG_ROLE_STAKE_CANNOT_CHANGE_BY_ZERO
+                }
+                stake::DecreasingStakeError::CannotDecreaseStakeWhileOngoingSlahes => {
+                    MSG_ROLE_STAKE_HAS_ONGOING_SLASHES
+                }
+                stake::DecreasingStakeError::InsufficientStake => MSG_ROLE_STAKE_INSUFFICIENT,
+                stake::DecreasingStakeError::CannotDecreaseStakeWhileUnstaking => {
+                    MSG_ROLE_STAKE_IS_UNSTAKING
+                }
+                stake::DecreasingStakeError::CannotDecreaseStakeBelowContributions => {
+                    MSG_ROLE_STAKE_BELOW_CONTRIBUTIONS
+                }
+            },
+        }
+    }
+}
+
+impl rstd::convert::From<WrappedError<stake::StakeActionError<stake::ImmediateSlashingError>>>
+    for &str
+{
+    fn from(wrapper: WrappedError<stake::StakeActionError<stake::ImmediateSlashingError>>) -> Self {
+        match wrapper.error {
+            stake::StakeActionError::StakeNotFound => MSG_ROLE_STAKE_NOT_FOUND,
+            stake::StakeActionError::Error(error) => match error {
+                stake::ImmediateSlashingError::NotStaked => MSG_ROLE_STAKE_NOT_STAKED,
+                stake::ImmediateSlashingError::SlashAmountShouldBeGreaterThanZero => {
+                    MSG_ROLE_STAKE_CANNOT_CHANGE_BY_ZERO
+                }
+            },
+        }
+    }
+}
+
 impl rstd::convert::From<WrappedError<hiring::DeactivateApplicationError>> for &str {
     fn from(wrapper: WrappedError<hiring::DeactivateApplicationError>) -> Self {
         match wrapper.error {
@@ -1091,6 +1208,7 @@ decl_event! {
         MintBalanceOf = minting::BalanceOf<T>,
         <T as system::Trait>::AccountId,
         <T as minting::Trait>::MintId,
+        StakeBalanceOf = BalanceOf<T>,
     {
         ChannelCreated(ChannelId),
         ChannelOwnershipTransferred(ChannelId),
@@ -1104,8 +1222,12 @@ decl_event! {
         AppliedOnCuratorOpening(CuratorOpeningId, CuratorApplicationId),
         CuratorExited(CuratorId),
         CuratorUnstaking(CuratorId),
+        CuratorSlashedForEarlyExit(CuratorId, StakeBalanceOf),
+        CuratorRoleStakeIncreased(CuratorId, StakeBalanceOf),
+        CuratorRoleStakeDecreased(CuratorId, StakeBalanceOf),
         CuratorApplicationTerminated(CuratorApplicationId),
         CuratorApplicationWithdrawn(CuratorApplicationId),
+        ContributedToCuratorApplicationRoleStake(CuratorApplicationId, AccountId, StakeBalanceOf),
         CuratorRoleAccountUpdated(CuratorId, AccountId),
         CuratorRewardAccountUpdated(CuratorId, AccountId),
         ChannelUpdatedByCurationActor(ChannelId),
@@ -1587,7 +1709,8 @@ decl_module! {
                             CuratorRoleStakeProfile::new(
                                 stake_id,
                                 &curator_opening.policy_commitment.terminate_curator_role_stake_unstaking_period,
-                                &curator_opening.policy_commitment.exit_curator_role_stake_unstaking_period
+                                &curator_opening.policy_commitment.exit_curator_role_stake_unstaking_period,
+                                &curator_opening.policy_commitment.exit_curator_role_early_exit_terms
                             )
                         )
                     } else {
@@ -1712,6 +1835,49 @@ decl_module! {
             Self::deposit_event(RawEvent::CuratorApplicationTerminated(curator_application_id));
         }
 
+        /// Contribute funds to the role stake of an active curator application, backing the applicant.
+        /// Contributions are slashed along with the stake and refunded to the contributor when it is unstaked.
+        pub fn contribute_to_curator_application_role_stake(
+            origin,
+            curator_application_id: CuratorApplicationId<T>,
+            contribution: BalanceOf<T>
+        ) {
+            // Ensure that it is signed
+            let contributor_account = ensure_signed(origin)?;
+
+            // Ensuring curator application actually exists
+            let (curator_application, _, _) = Self::ensure_curator_application_exists(&curator_application_id)?;
+
+            let application = hiring::ApplicationById::<T>::get(curator_application.application_id);
+
+            // Ensure application is still being considered
+            ensure!(
+                application.stage == hiring::ApplicationStage::Active,
+                MSG_CONTRIBUTE_TO_CURATOR_APPLICATION_ROLE_STAKE_APPLICATION_NOT_ACTIVE
+            );
+
+            // Ensure applicant has a role stake to contribute to
+            let role_stake_id = application.active_role_staking_id
+                .ok_or(MSG_CONTRIBUTE_TO_CURATOR_APPLICATION_ROLE_STAKE_NO_ROLE_STAKE)?;
+
+            // Attempt to contribute
+            // NB: Combined ensure check and mutation in stake module
+            ensure_on_wrapped_error!(
+                stake::Module::<T>::contribute_to_stake_from_account(
+                    &role_stake_id,
+                    &contributor_account,
+                    contribution
+                )
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ContributedToCuratorApplicationRoleStake(curator_application_id, contributor_account, contribution));
+        }
+
         /// Apply on a curator opening.
         pub fn apply_on_curator_opening(
             origin,
@@ -1868,6 +2034,77 @@ decl_module! {
             Self::deposit_event(RawEvent::CuratorRewardAccountUpdated(curator_id, new_reward_account));
         }
 
+        /// An active curator can top up the role stake from the role account,
+        /// for example to meet a raised minimum.
+        pub fn increase_curator_role_stake(
+            origin,
+            curator_id: CuratorId<T>,
+            amount: BalanceOf<T>
+        ) {
+            // Ensure there is a signer which matches role account of curator corresponding to provided id.
+            let curator = Self::ensure_active_curator_signed(origin, &curator_id)?;
+
+            // Ensure the curator actually has a role stake
+            let stake_id = Self::ensure_curator_has_role_stake(&curator)?;
+
+            // Ensure the opening did not require an exact role stake
+            Self::ensure_curator_role_stake_can_be_increased(&curator)?;
+
+            // Attempt to increase stake
+            // NB: Combined ensure check and mutation in stake module
+            ensure_on_wrapped_error!(
+                stake::Module::<T>::increase_stake_from_account(
+                    &stake_id,
+                    &curator.role_account,
+                    amount
+                )
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Trigger event
+            Self::deposit_event(RawEvent::CuratorRoleStakeIncreased(curator_id, amount));
+        }
+
+        /// An active curator can withdraw part of the role stake into the role account,
+        /// as long as what remains at stake meets the minimum of the opening the curator was hired through.
+        /// The withdrawn funds go to the role account, which is also where increases are funded from:
+        /// the account which funded the stake when applying is not recorded, and may have been a
+        /// member account rather than the role account.
+        pub fn decrease_curator_role_stake(
+            origin,
+            curator_id: CuratorId<T>,
+            amount: BalanceOf<T>
+        ) {
+            // Ensure there is a signer which matches role account of curator corresponding to provided id.
+            let curator = Self::ensure_active_curator_signed(origin, &curator_id)?;
+
+            // Ensure the curator actually has a role stake
+            let stake_id = Self::ensure_curator_has_role_stake(&curator)?;
+
+            // Ensure remaining stake would not fall below the opening minimum
+            Self::ensure_curator_role_stake_can_be_decreased_by(&curator, &stake_id, amount)?;
+
+            // Attempt to decrease stake
+            // NB: Combined ensure check and mutation in stake module
+            ensure_on_wrapped_error!(
+                stake::Module::<T>::decrease_stake_to_account(
+                    &stake_id,
+                    &curator.role_account,
+                    amount
+                )
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Trigger event
+            Self::deposit_event(RawEvent::CuratorRoleStakeDecreased(curator_id, amount));
+        }
+
         /// An active curator leaves role
         pub fn leave_curator_role(
             origin,
@@ -1877,10 +2114,29 @@ decl_module! {
             // Ensure there is a signer which matches role account of curator corresponding to provided id.
             let active_curator = Self::ensure_active_curator_signed(origin, &curator_id)?;
 
+            // Determine penalty, if any, for leaving before the minimum service period has passed
+            let opt_early_exit_slash = Self::determine_early_exit_slash(&active_curator);
+
+            // Slash role stake before unstaking is initiated
+            // NB: Combined ensure check and mutation in stake module
+            let opt_actually_slashed = if let Some((stake_id, slash_amount)) = opt_early_exit_slash {
+                let slash_outcome = ensure_on_wrapped_error!(
+                    stake::Module::<T>::slash_immediate(&stake_id, slash_amount, false)
+                )?;
+
+                Some(slash_outcome.actually_slashed)
+            } else {
+                None
+            };
+
             //
             // == MUTATION SAFE ==
             //
 
+            if let Some(actually_slashed) = opt_actually_slashed {
+                Self::deposit_event(RawEvent::CuratorSlashedForEarlyExit(curator_id, actually_slashed));
+            }
+
             Self::deactivate_curator(
                 &curator_id,
                 &active_curator,
@@ -2634,6 +2890,147 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    fn ensure_curator_has_role_stake(
+        curator: &Curator<
+            T::AccountId,
+            T::RewardRelationshipId,
+            T::StakeId,
+            T::BlockNumber,
+            LeadId<T>,
+            CuratorApplicationId<T>,
+            PrincipalId<T>,
+        >,
+    ) -> Result<StakeId<T>, &'static str> {
+        curator
+            .role_stake_profile
+            .as_ref()
+            .map(|stake_profile| stake_profile.stake_id)
+            .ok_or(MSG_CURATOR_HAS_NO_ROLE_STAKE)
+    }
+
+    fn ensure_curator_role_stake_can_be_decreased_by(
+        curator: &Curator<
+            T::AccountId,
+            T::RewardRelationshipId,
+            T::StakeId,
+            T::BlockNumber,
+            LeadId<T>,
+            CuratorApplicationId<T>,
+            PrincipalId<T>,
+        >,
+        stake_id: &StakeId<T>,
+        amount: BalanceOf<T>,
+    ) -> Result<(), &'static str> {
+        let staked_amount = match stake::Module::<T>::stakes(stake_id).staking_status {
+            stake::StakingStatus::Staked(ref staked_state) => staked_state.staked_amount,
+            _ => return Err(MSG_ROLE_STAKE_NOT_STAKED),
+        };
+
+        ensure!(amount <= staked_amount, MSG_ROLE_STAKE_INSUFFICIENT);
+
+        if let Some(role_staking_policy) = Self::curator_role_staking_policy(curator) {
+            match role_staking_policy.amount_mode {
+                hiring::StakingAmountLimitMode::Exact => {
+                    return Err(MSG_CURATOR_ROLE_STAKE_AMOUNT_IS_EXACT)
+                }
+                hiring::StakingAmountLimitMode::AtLeast => ensure!(
+                    staked_amount - amount >= role_staking_policy.amount,
+                    MSG_CURATOR_ROLE_STAKE_BELOW_MINIMUM
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn ensure_curator_role_stake_can_be_increased(
+        curator: &Curator<
+            T::AccountId,
+            T::RewardRelationshipId,
+            T::StakeId,
+            T::BlockNumber,
+            LeadId<T>,
+            CuratorApplicationId<T>,
+            PrincipalId<T>,
+        >,
+    ) -> Result<(), &'static str> {
+        if let Some(role_staking_policy) = Self::curator_role_staking_policy(curator) {
+            ensure!(
+                role_staking_policy.amount_mode != hiring::StakingAmountLimitMode::Exact,
+                MSG_CURATOR_ROLE_STAKE_AMOUNT_IS_EXACT
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Role staking policy of the opening the curator was hired through.
+    fn curator_role_staking_policy(
+        curator: &Curator<
+            T::AccountId,
+            T::RewardRelationshipId,
+            T::StakeId,
+            T::BlockNumber,
+            LeadId<T>,
+            CuratorApplicationId<T>,
+            PrincipalId<T>,
+        >,
+    ) -> Option<hiring::StakingPolicy<BalanceOf<T>, T::BlockNumber>> {
+        let curator_application =
+            CuratorApplicationById::<T>::get(curator.induction.curator_application_id);
+        let curator_opening = CuratorOpeningById::<T>::get(curator_application.curator_opening_id);
+
+        curator_opening.policy_commitment.role_staking_policy
+    }
+
+    /// Determines the role stake and amount to slash, if the curator is leaving
+    /// before the minimum service period of its early exit terms has passed.
+    fn determine_early_exit_slash(
+        curator: &Curator<
+            T::AccountId,
+            T::RewardRelationshipId,
+            T::StakeId,
+            T::BlockNumber,
+            LeadId<T>,
+            CuratorApplicationId<T>,
+            PrincipalId<T>,
+        >,
+    ) -> Option<(StakeId<T>, BalanceOf<T>)> {
+        let stake_profile = if let Some(ref stake_profile) = curator.role_stake_profile {
+            stake_profile
+        } else {
+            return None;
+        };
+
+        let early_exit_terms = if let Some(ref early_exit_terms) = stake_profile.early_exit_terms {
+            early_exit_terms
+        } else {
+            return None;
+        };
+
+        // No penalty once the minimum service period has passed
+        let current_block = <system::Module<T>>::block_number();
+
+        if current_block >= curator.induction.at_block + early_exit_terms.minimum_service_period {
+            return None;
+        }
+
+        // Slash is a share of what is currently at stake
+        let staked_amount = match stake::Module::<T>::stakes(stake_profile.stake_id).staking_status
+        {
+            stake::StakingStatus::Staked(ref staked_state) => staked_state.staked_amount,
+            _ => return None,
+        };
+
+        let slash_amount = early_exit_terms.slash_percentage * staked_amount;
+
+        if slash_amount.is_zero() {
+            None
+        } else {
+            Some((stake_profile.stake_id, slash_amount))
+        }
+    }
+
     fn deactivate_curator(
         curator_id: &CuratorId<T>,
         curator: &Curator<
@@ -0,0 +1,154 @@
+//! Layouts of stored values as they were before the curator early exit terms were introduced,
+//! and their migration to the current layouts.
+
+use crate::*;
+use common::storage_migration::translate_linked_map_value;
+
+/// Role stake information for a curator, before `early_exit_terms`.
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct OldCuratorRoleStakeProfile<StakeId, BlockNumber> {
+    pub stake_id: StakeId,
+    pub termination_unstaking_period: Option<BlockNumber>,
+    pub exit_unstaking_period: Option<BlockNumber>,
+}
+
+/// Curator, with the role stake profile before `early_exit_terms`.
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct OldCurator<
+    AccountId,
+    RewardRelationshipId,
+    StakeId,
+    BlockNumber,
+    LeadId,
+    CuratorApplicationId,
+    PrincipalId,
+> {
+    pub role_account: AccountId,
+    pub reward_relationship: Option<RewardRelationshipId>,
+    pub role_stake_profile: Option<OldCuratorRoleStakeProfile<StakeId, BlockNumber>>,
+    pub stage: CuratorRoleStage<BlockNumber>,
+    pub induction: CuratorInduction<LeadId, CuratorApplicationId, BlockNumber>,
+    pub principal_id: PrincipalId,
+}
+
+/// Opening policy commitment, before `exit_curator_role_early_exit_terms`.
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq)]
+pub struct OldOpeningPolicyCommitment<BlockNumber, Balance> {
+    pub application_rationing_policy: Option<hiring::ApplicationRationingPolicy>,
+    pub max_review_period_length: BlockNumber,
+    pub application_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    pub role_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    pub role_slashing_terms: SlashingTerms,
+    pub fill_opening_successful_applicant_application_stake_unstaking_period: Option<BlockNumber>,
+    pub fill_opening_failed_applicant_application_stake_unstaking_period: Option<BlockNumber>,
+    pub fill_opening_failed_applicant_role_stake_unstaking_period: Option<BlockNumber>,
+    pub terminate_curator_application_stake_unstaking_period: Option<BlockNumber>,
+    pub terminate_curator_role_stake_unstaking_period: Option<BlockNumber>,
+    pub exit_curator_role_application_stake_unstaking_period: Option<BlockNumber>,
+    pub exit_curator_role_stake_unstaking_period: Option<BlockNumber>,
+}
+
+/// Curator opening, with the policy commitment before `exit_curator_role_early_exit_terms`.
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct OldCuratorOpening<OpeningId, BlockNumber, Balance, CuratorApplicationId: Ord> {
+    pub opening_id: OpeningId,
+    pub curator_applications: BTreeSet<CuratorApplicationId>,
+    pub policy_commitment: OldOpeningPolicyCommitment<BlockNumber, Balance>,
+}
+
+pub type OldCuratorOf<T> = OldCurator<
+    <T as system::Trait>::AccountId,
+    <T as recurringrewards::Trait>::RewardRelationshipId,
+    <T as stake::Trait>::StakeId,
+    <T as system::Trait>::BlockNumber,
+    LeadId<T>,
+    CuratorApplicationId<T>,
+    PrincipalId<T>,
+>;
+
+pub type OldCuratorOpeningOf<T> = OldCuratorOpening<
+    <T as hiring::Trait>::OpeningId,
+    <T as system::Trait>::BlockNumber,
+    BalanceOf<T>,
+    CuratorApplicationId<T>,
+>;
+
+impl<StakeId, BlockNumber> From<OldCuratorRoleStakeProfile<StakeId, BlockNumber>>
+    for CuratorRoleStakeProfile<StakeId, BlockNumber>
+{
+    fn from(old: OldCuratorRoleStakeProfile<StakeId, BlockNumber>) -> Self {
+        CuratorRoleStakeProfile {
+            stake_id: old.stake_id,
+            termination_unstaking_period: old.termination_unstaking_period,
+            exit_unstaking_period: old.exit_unstaking_period,
+            early_exit_terms: None,
+        }
+    }
+}
+
+impl<BlockNumber, Balance> From<OldOpeningPolicyCommitment<BlockNumber, Balance>>
+    for OpeningPolicyCommitment<BlockNumber, Balance>
+{
+    fn from(old: OldOpeningPolicyCommitment<BlockNumber, Balance>) -> Self {
+        OpeningPolicyCommitment {
+            application_rationing_policy: old.application_rationing_policy,
+            max_review_period_length: old.max_review_period_length,
+            application_staking_policy: old.application_staking_policy,
+            role_staking_policy: old.role_staking_policy,
+            role_slashing_terms: old.role_slashing_terms,
+            fill_opening_successful_applicant_application_stake_unstaking_period: old
+                .fill_opening_successful_applicant_application_stake_unstaking_period,
+            fill_opening_failed_applicant_application_stake_unstaking_period: old
+                .fill_opening_failed_applicant_application_stake_unstaking_period,
+            fill_opening_failed_applicant_role_stake_unstaking_period: old
+                .fill_opening_failed_applicant_role_stake_unstaking_period,
+            terminate_curator_application_stake_unstaking_period: old
+                .terminate_curator_application_stake_unstaking_period,
+            terminate_curator_role_stake_unstaking_period: old
+                .terminate_curator_role_stake_unstaking_period,
+            exit_curator_role_application_stake_unstaking_period: old
+                .exit_curator_role_application_stake_unstaking_period,
+            exit_curator_role_stake_unstaking_period: old.exit_curator_role_stake_unstaking_period,
+            exit_curator_role_early_exit_terms: None,
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Adds empty early exit terms to the curators and curator openings stored before the terms
+    /// were introduced. Must only run when upgrading from a runtime without the terms.
+    pub fn migrate_early_exit_terms() {
+        let mut curator_id = CuratorId::<T>::zero();
+
+        while curator_id < Self::next_curator_id() {
+            translate_linked_map_value::<CuratorById<T>, _, _, OldCuratorOf<T>, _, _>(
+                &curator_id,
+                |old| Curator {
+                    role_account: old.role_account,
+                    reward_relationship: old.reward_relationship,
+                    role_stake_profile: old.role_stake_profile.map(Into::into),
+                    stage: old.stage,
+                    induction: old.induction,
+                    principal_id: old.principal_id,
+                },
+            );
+
+            curator_id += <CuratorId<T> as One>::one();
+        }
+
+        let mut curator_opening_id = CuratorOpeningId::<T>::zero();
+
+        while curator_opening_id < Self::next_curator_opening_id() {
+            translate_linked_map_value::<CuratorOpeningById<T>, _, _, OldCuratorOpeningOf<T>, _, _>(
+                &curator_opening_id,
+                |old| CuratorOpening {
+                    opening_id: old.opening_id,
+                    curator_applications: old.curator_applications,
+                    policy_commitment: old.policy_commitment.into(),
+                },
+            );
+
+            curator_opening_id += <CuratorOpeningId<T> as One>::one();
+        }
+    }
+}
@@ -71,6 +71,7 @@ pub type RawLibTestEvent = RawEvent<
     minting::BalanceOf<Test>,
     <Test as system::Trait>::AccountId,
     <Test as minting::Trait>::MintId,
+    BalanceOf<Test>,
 >;
 
 pub fn get_last_event_or_panic() -> RawLibTestEvent {
@@ -155,9 +156,13 @@ impl recurringrewards::Trait for Test {
 type TestStakeId = u64;
 type TestSlashId = u64;
 impl stake::Trait for Test {
+    type Event = ();
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
+    type SlashAppellants = ();
+    type SlashAppealAuthority = system::EnsureRoot<u64>;
+    type SlashDestination = ();
     type StakeId = TestStakeId;
     type SlashId = TestSlashId;
 }
@@ -173,10 +178,12 @@ impl hiring::Trait for Test {
 
 impl versioned_store::Trait for Test {
     type Event = TestEvent;
+    type ContentIdExists = ();
 }
 
 type TestPrincipalId = u64;
 impl versioned_store_permissions::Trait for Test {
+    type Event = ();
     type Credential = TestPrincipalId;
     type CredentialChecker = ();
     type CreateClassPermissionsChecker = ();
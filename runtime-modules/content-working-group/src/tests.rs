@@ -1,12 +1,14 @@
 #![cfg(test)]
 
 use super::genesis;
+use super::migration::*;
 use super::mock::{self, *};
 //use crate::membership;
+use common::storage_migration::replace_linked_map_value;
 use hiring;
 use rstd::collections::btree_map::BTreeMap;
 use rstd::collections::btree_set::BTreeSet;
-use sr_primitives::traits::One;
+use sr_primitives::{traits::One, Percent};
 use srml_support::{assert_err, assert_ok, StorageLinkedMap, StorageValue};
 
 /// DIRTY IMPORT BECAUSE
@@ -635,6 +637,119 @@ fn withdraw_curator_application_success() {
         });
 }
 
+#[test]
+fn contribute_to_curator_application_role_stake_success() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            /*
+             * Setup
+             */
+
+            let normal_opening_constructed = setup_normal_accepting_opening();
+
+            let curator_applicant_root_and_controller_account = 333;
+            let curator_applicant_role_account = 11111;
+            let human_readable_text =
+                generate_valid_length_buffer(&CuratorApplicationHumanReadableText::get());
+
+            let result = add_member_and_apply_on_opening(
+                normal_opening_constructed.curator_opening_id,
+                curator_applicant_root_and_controller_account,
+                to_vec("CuratorWannabe"),
+                curator_applicant_role_account,
+                human_readable_text,
+            );
+
+            let contributor_account = 4444;
+            let contribution = 1000;
+
+            let _ = balances::Module::<Test>::deposit_creating(&contributor_account, contribution);
+
+            let curator_application =
+                CuratorApplicationById::<Test>::get(result.curator_application_id);
+            let role_stake_id =
+                hiring::ApplicationById::<Test>::get(curator_application.application_id)
+                    .active_role_staking_id
+                    .unwrap();
+
+            let role_stake_before = staked_amount(role_stake_id);
+
+            /*
+             * Test
+             */
+
+            assert_eq!(
+                ContentWorkingGroup::contribute_to_curator_application_role_stake(
+                    Origin::signed(contributor_account),
+                    result.curator_application_id,
+                    contribution
+                )
+                .unwrap(),
+                ()
+            );
+
+            // Event was triggered
+            assert_eq!(
+                get_last_event_or_panic(),
+                crate::RawEvent::ContributedToCuratorApplicationRoleStake(
+                    result.curator_application_id,
+                    contributor_account,
+                    contribution
+                )
+            );
+
+            // Stake was increased and contribution recorded
+            assert_eq!(
+                staked_amount(role_stake_id),
+                role_stake_before + contribution
+            );
+            assert_eq!(
+                stake::Module::<Test>::contributions_by_stake_id(role_stake_id)
+                    .get(&contributor_account),
+                Some(&contribution)
+            );
+
+            // Cannot contribute more than available balance
+            assert_eq!(
+                ContentWorkingGroup::contribute_to_curator_application_role_stake(
+                    Origin::signed(contributor_account),
+                    result.curator_application_id,
+                    contribution
+                ),
+                Err(MSG_INSUFFICIENT_BALANCE_TO_COVER_STAKE)
+            );
+
+            // Cannot contribute to withdrawn application
+            assert_eq!(
+                ContentWorkingGroup::withdraw_curator_application(
+                    Origin::signed(curator_applicant_role_account),
+                    result.curator_application_id
+                )
+                .unwrap(),
+                ()
+            );
+
+            let _ = balances::Module::<Test>::deposit_creating(&contributor_account, contribution);
+
+            assert_eq!(
+                ContentWorkingGroup::contribute_to_curator_application_role_stake(
+                    Origin::signed(contributor_account),
+                    result.curator_application_id,
+                    contribution
+                ),
+                Err(MSG_CONTRIBUTE_TO_CURATOR_APPLICATION_ROLE_STAKE_APPLICATION_NOT_ACTIVE)
+            );
+        });
+}
+
+fn staked_amount(stake_id: <Test as stake::Trait>::StakeId) -> BalanceOf<Test> {
+    match stake::Module::<Test>::stakes(stake_id).staking_status {
+        stake::StakingStatus::Staked(staked_state) => staked_state.staked_amount,
+        _ => 0,
+    }
+}
+
 #[test]
 fn terminate_curator_application_success() {
     TestExternalitiesBuilder::<Test>::default()
@@ -1079,12 +1194,200 @@ fn leave_curator_role_success() {
         });
 }
 
+fn setup_lead_and_hire_curator_with_early_exit_terms(
+    early_exit_terms: EarlyExitTerms<<Test as system::Trait>::BlockNumber>,
+) -> SetupLeadAndHireCuratorResult {
+    setup_lead_and_hire_curator_with_policy(OpeningPolicyCommitment {
+        exit_curator_role_early_exit_terms: Some(early_exit_terms),
+        ..get_baseline_opening_policy()
+    })
+}
+
+fn early_exit_slash_events() -> Vec<RawLibTestEvent> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            TestEvent::lib(event @ crate::RawEvent::CuratorSlashedForEarlyExit(_, _)) => {
+                Some(event)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn leave_curator_role_before_minimum_service_period_slashes_role_stake() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let result = setup_lead_and_hire_curator_with_early_exit_terms(EarlyExitTerms {
+                minimum_service_period: 10,
+                slash_percentage: Percent::from_percent(10),
+            });
+
+            // Terms of the opening are committed to in the role stake profile of the curator
+            assert_eq!(
+                CuratorById::<Test>::get(result.curator_id())
+                    .role_stake_profile
+                    .unwrap()
+                    .early_exit_terms,
+                Some(EarlyExitTerms {
+                    minimum_service_period: 10,
+                    slash_percentage: Percent::from_percent(10),
+                })
+            );
+
+            let role_stake_amount = get_baseline_opening_policy()
+                .role_staking_policy
+                .unwrap()
+                .amount;
+
+            let fixture = LeaveCuratorRoleFixture {
+                origin: Origin::signed(result.curator_params().curator_applicant_role_account),
+                curator_id: result.curator_id(),
+                rationale_text: "I am sick of this horrible thing".as_bytes().to_vec(),
+            };
+
+            fixture.call_and_assert_success();
+
+            assert_eq!(
+                early_exit_slash_events(),
+                vec![crate::RawEvent::CuratorSlashedForEarlyExit(
+                    result.curator_id(),
+                    role_stake_amount / 10
+                )]
+            );
+        });
+}
+
+#[test]
+fn leave_curator_role_after_minimum_service_period_does_not_slash_role_stake() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let result = setup_lead_and_hire_curator_with_early_exit_terms(EarlyExitTerms {
+                minimum_service_period: 10,
+                slash_percentage: Percent::from_percent(10),
+            });
+
+            // Curator was inducted at block 1
+            System::set_block_number(11);
+
+            assert_eq!(
+                ContentWorkingGroup::leave_curator_role(
+                    Origin::signed(result.curator_params().curator_applicant_role_account),
+                    result.curator_id(),
+                    "I am sick of this horrible thing".as_bytes().to_vec(),
+                ),
+                Ok(())
+            );
+
+            assert_eq!(
+                get_last_event_or_panic(),
+                crate::RawEvent::CuratorUnstaking(result.curator_id())
+            );
+
+            assert!(early_exit_slash_events().is_empty());
+        });
+}
+
 struct TerminateCuratorRoleFixture {
     pub origin: Origin,
     pub curator_id: CuratorId<Test>,
     pub rationale_text: Vec<u8>,
 }
 
+#[test]
+fn migrate_early_exit_terms_success() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let result = setup_lead_and_hire_curator();
+
+            let curator_id = result.curator_id();
+            let curator_opening_id = result
+                .setup_and_fill_opening_result
+                .setup_opening_in_review
+                .normal_opening_constructed
+                .curator_opening_id;
+
+            let curator = CuratorById::<Test>::get(curator_id);
+            let curator_opening = CuratorOpeningById::<Test>::get(curator_opening_id);
+
+            // Store curator and opening as they were encoded before the early exit terms
+            let role_stake_profile = curator.role_stake_profile.clone().unwrap();
+
+            replace_linked_map_value::<CuratorById<Test>, _, _, _>(
+                &curator_id,
+                OldCurator {
+                    role_account: curator.role_account,
+                    reward_relationship: curator.reward_relationship,
+                    role_stake_profile: Some(OldCuratorRoleStakeProfile {
+                        stake_id: role_stake_profile.stake_id,
+                        termination_unstaking_period: role_stake_profile
+                            .termination_unstaking_period,
+                        exit_unstaking_period: role_stake_profile.exit_unstaking_period,
+                    }),
+                    stage: curator.stage.clone(),
+                    induction: curator.induction.clone(),
+                    principal_id: curator.principal_id,
+                },
+            );
+
+            let policy = curator_opening.policy_commitment.clone();
+
+            replace_linked_map_value::<CuratorOpeningById<Test>, _, _, _>(
+                &curator_opening_id,
+                OldCuratorOpening {
+                    opening_id: curator_opening.opening_id,
+                    curator_applications: curator_opening.curator_applications.clone(),
+                    policy_commitment: OldOpeningPolicyCommitment {
+                        application_rationing_policy: policy.application_rationing_policy,
+                        max_review_period_length: policy.max_review_period_length,
+                        application_staking_policy: policy.application_staking_policy,
+                        role_staking_policy: policy.role_staking_policy,
+                        role_slashing_terms: policy.role_slashing_terms,
+                        fill_opening_successful_applicant_application_stake_unstaking_period: policy
+                            .fill_opening_successful_applicant_application_stake_unstaking_period,
+                        fill_opening_failed_applicant_application_stake_unstaking_period: policy
+                            .fill_opening_failed_applicant_application_stake_unstaking_period,
+                        fill_opening_failed_applicant_role_stake_unstaking_period: policy
+                            .fill_opening_failed_applicant_role_stake_unstaking_period,
+                        terminate_curator_application_stake_unstaking_period: policy
+                            .terminate_curator_application_stake_unstaking_period,
+                        terminate_curator_role_stake_unstaking_period: policy
+                            .terminate_curator_role_stake_unstaking_period,
+                        exit_curator_role_application_stake_unstaking_period: policy
+                            .exit_curator_role_application_stake_unstaking_period,
+                        exit_curator_role_stake_unstaking_period: policy
+                            .exit_curator_role_stake_unstaking_period,
+                    },
+                },
+            );
+
+            // Old encodings cannot be read with the current layouts
+            assert_ne!(CuratorById::<Test>::get(curator_id), curator);
+            assert_ne!(
+                CuratorOpeningById::<Test>::get(curator_opening_id),
+                curator_opening
+            );
+
+            ContentWorkingGroup::migrate_early_exit_terms();
+
+            assert_eq!(CuratorById::<Test>::get(curator_id), curator);
+            assert_eq!(
+                CuratorOpeningById::<Test>::get(curator_opening_id),
+                curator_opening
+            );
+
+            // Linkage of the entries is kept
+            assert_eq!(
+                CuratorById::<Test>::enumerate().collect::<Vec<_>>(),
+                vec![(curator_id, curator)]
+            );
+        });
+}
+
 impl TerminateCuratorRoleFixture {
     fn call(&self) -> Result<(), &'static str> {
         ContentWorkingGroup::terminate_curator_role(
@@ -1135,6 +1438,156 @@ impl TerminateCuratorRoleFixture {
     }
 }
 
+fn curator_role_stake_id(curator_id: CuratorId<Test>) -> <Test as stake::Trait>::StakeId {
+    CuratorById::<Test>::get(curator_id)
+        .role_stake_profile
+        .unwrap()
+        .stake_id
+}
+
+#[test]
+fn increase_and_decrease_curator_role_stake_success() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let result = setup_lead_and_hire_curator();
+
+            let curator_role_account = result.curator_params().curator_applicant_role_account;
+            let stake_id = curator_role_stake_id(result.curator_id());
+            let minimum_role_stake = get_baseline_opening_policy()
+                .role_staking_policy
+                .unwrap()
+                .amount;
+
+            let top_up = 500;
+            let _ = balances::Module::<Test>::deposit_creating(&curator_role_account, top_up);
+
+            assert_eq!(
+                ContentWorkingGroup::increase_curator_role_stake(
+                    Origin::signed(curator_role_account),
+                    result.curator_id(),
+                    top_up
+                ),
+                Ok(())
+            );
+
+            assert_eq!(
+                get_last_event_or_panic(),
+                crate::RawEvent::CuratorRoleStakeIncreased(result.curator_id(), top_up)
+            );
+            assert_eq!(staked_amount(stake_id), minimum_role_stake + top_up);
+            assert_eq!(
+                balances::Module::<Test>::free_balance(&curator_role_account),
+                0
+            );
+
+            // Cannot withdraw below opening minimum
+            assert_eq!(
+                ContentWorkingGroup::decrease_curator_role_stake(
+                    Origin::signed(curator_role_account),
+                    result.curator_id(),
+                    top_up + 1
+                ),
+                Err(MSG_CURATOR_ROLE_STAKE_BELOW_MINIMUM)
+            );
+
+            assert_eq!(
+                ContentWorkingGroup::decrease_curator_role_stake(
+                    Origin::signed(curator_role_account),
+                    result.curator_id(),
+                    top_up
+                ),
+                Ok(())
+            );
+
+            assert_eq!(
+                get_last_event_or_panic(),
+                crate::RawEvent::CuratorRoleStakeDecreased(result.curator_id(), top_up)
+            );
+            assert_eq!(staked_amount(stake_id), minimum_role_stake);
+            assert_eq!(
+                balances::Module::<Test>::free_balance(&curator_role_account),
+                top_up
+            );
+        });
+}
+
+#[test]
+fn increase_curator_role_stake_fails_with_insufficient_balance() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let result = setup_lead_and_hire_curator();
+
+            let curator_role_account = result.curator_params().curator_applicant_role_account;
+
+            assert_eq!(
+                ContentWorkingGroup::increase_curator_role_stake(
+                    Origin::signed(curator_role_account),
+                    result.curator_id(),
+                    balances::Module::<Test>::free_balance(&curator_role_account) + 1
+                ),
+                Err(MSG_INSUFFICIENT_BALANCE_TO_COVER_STAKE)
+            );
+
+            assert_eq!(
+                ContentWorkingGroup::increase_curator_role_stake(
+                    Origin::signed(curator_role_account),
+                    result.curator_id(),
+                    0
+                ),
+                Err(MSG_ROLE_STAKE_CANNOT_CHANGE_BY_ZERO)
+            );
+        });
+}
+
+#[test]
+fn changing_curator_role_stake_fails_with_exact_role_staking_amount() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let baseline_policy = get_baseline_opening_policy();
+            let result = setup_lead_and_hire_curator_with_policy(OpeningPolicyCommitment {
+                role_staking_policy: Some(hiring::StakingPolicy {
+                    amount_mode: hiring::StakingAmountLimitMode::Exact,
+                    ..baseline_policy.role_staking_policy.clone().unwrap()
+                }),
+                ..baseline_policy
+            });
+
+            let curator_role_account = result.curator_params().curator_applicant_role_account;
+            let stake_id = curator_role_stake_id(result.curator_id());
+            let role_stake = staked_amount(stake_id);
+
+            let top_up = 500;
+            let _ = balances::Module::<Test>::deposit_creating(&curator_role_account, top_up);
+
+            assert_eq!(
+                ContentWorkingGroup::increase_curator_role_stake(
+                    Origin::signed(curator_role_account),
+                    result.curator_id(),
+                    top_up
+                ),
+                Err(MSG_CURATOR_ROLE_STAKE_AMOUNT_IS_EXACT)
+            );
+
+            assert_eq!(
+                ContentWorkingGroup::decrease_curator_role_stake(
+                    Origin::signed(curator_role_account),
+                    result.curator_id(),
+                    1
+                ),
+                Err(MSG_CURATOR_ROLE_STAKE_AMOUNT_IS_EXACT)
+            );
+
+            assert_eq!(staked_amount(stake_id), role_stake);
+            assert_eq!(
+                balances::Module::<Test>::free_balance(&curator_role_account),
+                top_up
+            );
+        });
+}
+
 #[test]
 fn terminate_curator_role_success() {
     TestExternalitiesBuilder::<Test>::default()
@@ -1401,6 +1854,7 @@ pub fn get_baseline_opening_policy(
         terminate_curator_role_stake_unstaking_period: None,
         exit_curator_role_application_stake_unstaking_period: None,
         exit_curator_role_stake_unstaking_period: None,
+        exit_curator_role_early_exit_terms: None,
     }
 }
 
@@ -1617,6 +2071,12 @@ struct NormalOpeningConstructed {
 }
 
 fn setup_normal_opening() -> NormalOpeningConstructed {
+    setup_normal_opening_with_policy(get_baseline_opening_policy())
+}
+
+fn setup_normal_opening_with_policy(
+    policy: OpeningPolicyCommitment<<Test as system::Trait>::BlockNumber, BalanceOf<Test>>,
+) -> NormalOpeningConstructed {
     let new_member_as_lead = add_member_and_set_as_lead();
 
     let expected_curator_opening_id = NextCuratorOpeningId::<Test>::get();
@@ -1625,7 +2085,7 @@ fn setup_normal_opening() -> NormalOpeningConstructed {
         ContentWorkingGroup::add_curator_opening(
             Origin::signed(LEAD_ROLE_ACCOUNT),
             hiring::ActivateOpeningAt::ExactBlock(34),
-            get_baseline_opening_policy(),
+            policy,
             generate_valid_length_buffer(&OpeningHumanReadableText::get())
         )
         .unwrap(),
@@ -1644,7 +2104,13 @@ fn setup_normal_opening() -> NormalOpeningConstructed {
 }
 
 fn setup_normal_accepting_opening() -> NormalOpeningConstructed {
-    let normal_opening_constructed = setup_normal_opening();
+    setup_normal_accepting_opening_with_policy(get_baseline_opening_policy())
+}
+
+fn setup_normal_accepting_opening_with_policy(
+    policy: OpeningPolicyCommitment<<Test as system::Trait>::BlockNumber, BalanceOf<Test>>,
+) -> NormalOpeningConstructed {
+    let normal_opening_constructed = setup_normal_opening_with_policy(policy);
 
     assert_eq!(
         ContentWorkingGroup::accept_curator_applications(
@@ -1667,7 +2133,14 @@ struct SetupOpeningInReview {
 fn setup_opening_in_review(
     applicants: &Vec<AddMemberAndApplyOnOpeningParams>,
 ) -> SetupOpeningInReview {
-    let normal_opening_constructed = setup_normal_accepting_opening();
+    setup_opening_in_review_with_policy(applicants, get_baseline_opening_policy())
+}
+
+fn setup_opening_in_review_with_policy(
+    applicants: &Vec<AddMemberAndApplyOnOpeningParams>,
+    policy: OpeningPolicyCommitment<<Test as system::Trait>::BlockNumber, BalanceOf<Test>>,
+) -> SetupOpeningInReview {
+    let normal_opening_constructed = setup_normal_accepting_opening_with_policy(policy);
 
     let added_members_application_result =
         add_members_and_apply_on_opening(normal_opening_constructed.curator_opening_id, applicants);
@@ -1701,6 +2174,13 @@ struct SetupAndFillOpeningResult {
 
 fn setup_and_fill_opening(
     applicants: &Vec<FillOpeningApplicantParams>,
+) -> SetupAndFillOpeningResult {
+    setup_and_fill_opening_with_policy(applicants, get_baseline_opening_policy())
+}
+
+fn setup_and_fill_opening_with_policy(
+    applicants: &Vec<FillOpeningApplicantParams>,
+    policy: OpeningPolicyCommitment<<Test as system::Trait>::BlockNumber, BalanceOf<Test>>,
 ) -> SetupAndFillOpeningResult {
     let setup_opening_params = applicants
         .iter()
@@ -1708,7 +2188,8 @@ fn setup_and_fill_opening(
         .map(|param| param.add_and_apply_params)
         .collect::<Vec<_>>();
 
-    let setup_opening_in_review = setup_opening_in_review(&setup_opening_params);
+    let setup_opening_in_review =
+        setup_opening_in_review_with_policy(&setup_opening_params, policy);
 
     let curator_opening = CuratorOpeningById::<Test>::get(
         setup_opening_in_review
@@ -1824,6 +2305,9 @@ fn setup_and_fill_opening(
                 &curator_opening
                     .policy_commitment
                     .exit_curator_role_stake_unstaking_period,
+                &curator_opening
+                    .policy_commitment
+                    .exit_curator_role_early_exit_terms,
             ))
         } else {
             None
@@ -1910,14 +2394,22 @@ impl SetupLeadAndHireCuratorResult {
 }
 
 fn setup_lead_and_hire_curator() -> SetupLeadAndHireCuratorResult {
+    setup_lead_and_hire_curator_with_policy(get_baseline_opening_policy())
+}
+
+fn setup_lead_and_hire_curator_with_policy(
+    policy: OpeningPolicyCommitment<<Test as system::Trait>::BlockNumber, BalanceOf<Test>>,
+) -> SetupLeadAndHireCuratorResult {
     let curator_params = make_generic_add_member_params();
 
     // Hire curator
-    let setup_and_fill_opening_result =
-        setup_and_fill_opening(&vec![FillOpeningApplicantParams::new(
+    let setup_and_fill_opening_result = setup_and_fill_opening_with_policy(
+        &vec![FillOpeningApplicantParams::new(
             curator_params.clone(),
             true,
-        )]);
+        )],
+        policy,
+    );
 
     SetupLeadAndHireCuratorResult {
         curator_params,
@@ -90,9 +90,13 @@ impl Trait for Test {
 }
 
 impl stake::Trait for Test {
+    type Event = ();
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
+    type SlashAppellants = ();
+    type SlashAppealAuthority = system::EnsureRoot<u64>;
+    type SlashDestination = ();
     type StakeId = u64;
     type SlashId = u64;
 }
@@ -22,6 +22,9 @@
 //! - [create_evict_storage_provider_proposal](./struct.Module.html#method.create_evict_storage_provider_proposal)
 //! - [create_set_validator_count_proposal](./struct.Module.html#method.create_set_validator_count_proposal)
 //! - [create_set_storage_role_parameters_proposal](./struct.Module.html#method.create_set_storage_role_parameters_proposal)
+//! - [create_set_storage_provider_reward_rate_proposal](./struct.Module.html#method.create_set_storage_provider_reward_rate_proposal)
+//! - [create_uphold_slash_appeal_proposal](./struct.Module.html#method.create_uphold_slash_appeal_proposal)
+//! - [create_cancel_slash_appeal_proposal](./struct.Module.html#method.create_cancel_slash_appeal_proposal)
 //!
 //! ### Proposal implementations of this module
 //! - execute_text_proposal - prints the proposal to the log
@@ -79,6 +82,8 @@ const CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE: u32 = 1_000_000;
 const MAX_SPENDING_PROPOSAL_VALUE: u32 = 2_000_000_u32;
 // Max validator count for the 'set validator count' proposal
 const MAX_VALIDATOR_COUNT: u32 = 100;
+// Max per byte reward for the 'set storage provider reward rate' proposal
+const STORAGE_PROVIDER_REWARD_RATE_MAX_VALUE: u32 = 1_000;
 // min_actors min value for the 'set storage role parameters' proposal
 const ROLE_PARAMETERS_MIN_ACTORS_MAX_VALUE: u32 = 2;
 // max_actors min value for the 'set storage role parameters' proposal
@@ -283,7 +288,13 @@ decl_error! {
         InvalidStorageWorkingGroupMintCapacity,
 
         /// Invalid 'set lead proposal' parameter - proposed lead cannot be a councilor
-        InvalidSetLeadParameterCannotBeCouncilor
+        InvalidSetLeadParameterCannotBeCouncilor,
+
+        /// Invalid storage provider reward rate parameter
+        InvalidStorageProviderRewardRate,
+
+        /// Invalid 'slash appeal' proposal parameter - the slash has not been appealed
+        InvalidSlashAppealNotFound
     }
 }
 
@@ -331,7 +342,9 @@ decl_storage! {
                 BalanceOfGovernanceCurrency<T>,
                 T::BlockNumber,
                 T::AccountId,
-                T::MemberId
+                T::MemberId,
+                T::StakeId,
+                T::SlashId
             >;
 
         /// Voting period for the 'set validator count' proposal
@@ -401,6 +414,22 @@ decl_storage! {
         /// Grace period for the 'set storage role parameters' proposal
         pub SetStorageRoleParametersProposalGracePeriod get(set_storage_role_parameters_proposal_grace_period)
             config(): T::BlockNumber;
+
+        /// Voting period for the 'set storage provider reward rate' proposal
+        pub SetStorageProviderRewardRateProposalVotingPeriod get(set_storage_provider_reward_rate_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set storage provider reward rate' proposal
+        pub SetStorageProviderRewardRateProposalGracePeriod get(set_storage_provider_reward_rate_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'uphold slash appeal' and 'cancel slash appeal' proposals
+        pub SlashAppealProposalVotingPeriod get(slash_appeal_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'uphold slash appeal' and 'cancel slash appeal' proposals
+        pub SlashAppealProposalGracePeriod get(slash_appeal_proposal_grace_period)
+            config(): T::BlockNumber;
     }
 }
 
@@ -700,6 +729,98 @@ decl_module! {
             )?;
         }
 
+        /// Create 'Set storage provider reward rate' proposal type.
+        /// This proposal uses `set_reward_rate_per_byte()` extrinsic from the `storage::data_object_storage_registry`  module.
+        pub fn create_set_storage_provider_reward_rate_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            reward_rate_per_byte: BalanceOfMint<T>,
+        ) {
+            ensure!(
+                reward_rate_per_byte <= <BalanceOfMint<T>>::from(STORAGE_PROVIDER_REWARD_RATE_MAX_VALUE),
+                Error::InvalidStorageProviderRewardRate
+            );
+
+            let proposal_parameters =
+                proposal_types::parameters::set_storage_provider_reward_rate_proposal::<T>();
+            let proposal_details = ProposalDetails::SetStorageProviderRewardRate(reward_rate_per_byte);
+            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+
+            Self::create_proposal(
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_code,
+                proposal_parameters,
+                proposal_details,
+            )?;
+        }
+
+        /// Create 'Uphold slash appeal' proposal type.
+        /// This proposal uses `uphold_appealed_slash()` extrinsic from the `stake`  module.
+        pub fn create_uphold_slash_appeal_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            stake_id: T::StakeId,
+            slash_id: T::SlashId,
+        ) {
+            Self::ensure_slash_appeal_exists(&stake_id, &slash_id)?;
+
+            let proposal_parameters =
+                proposal_types::parameters::slash_appeal_proposal::<T>();
+            let proposal_details = ProposalDetails::UpholdSlashAppeal(stake_id, slash_id);
+            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+
+            Self::create_proposal(
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_code,
+                proposal_parameters,
+                proposal_details,
+            )?;
+        }
+
+        /// Create 'Cancel slash appeal' proposal type.
+        /// This proposal uses `cancel_appealed_slash()` extrinsic from the `stake`  module.
+        pub fn create_cancel_slash_appeal_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            stake_id: T::StakeId,
+            slash_id: T::SlashId,
+        ) {
+            Self::ensure_slash_appeal_exists(&stake_id, &slash_id)?;
+
+            let proposal_parameters =
+                proposal_types::parameters::slash_appeal_proposal::<T>();
+            let proposal_details = ProposalDetails::CancelSlashAppeal(stake_id, slash_id);
+            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+
+            Self::create_proposal(
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_code,
+                proposal_parameters,
+                proposal_details,
+            )?;
+        }
+
 // *************** Extrinsic to execute
 
         /// Text proposal extrinsic. Should be used as callable object to pass to the `engine` module.
@@ -768,6 +889,8 @@ impl<T: Trait> Module<T> {
             T::BlockNumber,
             T::AccountId,
             T::MemberId,
+            T::StakeId,
+            T::SlashId,
         >,
     ) -> DispatchResult<Error> {
         let account_id = T::MembershipOriginValidator::ensure_actor_origin(origin, member_id)?;
@@ -800,6 +923,19 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    // validates the appeal of the 'Uphold slash appeal' and 'Cancel slash appeal' proposals
+    fn ensure_slash_appeal_exists(
+        stake_id: &T::StakeId,
+        slash_id: &T::SlashId,
+    ) -> Result<(), Error> {
+        ensure!(
+            <stake::SlashAppeals<T>>::exists(&(*stake_id, *slash_id)),
+            Error::InvalidSlashAppealNotFound
+        );
+
+        Ok(())
+    }
+
     // validates storage role parameters for the 'Set storage role parameters' proposal
     fn ensure_storage_role_parameters_valid(
         role_parameters: &RoleParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>,
@@ -1086,5 +1222,17 @@ impl<T: Trait> Module<T> {
         <SetStorageRoleParametersProposalGracePeriod<T>>::put(T::BlockNumber::from(
             p.set_storage_role_parameters_proposal_grace_period,
         ));
+        <SetStorageProviderRewardRateProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_storage_provider_reward_rate_proposal_voting_period,
+        ));
+        <SetStorageProviderRewardRateProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_storage_provider_reward_rate_proposal_grace_period,
+        ));
+        <SlashAppealProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.slash_appeal_proposal_voting_period,
+        ));
+        <SlashAppealProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.slash_appeal_proposal_grace_period,
+        ));
     }
 }
@@ -23,12 +23,22 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
     <T as system::Trait>::BlockNumber,
     <T as system::Trait>::AccountId,
     crate::MemberId<T>,
+    <T as stake::Trait>::StakeId,
+    <T as stake::Trait>::SlashId,
 >;
 
 /// Proposal details provide voters the information required for the perceived voting.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
-pub enum ProposalDetails<MintedBalance, CurrencyBalance, BlockNumber, AccountId, MemberId> {
+pub enum ProposalDetails<
+    MintedBalance,
+    CurrencyBalance,
+    BlockNumber,
+    AccountId,
+    MemberId,
+    StakeId,
+    SlashId,
+> {
     /// The text of the `text` proposal
     Text(Vec<u8>),
 
@@ -55,10 +65,27 @@ pub enum ProposalDetails<MintedBalance, CurrencyBalance, BlockNumber, AccountId,
 
     /// Role parameters for the `set storage role parameters` proposal
     SetStorageRoleParameters(RoleParameters<CurrencyBalance, BlockNumber>),
+
+    /// Reward per byte served for the `set storage provider reward rate` proposal
+    SetStorageProviderRewardRate(MintedBalance),
+
+    /// Stake and slash of the appeal rejected by the `uphold slash appeal` proposal
+    UpholdSlashAppeal(StakeId, SlashId),
+
+    /// Stake and slash of the appeal accepted by the `cancel slash appeal` proposal
+    CancelSlashAppeal(StakeId, SlashId),
 }
 
-impl<MintedBalance, CurrencyBalance, BlockNumber, AccountId, MemberId> Default
-    for ProposalDetails<MintedBalance, CurrencyBalance, BlockNumber, AccountId, MemberId>
+impl<MintedBalance, CurrencyBalance, BlockNumber, AccountId, MemberId, StakeId, SlashId> Default
+    for ProposalDetails<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        MemberId,
+        StakeId,
+        SlashId,
+    >
 {
     fn default() -> Self {
         ProposalDetails::Text(b"invalid proposal details".to_vec())
@@ -120,6 +147,18 @@ pub struct ProposalsConfigParameters {
 
     /// 'Set storage role parameters' proposal grace period
     pub set_storage_role_parameters_proposal_grace_period: u32,
+
+    /// 'Set storage provider reward rate' proposal voting period
+    pub set_storage_provider_reward_rate_proposal_voting_period: u32,
+
+    /// 'Set storage provider reward rate' proposal grace period
+    pub set_storage_provider_reward_rate_proposal_grace_period: u32,
+
+    /// 'Uphold slash appeal' and 'Cancel slash appeal' proposals voting period
+    pub slash_appeal_proposal_voting_period: u32,
+
+    /// 'Uphold slash appeal' and 'Cancel slash appeal' proposals grace period
+    pub slash_appeal_proposal_grace_period: u32,
 }
 
 impl Default for ProposalsConfigParameters {
@@ -143,6 +182,10 @@ impl Default for ProposalsConfigParameters {
             evict_storage_provider_proposal_grace_period: 0u32,
             set_storage_role_parameters_proposal_voting_period: 43200u32,
             set_storage_role_parameters_proposal_grace_period: 14400u32,
+            set_storage_provider_reward_rate_proposal_voting_period: 43200u32,
+            set_storage_provider_reward_rate_proposal_grace_period: 14400u32,
+            slash_appeal_proposal_voting_period: 43200u32,
+            slash_appeal_proposal_grace_period: 0u32,
         }
     }
 }
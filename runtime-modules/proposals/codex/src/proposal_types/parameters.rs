@@ -125,3 +125,31 @@ pub(crate) fn set_storage_role_parameters_proposal<T: crate::Trait>(
         required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
     }
 }
+
+// Proposal parameters for the 'Set storage provider reward rate' proposal
+pub(crate) fn set_storage_provider_reward_rate_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::set_storage_provider_reward_rate_proposal_voting_period(),
+        grace_period: <Module<T>>::set_storage_provider_reward_rate_proposal_grace_period(),
+        approval_quorum_percentage: 50,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+    }
+}
+
+// Proposal parameters for the 'Uphold slash appeal' and 'Cancel slash appeal' proposals
+pub(crate) fn slash_appeal_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::slash_appeal_proposal_voting_period(),
+        grace_period: <Module<T>>::slash_appeal_proposal_grace_period(),
+        approval_quorum_percentage: 50,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+    }
+}
@@ -77,9 +77,13 @@ impl balances::Trait for Test {
 }
 
 impl stake::Trait for Test {
+    type Event = ();
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
+    type SlashAppellants = ();
+    type SlashAppealAuthority = system::EnsureRoot<u64>;
+    type SlashDestination = ();
     type StakeId = u64;
     type SlashId = u64;
 }
@@ -177,6 +181,7 @@ impl recurring_rewards::Trait for Test {
 }
 
 impl versioned_store_permissions::Trait for Test {
+    type Event = ();
     type Credential = u64;
     type CredentialChecker = ();
     type CreateClassPermissionsChecker = ();
@@ -184,6 +189,7 @@ impl versioned_store_permissions::Trait for Test {
 
 impl versioned_store::Trait for Test {
     type Event = ();
+    type ContentIdExists = ();
 }
 
 impl hiring::Trait for Test {
@@ -38,7 +38,7 @@ where
     invalid_stake_call: InvalidStakeCall,
     successful_call: SuccessfulCall,
     proposal_parameters: ProposalParameters<u64, u64>,
-    proposal_details: ProposalDetails<u64, u64, u64, u64, u64>,
+    proposal_details: ProposalDetails<u64, u64, u64, u64, u64, u64, u64>,
 }
 
 impl<InsufficientRightsCall, EmptyStakeCall, InvalidStakeCall, SuccessfulCall>
@@ -538,6 +538,242 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
     });
 }
 
+#[test]
+fn create_set_storage_provider_reward_rate_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_storage_provider_reward_rate_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    0,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_storage_provider_reward_rate_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    0,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_storage_provider_reward_rate_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    0,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_storage_provider_reward_rate_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    10,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_storage_provider_reward_rate_proposal::<Test>(
+                ),
+            proposal_details: ProposalDetails::SetStorageProviderRewardRate(10),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_storage_provider_reward_rate_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_set_storage_provider_reward_rate_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                (crate::STORAGE_PROVIDER_REWARD_RATE_MAX_VALUE + 1) as u64,
+            ),
+            Err(Error::InvalidStorageProviderRewardRate)
+        );
+    });
+}
+
+fn insert_slash_appeal(stake_id: u64, slash_id: u64) {
+    <stake::SlashAppeals<Test>>::insert(
+        &(stake_id, slash_id),
+        stake::SlashAppeal {
+            appellant: 1,
+            filed_at_block: 0,
+            rationale: b"rationale".to_vec(),
+            slash_was_active: true,
+        },
+    );
+}
+
+#[test]
+fn create_uphold_slash_appeal_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+        insert_slash_appeal(1, 1);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_uphold_slash_appeal_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    1,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_uphold_slash_appeal_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    1,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_uphold_slash_appeal_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    1,
+                    1,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_uphold_slash_appeal_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    1,
+                    1,
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::slash_appeal_proposal::<Test>(),
+            proposal_details: ProposalDetails::UpholdSlashAppeal(1, 1),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_cancel_slash_appeal_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+        insert_slash_appeal(1, 1);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_cancel_slash_appeal_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    1,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_cancel_slash_appeal_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    1,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_cancel_slash_appeal_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    1,
+                    1,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_cancel_slash_appeal_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    1,
+                    1,
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::slash_appeal_proposal::<Test>(),
+            proposal_details: ProposalDetails::CancelSlashAppeal(1, 1),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_slash_appeal_proposals_fail_without_appeal() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+        insert_slash_appeal(1, 1);
+
+        assert_eq!(
+            ProposalCodex::create_uphold_slash_appeal_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                1,
+                2,
+            ),
+            Err(Error::InvalidSlashAppealNotFound)
+        );
+
+        assert_eq!(
+            ProposalCodex::create_cancel_slash_appeal_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                2,
+                1,
+            ),
+            Err(Error::InvalidSlashAppealNotFound)
+        );
+    });
+}
+
 #[test]
 fn create_spending_proposal_common_checks_succeed() {
     initial_test_ext().execute_with(|| {
@@ -1143,5 +1379,21 @@ fn set_default_proposal_parameters_succeeded() {
             <SetStorageRoleParametersProposalGracePeriod<Test>>::get(),
             p.set_storage_role_parameters_proposal_grace_period as u64
         );
+        assert_eq!(
+            <SetStorageProviderRewardRateProposalVotingPeriod<Test>>::get(),
+            p.set_storage_provider_reward_rate_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetStorageProviderRewardRateProposalGracePeriod<Test>>::get(),
+            p.set_storage_provider_reward_rate_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SlashAppealProposalVotingPeriod<Test>>::get(),
+            p.slash_appeal_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SlashAppealProposalGracePeriod<Test>>::get(),
+            p.slash_appeal_proposal_grace_period as u64
+        );
     });
 }
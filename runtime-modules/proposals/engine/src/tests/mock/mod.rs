@@ -79,9 +79,13 @@ impl common::currency::GovernanceCurrency for Test {
 impl proposals::Trait for Test {}
 
 impl stake::Trait for Test {
+    type Event = ();
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = BalanceManagerStakingEventsHandler;
+    type SlashAppellants = ();
+    type SlashAppealAuthority = system::EnsureRoot<u64>;
+    type SlashDestination = ();
     type StakeId = u64;
     type SlashId = u64;
 }
@@ -5,9 +5,9 @@
 use codec::{Decode, Encode};
 use common::currency::{BalanceOf, GovernanceCurrency};
 use rstd::prelude::*;
-use sr_primitives::traits::{Bounded, Zero};
+use sr_primitives::traits::{Bounded, Saturating, Zero};
 use srml_support::traits::{
-    Currency, LockIdentifier, LockableCurrency, WithdrawReason, WithdrawReasons,
+    Currency, Imbalance, LockIdentifier, LockableCurrency, WithdrawReason, WithdrawReasons,
 };
 use srml_support::{decl_event, decl_module, decl_storage, ensure};
 use system::{self, ensure_root, ensure_signed};
@@ -19,6 +19,10 @@ pub use membership::members::Role;
 
 const STAKING_ID: LockIdentifier = *b"role_stk";
 
+pub type NegativeImbalance<T> = <<T as GovernanceCurrency>::Currency as Currency<
+    <T as system::Trait>::AccountId,
+>>::NegativeImbalance;
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, Debug)]
 pub struct RoleParameters<Balance, BlockNumber> {
@@ -32,7 +36,8 @@ pub struct RoleParameters<Balance, BlockNumber> {
     // the maximum number of spots available to fill for a role
     pub max_actors: u32,
 
-    // fixed amount of tokens paid to actors' primary account
+    // fixed amount of tokens paid to actors' primary account. This is the base reward
+    // of storage providers, the storage registry pays their reward per byte served on top.
     pub reward: Balance,
 
     // payouts are made at this block interval
@@ -161,6 +166,7 @@ decl_event! {
         EntryRequested(AccountId, Role),
         Staked(AccountId, Role),
         Unstaked(AccountId, Role),
+        Evicted(AccountId, Role),
     }
 }
 
@@ -234,6 +240,32 @@ impl<T: Trait> Module<T> {
         );
     }
 
+    /// Slashes up to `slash_amount` from the actor account and removes the actor from service.
+    /// The rest of the role stake stays locked for the unbonding period. Returns the slashed funds,
+    /// which the caller is responsible for routing; dropping them burns the funds.
+    pub fn slash_and_evict_actor(
+        actor_account: &T::AccountId,
+        slash_amount: BalanceOf<T>,
+    ) -> Result<NegativeImbalance<T>, &'static str> {
+        let actor = Self::ensure_actor(actor_account)?;
+        let role_parameters = Self::ensure_role_parameters(actor.role)?;
+
+        let (imbalance, _) = T::Currency::slash(actor_account, slash_amount);
+        let slashed = imbalance.peek();
+
+        Self::apply_unstake(
+            actor_account.clone(),
+            actor.role,
+            actor.member_id,
+            role_parameters.unbonding_period,
+            role_parameters.min_stake.saturating_sub(slashed),
+        );
+
+        Self::deposit_event(RawEvent::Evicted(actor_account.clone(), actor.role));
+
+        Ok(imbalance)
+    }
+
     pub fn is_role_account(account_id: &T::AccountId) -> bool {
         <ActorByAccountId<T>>::exists(account_id)
     }
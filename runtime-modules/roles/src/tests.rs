@@ -2,6 +2,7 @@
 
 use super::mock::*;
 
+use srml_support::traits::Imbalance;
 use srml_support::*;
 
 fn init_storage_role() {
@@ -202,3 +203,53 @@ fn unstaking() {
         assert_eq!(lock.until, current_block + storage_params.unbonding_period);
     });
 }
+
+#[test]
+fn slashing_and_evicting() {
+    initial_test_ext().execute_with(|| {
+        init_storage_role();
+        let storage_params = init_storage_parmeters();
+        let actor_account = 5;
+        let slash_amount = 30;
+
+        assert!(Actors::slash_and_evict_actor(&actor_account, slash_amount).is_err());
+
+        let actor: actors::Actor<Test> = actors::Actor {
+            role: actors::Role::StorageProvider,
+            member_id: alice_id(),
+            account: actor_account,
+            joined_at: 1,
+        };
+        <actors::ActorAccountIds<Test>>::put(vec![actor_account]);
+        <actors::ActorByAccountId<Test>>::insert(&actor_account, actor);
+        <actors::AccountIdsByRole<Test>>::insert(
+            actors::Role::StorageProvider,
+            vec![actor_account],
+        );
+        <actors::AccountIdsByMemberId<Test>>::insert(alice_id(), vec![actor_account]);
+        let _ = Balances::deposit_creating(&actor_account, storage_params.min_stake);
+
+        let current_block = 500;
+        System::set_block_number(current_block);
+
+        assert_eq!(
+            Actors::slash_and_evict_actor(&actor_account, slash_amount)
+                .map(|imbalance| imbalance.peek()),
+            Ok(slash_amount)
+        );
+        assert_eq!(
+            Balances::free_balance(&actor_account),
+            storage_params.min_stake - slash_amount
+        );
+
+        assert!(Actors::actor_by_account_id(actor_account).is_none());
+        assert_eq!(
+            Actors::account_ids_by_role(actors::Role::StorageProvider).len(),
+            0
+        );
+
+        let lock = Balances::locks(&actor_account)[0].clone();
+        assert_eq!(lock.amount, storage_params.min_stake - slash_amount);
+        assert_eq!(lock.until, current_block + storage_params.unbonding_period);
+    });
+}
@@ -1,4 +1,5 @@
 use crate::actors;
+use rstd::prelude::*;
 
 // Roles
 pub trait Roles<T: system::Trait> {
@@ -8,6 +9,9 @@ pub trait Roles<T: system::Trait> {
 
     // If available, return a random account ID for the given role.
     fn random_account_for_role(role: actors::Role) -> Result<T::AccountId, &'static str>;
+
+    // Return all live accounts in the given role.
+    fn accounts_for_role(role: actors::Role) -> Vec<T::AccountId>;
 }
 
 impl<T: system::Trait> Roles<T> for () {
@@ -22,4 +26,8 @@ impl<T: system::Trait> Roles<T> for () {
     fn random_account_for_role(_role: actors::Role) -> Result<T::AccountId, &'static str> {
         Err("not implemented")
     }
+
+    fn accounts_for_role(_role: actors::Role) -> Vec<T::AccountId> {
+        vec![]
+    }
 }
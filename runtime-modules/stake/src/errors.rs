@@ -42,6 +42,7 @@ pub enum DecreasingStakeError {
     CannotDecreaseStakeWhileOngoingSlahes,
     InsufficientStake,
     CannotDecreaseStakeWhileUnstaking,
+    CannotDecreaseStakeBelowContributions,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -77,6 +78,21 @@ pub enum CancelSlashingError {
     NotStaked,
 }
 
+#[derive(Debug, Eq, PartialEq)]
+pub enum SlashAppealError {
+    SlashNotFound,
+    NotStaked,
+    AlreadyAppealed,
+    RationaleTooLong,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum SlashAppealReviewError {
+    AppealNotFound,
+    SlashNotFound,
+    NotStaked,
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum UnstakingError {
     NotStaked,
@@ -162,3 +178,32 @@ impl From<UnstakingError> for StakeActionError<InitiateUnstakingError> {
         StakeActionError::Error(InitiateUnstakingError::UnstakingError(e))
     }
 }
+
+impl From<StakeActionError<SlashAppealError>> for &'static str {
+    fn from(err: StakeActionError<SlashAppealError>) -> Self {
+        match err {
+            StakeActionError::StakeNotFound => "Stake not found",
+            StakeActionError::Error(SlashAppealError::SlashNotFound) => "Slash not found",
+            StakeActionError::Error(SlashAppealError::NotStaked) => "Stake is not staked",
+            StakeActionError::Error(SlashAppealError::AlreadyAppealed) => {
+                "Slash has already been appealed"
+            }
+            StakeActionError::Error(SlashAppealError::RationaleTooLong) => {
+                "Slash appeal rationale too long"
+            }
+        }
+    }
+}
+
+impl From<StakeActionError<SlashAppealReviewError>> for &'static str {
+    fn from(err: StakeActionError<SlashAppealReviewError>) -> Self {
+        match err {
+            StakeActionError::StakeNotFound => "Stake not found",
+            StakeActionError::Error(SlashAppealReviewError::AppealNotFound) => {
+                "Slash appeal not found"
+            }
+            StakeActionError::Error(SlashAppealReviewError::SlashNotFound) => "Slash not found",
+            StakeActionError::Error(SlashAppealReviewError::NotStaked) => "Stake is not staked",
+        }
+    }
+}
@@ -8,10 +8,14 @@ use runtime_primitives::traits::{
     AccountIdConversion, MaybeSerialize, Member, One, SimpleArithmetic, Zero,
 };
 use runtime_primitives::ModuleId;
-use srml_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
-use srml_support::{decl_module, decl_storage, ensure, Parameter};
+use srml_support::traits::{
+    Currency, EnsureOrigin, ExistenceRequirement, Get, Imbalance, WithdrawReasons,
+};
+use srml_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
+use system::ensure_signed;
 
 use rstd::collections::btree_map::BTreeMap;
+use rstd::marker::PhantomData;
 
 mod errors;
 pub use errors::*;
@@ -25,7 +29,13 @@ pub type BalanceOf<T> =
 pub type NegativeImbalance<T> =
     <<T as Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::NegativeImbalance;
 
+/// Maximum length of the rationale text of a slash appeal.
+pub const MAX_SLASH_APPEAL_RATIONALE_LENGTH: usize = 2048;
+
 pub trait Trait: system::Trait + Sized {
+    /// The overarching event type.
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
     /// The currency that is managed by the module
     type Currency: Currency<Self::AccountId>;
 
@@ -35,6 +45,15 @@ pub trait Trait: system::Trait + Sized {
     /// Type that will handle various staking events
     type StakingEventsHandler: StakingEventsHandler<Self>;
 
+    /// Type that determines which accounts may appeal slashes on behalf of the slashed party
+    type SlashAppellants: SlashAppellants<Self>;
+
+    /// Origin which reviews slash appeals, upholding or cancelling the appealed slashes
+    type SlashAppealAuthority: EnsureOrigin<Self::Origin>;
+
+    /// Type that receives slashed funds not consumed by the staking events handler
+    type SlashDestination: SlashDestination<Self>;
+
     /// The type used as a stake identifier.
     type StakeId: Parameter
         + Member
@@ -139,6 +158,131 @@ impl<T: Trait, X: StakingEventsHandler<T>, Y: StakingEventsHandler<T>> StakingEv
     }
 }
 
+/// Determines which accounts may file an appeal against an ongoing slash of a stake.
+/// The stake module does not track who is the holder of a stake, so this is resolved by the
+/// module which created the stake.
+pub trait SlashAppellants<T: Trait> {
+    fn can_appeal(stake_id: &T::StakeId, account_id: &T::AccountId) -> bool;
+}
+
+/// Default implementation does not allow appealing slashes
+impl<T: Trait> SlashAppellants<T> for () {
+    fn can_appeal(_stake_id: &T::StakeId, _account_id: &T::AccountId) -> bool {
+        false
+    }
+}
+
+/// Receives the slashed funds left over after the staking events handler was notified of a slash.
+/// Whatever is not consumed is burned.
+pub trait SlashDestination<T: Trait> {
+    fn receive_slashed_funds(record: &SlashRecordOf<T>, imbalance: NegativeImbalance<T>);
+}
+
+/// Default implementation burns the slashed funds
+impl<T: Trait> SlashDestination<T> for () {
+    fn receive_slashed_funds(_record: &SlashRecordOf<T>, imbalance: NegativeImbalance<T>) {
+        drop(imbalance);
+    }
+}
+
+/// Rewards the reporter of a slash, if any, with the configured share in percent points of the slashed funds,
+/// and passes on the rest to the next destination. For example:
+/// type SlashDestination = RewardReporter<Runtime, ReporterRewardPercentPts, ()>
+pub struct RewardReporter<T, RewardPercentPts, Rest> {
+    pub marker: PhantomData<(T, RewardPercentPts, Rest)>,
+}
+
+impl<T: Trait, RewardPercentPts: Get<u16>, Rest: SlashDestination<T>> SlashDestination<T>
+    for RewardReporter<T, RewardPercentPts, Rest>
+{
+    fn receive_slashed_funds(record: &SlashRecordOf<T>, imbalance: NegativeImbalance<T>) {
+        let remaining_imbalance = if let Some(ref reporter) = record.reporter {
+            let reward_percent_pts = RewardPercentPts::get().min(100);
+
+            let reward = imbalance.peek() * BalanceOf::<T>::from(reward_percent_pts)
+                / BalanceOf::<T>::from(100u16);
+
+            let (reward, rest) = imbalance.split(reward);
+
+            T::Currency::resolve_creating(reporter, reward);
+
+            rest
+        } else {
+            imbalance
+        };
+
+        Rest::receive_slashed_funds(record, remaining_imbalance)
+    }
+}
+
+/// Staking events handler which settles funds contributed to a stake by accounts other than the stake holder.
+/// On slashing, all contributions are reduced pro rata to the slashed share of the stake. On unstaking, each
+/// contributor is refunded its remaining contribution and only the rest of the value is passed on.
+/// It should be placed first when chaining handlers, so the following handlers only see the stake holder's own funds.
+pub struct ContributionsStakingEventsHandler<T> {
+    pub marker: PhantomData<T>,
+}
+
+impl<T: Trait> StakingEventsHandler<T> for ContributionsStakingEventsHandler<T> {
+    fn unstaked(
+        id: &T::StakeId,
+        _unstaked_amount: BalanceOf<T>,
+        remaining_imbalance: NegativeImbalance<T>,
+    ) -> NegativeImbalance<T> {
+        if !<ContributionsByStakeId<T>>::exists(id) {
+            return remaining_imbalance;
+        }
+
+        let mut remaining_imbalance = remaining_imbalance;
+
+        for (contributor, contributed_amount) in <ContributionsByStakeId<T>>::take(id).into_iter() {
+            let (refund, rest) = remaining_imbalance.split(contributed_amount);
+
+            T::Currency::resolve_creating(&contributor, refund);
+
+            remaining_imbalance = rest;
+        }
+
+        remaining_imbalance
+    }
+
+    fn slashed(
+        id: &T::StakeId,
+        _slash_id: Option<T::SlashId>,
+        slashed_amount: BalanceOf<T>,
+        remaining_stake: BalanceOf<T>,
+        remaining_imbalance: NegativeImbalance<T>,
+    ) -> NegativeImbalance<T> {
+        if !<ContributionsByStakeId<T>>::exists(id) {
+            return remaining_imbalance;
+        }
+
+        let staked_amount_before_slash = remaining_stake + slashed_amount;
+
+        let contributions = <ContributionsByStakeId<T>>::get(id)
+            .into_iter()
+            .map(|(contributor, contributed_amount)| {
+                let reduced_amount = if staked_amount_before_slash.is_zero() {
+                    Zero::zero()
+                } else {
+                    contributed_amount * remaining_stake / staked_amount_before_slash
+                };
+
+                (contributor, reduced_amount)
+            })
+            .filter(|(_, reduced_amount)| !reduced_amount.is_zero())
+            .collect::<BTreeMap<_, _>>();
+
+        if contributions.is_empty() {
+            <ContributionsByStakeId<T>>::remove(id);
+        } else {
+            <ContributionsByStakeId<T>>::insert(id, contributions);
+        }
+
+        remaining_imbalance
+    }
+}
+
 #[derive(Encode, Decode, Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub struct Slash<BlockNumber, Balance> {
     /// The block where slashing was initiated.
@@ -155,6 +299,64 @@ pub struct Slash<BlockNumber, Balance> {
     pub slash_amount: Balance,
 }
 
+/// Why a stake is slashed, and who reported it.
+#[derive(Encode, Decode, Clone, Debug, Default, Eq, PartialEq)]
+pub struct SlashDetails<AccountId> {
+    /// Reason for the slash.
+    pub reason: Vec<u8>,
+
+    /// Account which reported the misbehaviour that led to the slash, if any.
+    pub reporter: Option<AccountId>,
+}
+
+/// Entry of the slash ledger, recorded when a slash is executed.
+#[derive(Encode, Decode, Clone, Debug, Default, Eq, PartialEq)]
+pub struct SlashRecord<StakeId, SlashId, AccountId, BlockNumber, Balance> {
+    /// The slashed stake.
+    pub stake_id: StakeId,
+
+    /// The slash executed, none for immediate slashes.
+    pub slash_id: Option<SlashId>,
+
+    /// Amount actually slashed.
+    pub slashed_amount: Balance,
+
+    /// Reason for the slash.
+    pub reason: Vec<u8>,
+
+    /// Account which reported the misbehaviour that led to the slash, if any.
+    pub reporter: Option<AccountId>,
+
+    /// The block where the slash was executed.
+    pub executed_at_block: BlockNumber,
+}
+
+pub type SlashRecordOf<T> = SlashRecord<
+    <T as Trait>::StakeId,
+    <T as Trait>::SlashId,
+    <T as system::Trait>::AccountId,
+    <T as system::Trait>::BlockNumber,
+    BalanceOf<T>,
+>;
+
+pub type SlashRecordId = u64;
+
+/// An appeal against an ongoing slash. The slash is paused while the appeal is under review.
+#[derive(Encode, Decode, Clone, Debug, Default, Eq, PartialEq)]
+pub struct SlashAppeal<AccountId, BlockNumber> {
+    /// Account which filed the appeal on behalf of the slashed party.
+    pub appellant: AccountId,
+
+    /// The block where the appeal was filed.
+    pub filed_at_block: BlockNumber,
+
+    /// Why the slashed party considers the slash unwarranted.
+    pub rationale: Vec<u8>,
+
+    /// Whether the slash was active when appealed, in which case it is resumed if the slash is upheld.
+    pub slash_was_active: bool,
+}
+
 #[derive(Encode, Decode, Debug, Default, Eq, PartialEq)]
 pub struct UnstakingState<BlockNumber> {
     /// The block where the unstaking was initiated
@@ -705,6 +907,8 @@ pub struct SlashImmediateOutcome<Balance, NegativeImbalance> {
     pub caused_unstake: bool,
     pub actually_slashed: Balance,
     pub remaining_stake: Balance,
+    /// Slashed funds are handed over to the slash destination, so this only holds
+    /// what the unstaked handler left over if the slash caused unstaking.
     pub remaining_imbalance: NegativeImbalance,
 }
 
@@ -716,14 +920,81 @@ decl_storage! {
         /// Identifier value for next stake, and count of total stakes created (not necessarily the number of current
         /// stakes in the Stakes map as stakes can be removed.)
         pub StakesCreated get(stakes_created): T::StakeId;
+
+        /// Maps stake identifiers to funds contributed to the stake by accounts other than the stake holder.
+        pub ContributionsByStakeId get(contributions_by_stake_id): map T::StakeId => BTreeMap<T::AccountId, BalanceOf<T>>;
+
+        /// Details of slashes which were initiated but not yet executed.
+        pub PendingSlashDetails get(pending_slash_details): map (T::StakeId, T::SlashId) => SlashDetails<T::AccountId>;
+
+        /// Ledger of all executed slashes.
+        pub SlashLedger get(slash_ledger): linked_map SlashRecordId => SlashRecordOf<T>;
+
+        /// Identifier value for next slash record, and count of total slashes executed.
+        pub SlashRecordsCreated get(slash_records_created): SlashRecordId;
+
+        /// Appeals under review, by the stake and slash they were filed against.
+        pub SlashAppeals get(slash_appeals): map (T::StakeId, T::SlashId) => SlashAppeal<T::AccountId, T::BlockNumber>;
+    }
+}
+
+decl_event! {
+    pub enum Event<T> where
+        <T as system::Trait>::AccountId,
+        <T as Trait>::StakeId,
+        <T as Trait>::SlashId,
+    {
+        /// An appeal was filed against an ongoing slash by the account, pausing the slash.
+        SlashAppealFiled(StakeId, SlashId, AccountId),
+
+        /// The appealed slash was upheld and will continue.
+        AppealedSlashUpheld(StakeId, SlashId),
+
+        /// The appealed slash was cancelled.
+        AppealedSlashCancelled(StakeId, SlashId),
     }
 }
 
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        fn deposit_event() = default;
+
         fn on_finalize(_now: T::BlockNumber) {
             Self::finalize_slashing_and_unstaking();
         }
+
+        /// Appeal an ongoing slash on behalf of the slashed party. The slash is paused until the appeal
+        /// is reviewed.
+        pub fn file_slash_appeal(origin, stake_id: T::StakeId, slash_id: T::SlashId, rationale: Vec<u8>) {
+            let appellant = ensure_signed(origin)?;
+
+            ensure!(
+                T::SlashAppellants::can_appeal(&stake_id, &appellant),
+                "Account is not allowed to appeal slashes of this stake"
+            );
+
+            Self::appeal_slash(&stake_id, &slash_id, &appellant, rationale)?;
+
+            Self::deposit_event(RawEvent::SlashAppealFiled(stake_id, slash_id, appellant));
+        }
+
+        /// Reject an appeal, resuming the appealed slash.
+        pub fn uphold_appealed_slash(origin, stake_id: T::StakeId, slash_id: T::SlashId) {
+            T::SlashAppealAuthority::ensure_origin(origin)?;
+
+            Self::uphold_slash(&stake_id, &slash_id)?;
+
+            Self::deposit_event(RawEvent::AppealedSlashUpheld(stake_id, slash_id));
+        }
+
+        /// Accept an appeal, cancelling the appealed slash.
+        pub fn cancel_appealed_slash(origin, stake_id: T::StakeId, slash_id: T::SlashId) {
+            T::SlashAppealAuthority::ensure_origin(origin)?;
+
+            Self::cancel_slash_on_appeal(&stake_id, &slash_id)?;
+
+            Self::deposit_event(RawEvent::AppealedSlashCancelled(stake_id, slash_id));
+        }
     }
 }
 
@@ -760,6 +1031,7 @@ impl<T: Trait> Module<T> {
         );
 
         <Stakes<T>>::remove(stake_id);
+        <ContributionsByStakeId<T>>::remove(stake_id);
 
         Ok(())
     }
@@ -918,12 +1190,67 @@ impl<T: Trait> Module<T> {
         Ok(total_staked_amount)
     }
 
+    /// Provided the stake exists and is in state Staked.Normal, and the contributor account covers the value,
+    /// then the value is transferred to the module's account, the corresponding staked_amount is increased by
+    /// the value and the value is recorded as contributed by the contributor. New value of staked_amount is returned.
+    pub fn contribute_to_stake_from_account(
+        stake_id: &T::StakeId,
+        contributor_account_id: &T::AccountId,
+        value: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, StakeActionError<IncreasingStakeFromAccountError>> {
+        let total_staked_amount =
+            Self::increase_stake_from_account(stake_id, contributor_account_id, value)?;
+
+        <ContributionsByStakeId<T>>::mutate(stake_id, |contributions| {
+            let contributed_amount = contributions
+                .entry(contributor_account_id.clone())
+                .or_insert_with(Zero::zero);
+
+            *contributed_amount += value;
+        });
+
+        Ok(total_staked_amount)
+    }
+
+    /// Total amount contributed to the stake by accounts other than the stake holder.
+    pub fn total_contributions(stake_id: &T::StakeId) -> BalanceOf<T> {
+        Self::contributions_by_stake_id(stake_id)
+            .values()
+            .fold(Zero::zero(), |sum, contributed_amount| {
+                sum + *contributed_amount
+            })
+    }
+
+    /// Ensures decreasing the stake by the value would leave enough at stake to cover all contributions,
+    /// as the stake holder can only withdraw its own funds.
+    fn ensure_decrease_leaves_contributions_at_stake(
+        stake: &Stake<T::BlockNumber, BalanceOf<T>, T::SlashId>,
+        stake_id: &T::StakeId,
+        value: BalanceOf<T>,
+    ) -> Result<(), StakeActionError<DecreasingStakeError>> {
+        if let StakingStatus::Staked(ref staked_state) = stake.staking_status {
+            // decreasing by more than the staked amount is reported by the stake itself
+            if value <= staked_state.staked_amount {
+                ensure!(
+                    staked_state.staked_amount - value >= Self::total_contributions(stake_id),
+                    StakeActionError::Error(
+                        DecreasingStakeError::CannotDecreaseStakeBelowContributions
+                    )
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn ensure_can_decrease_stake(
         stake_id: &T::StakeId,
         value: BalanceOf<T>,
     ) -> Result<(), StakeActionError<DecreasingStakeError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
+        Self::ensure_decrease_leaves_contributions_at_stake(&stake, stake_id, value)?;
+
         stake
             .decrease_stake(value, T::Currency::minimum_balance())
             .err()
@@ -936,6 +1263,8 @@ impl<T: Trait> Module<T> {
     ) -> Result<(BalanceOf<T>, NegativeImbalance<T>), StakeActionError<DecreasingStakeError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
+        Self::ensure_decrease_leaves_contributions_at_stake(&stake, stake_id, value)?;
+
         let (deduct_from_pool, staked_amount) =
             stake.decrease_stake(value, T::Currency::minimum_balance())?;
 
@@ -956,6 +1285,8 @@ impl<T: Trait> Module<T> {
     ) -> Result<BalanceOf<T>, StakeActionError<DecreasingStakeError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
+        Self::ensure_decrease_leaves_contributions_at_stake(&stake, stake_id, value)?;
+
         let (deduct_from_pool, staked_amount) =
             stake.decrease_stake(value, T::Currency::minimum_balance())?;
 
@@ -976,6 +1307,25 @@ impl<T: Trait> Module<T> {
     ) -> Result<
         SlashImmediateOutcome<BalanceOf<T>, NegativeImbalance<T>>,
         StakeActionError<ImmediateSlashingError>,
+    > {
+        Self::slash_immediate_with_details(
+            stake_id,
+            slash_amount,
+            unstake_on_zero_staked,
+            SlashDetails::default(),
+        )
+    }
+
+    /// Same as `slash_immediate`, recording the reason and reporter of the slash in the slash ledger.
+    /// Slashed funds not consumed by the staking events handler are routed to the slash destination.
+    pub fn slash_immediate_with_details(
+        stake_id: &T::StakeId,
+        slash_amount: BalanceOf<T>,
+        unstake_on_zero_staked: bool,
+        details: SlashDetails<T::AccountId>,
+    ) -> Result<
+        SlashImmediateOutcome<BalanceOf<T>, NegativeImbalance<T>>,
+        StakeActionError<ImmediateSlashingError>,
     > {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
@@ -1009,16 +1359,24 @@ impl<T: Trait> Module<T> {
             slashed_imbalance,
         );
 
+        // Slashed funds are not returned to the stake holder on unstaking
+        Self::record_slash_and_route_funds(
+            stake_id,
+            None,
+            actually_slashed,
+            details,
+            remaining_imbalance_after_slash_handler,
+        );
+
         let remaining_imbalance = if caused_unstake {
-            // Notify unstaked handler with any remaining unused imbalance
-            // from the slashing event handler
+            // Notify unstaked handler, nothing remains at stake to be returned
             T::StakingEventsHandler::unstaked(
                 &stake_id,
                 staked_amount_before_slash,
-                remaining_imbalance_after_slash_handler,
+                NegativeImbalance::<T>::zero(),
             )
         } else {
-            remaining_imbalance_after_slash_handler
+            NegativeImbalance::<T>::zero()
         };
 
         Ok(SlashImmediateOutcome {
@@ -1034,6 +1392,22 @@ impl<T: Trait> Module<T> {
         stake_id: &T::StakeId,
         slash_amount: BalanceOf<T>,
         slash_period: T::BlockNumber,
+    ) -> Result<T::SlashId, StakeActionError<InitiateSlashingError>> {
+        Self::initiate_slashing_with_details(
+            stake_id,
+            slash_amount,
+            slash_period,
+            SlashDetails::default(),
+        )
+    }
+
+    /// Same as `initiate_slashing`, keeping the reason and reporter of the slash to be recorded in the
+    /// slash ledger once the slash is executed.
+    pub fn initiate_slashing_with_details(
+        stake_id: &T::StakeId,
+        slash_amount: BalanceOf<T>,
+        slash_period: T::BlockNumber,
+        details: SlashDetails<T::AccountId>,
     ) -> Result<T::SlashId, StakeActionError<InitiateSlashingError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
@@ -1044,9 +1418,38 @@ impl<T: Trait> Module<T> {
         )?;
 
         <Stakes<T>>::insert(stake_id, stake);
+
+        if details != SlashDetails::default() {
+            <PendingSlashDetails<T>>::insert(&(*stake_id, slash_id), details);
+        }
+
         Ok(slash_id)
     }
 
+    /// Records an executed slash in the slash ledger and hands the slashed funds over to the slash destination.
+    fn record_slash_and_route_funds(
+        stake_id: &T::StakeId,
+        slash_id: Option<T::SlashId>,
+        slashed_amount: BalanceOf<T>,
+        details: SlashDetails<T::AccountId>,
+        imbalance: NegativeImbalance<T>,
+    ) {
+        let record = SlashRecord {
+            stake_id: *stake_id,
+            slash_id,
+            slashed_amount,
+            reason: details.reason,
+            reporter: details.reporter,
+            executed_at_block: <system::Module<T>>::block_number(),
+        };
+
+        T::SlashDestination::receive_slashed_funds(&record, imbalance);
+
+        let record_id = Self::slash_records_created();
+        <SlashLedger<T>>::insert(record_id, record);
+        SlashRecordsCreated::put(record_id + 1);
+    }
+
     /// Pause an ongoing slashing
     pub fn pause_slashing(
         stake_id: &T::StakeId,
@@ -1084,10 +1487,120 @@ impl<T: Trait> Module<T> {
         stake.cancel_slashing(slash_id)?;
 
         <Stakes<T>>::insert(stake_id, stake);
+        <PendingSlashDetails<T>>::remove(&(*stake_id, *slash_id));
+        <SlashAppeals<T>>::remove(&(*stake_id, *slash_id));
 
         Ok(())
     }
 
+    /// Appeal an ongoing slashing, pausing it if it is active. Only one appeal can be under review
+    /// for a slash at a time.
+    pub fn appeal_slash(
+        stake_id: &T::StakeId,
+        slash_id: &T::SlashId,
+        appellant: &T::AccountId,
+        rationale: Vec<u8>,
+    ) -> Result<(), StakeActionError<SlashAppealError>> {
+        let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
+
+        ensure!(
+            !<SlashAppeals<T>>::exists(&(*stake_id, *slash_id)),
+            StakeActionError::Error(SlashAppealError::AlreadyAppealed)
+        );
+
+        ensure!(
+            rationale.len() <= MAX_SLASH_APPEAL_RATIONALE_LENGTH,
+            StakeActionError::Error(SlashAppealError::RationaleTooLong)
+        );
+
+        let slash_was_active = match stake.pause_slashing(slash_id) {
+            Ok(()) => true,
+            Err(PauseSlashingError::AlreadyPaused) => false,
+            Err(PauseSlashingError::SlashNotFound) => {
+                return Err(StakeActionError::Error(SlashAppealError::SlashNotFound))
+            }
+            Err(PauseSlashingError::NotStaked) => {
+                return Err(StakeActionError::Error(SlashAppealError::NotStaked))
+            }
+        };
+
+        <Stakes<T>>::insert(stake_id, stake);
+
+        <SlashAppeals<T>>::insert(
+            &(*stake_id, *slash_id),
+            SlashAppeal {
+                appellant: appellant.clone(),
+                filed_at_block: <system::Module<T>>::block_number(),
+                rationale,
+                slash_was_active,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Reject the appeal against a slashing, resuming the slashing if it was active when appealed.
+    pub fn uphold_slash(
+        stake_id: &T::StakeId,
+        slash_id: &T::SlashId,
+    ) -> Result<(), StakeActionError<SlashAppealReviewError>> {
+        let appeal = Self::ensure_slash_appeal_exists(stake_id, slash_id)?;
+
+        if appeal.slash_was_active {
+            let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
+
+            // The slash may have already been resumed independently of the appeal
+            match stake.resume_slashing(slash_id) {
+                Ok(()) | Err(ResumeSlashingError::NotPaused) => {}
+                Err(ResumeSlashingError::SlashNotFound) => {
+                    return Err(StakeActionError::Error(
+                        SlashAppealReviewError::SlashNotFound,
+                    ))
+                }
+                Err(ResumeSlashingError::NotStaked) => {
+                    return Err(StakeActionError::Error(SlashAppealReviewError::NotStaked))
+                }
+            }
+
+            <Stakes<T>>::insert(stake_id, stake);
+        }
+
+        <SlashAppeals<T>>::remove(&(*stake_id, *slash_id));
+
+        Ok(())
+    }
+
+    /// Accept the appeal against a slashing, cancelling the slashing.
+    pub fn cancel_slash_on_appeal(
+        stake_id: &T::StakeId,
+        slash_id: &T::SlashId,
+    ) -> Result<(), StakeActionError<SlashAppealReviewError>> {
+        Self::ensure_slash_appeal_exists(stake_id, slash_id)?;
+
+        Self::cancel_slashing(stake_id, slash_id).map_err(|err| match err {
+            StakeActionError::StakeNotFound => StakeActionError::StakeNotFound,
+            StakeActionError::Error(CancelSlashingError::SlashNotFound) => {
+                StakeActionError::Error(SlashAppealReviewError::SlashNotFound)
+            }
+            StakeActionError::Error(CancelSlashingError::NotStaked) => {
+                StakeActionError::Error(SlashAppealReviewError::NotStaked)
+            }
+        })
+    }
+
+    fn ensure_slash_appeal_exists(
+        stake_id: &T::StakeId,
+        slash_id: &T::SlashId,
+    ) -> Result<SlashAppeal<T::AccountId, T::BlockNumber>, StakeActionError<SlashAppealReviewError>>
+    {
+        ensure!(
+            <SlashAppeals<T>>::exists(&(*stake_id, *slash_id)),
+            StakeActionError::Error(SlashAppealReviewError::AppealNotFound)
+        );
+
+        Ok(Self::slash_appeals(&(*stake_id, *slash_id)))
+    }
+
     /// Initiate unstaking of a Staked stake.
     pub fn initiate_unstaking(
         stake_id: &T::StakeId,
@@ -1151,16 +1664,27 @@ impl<T: Trait> Module<T> {
             }
 
             for (slash_id, slashed_amount, staked_amount) in slashed.into_iter() {
+                // an appeal left on a slash which was resumed independently is moot now
+                <SlashAppeals<T>>::remove(&(stake_id, slash_id));
+
                 // remove the slashed amount from the pool
                 let imbalance = Self::withdraw_funds_from_stake_pool(slashed_amount);
 
-                let _ = T::StakingEventsHandler::slashed(
+                let remaining_imbalance = T::StakingEventsHandler::slashed(
                     &stake_id,
                     Some(slash_id),
                     slashed_amount,
                     staked_amount,
                     imbalance,
                 );
+
+                Self::record_slash_and_route_funds(
+                    &stake_id,
+                    Some(slash_id),
+                    slashed_amount,
+                    <PendingSlashDetails<T>>::take(&(stake_id, slash_id)),
+                    remaining_imbalance,
+                );
             }
 
             if let Some(staked_amount) = unstaked {
@@ -11,7 +11,7 @@ use runtime_primitives::{
     traits::{BlakeTwo256, IdentityLookup},
     Perbill,
 };
-use srml_support::{impl_outer_origin, parameter_types};
+use srml_support::{impl_outer_origin, parameter_types, traits::EnsureOrigin};
 
 impl_outer_origin! {
     pub enum Origin for Test {}
@@ -53,6 +53,7 @@ parameter_types! {
     pub const TransactionBaseFee: u32 = 5;
     pub const TransactionByteFee: u32 = 0;
     pub const StakePoolId: [u8; 8] = *b"joystake";
+    pub const ReporterRewardPercentPts: u16 = 10;
 }
 
 impl balances::Trait for Test {
@@ -72,10 +73,39 @@ impl balances::Trait for Test {
     type CreationFee = CreationFee;
 }
 
+/// Account allowed to appeal slashes of any stake in tests.
+pub const SLASH_APPELLANT_ACCOUNT: u64 = 77;
+
+pub struct TestSlashAppellants {}
+impl SlashAppellants<Test> for TestSlashAppellants {
+    fn can_appeal(_stake_id: &<Test as Trait>::StakeId, account_id: &u64) -> bool {
+        *account_id == SLASH_APPELLANT_ACCOUNT
+    }
+}
+
+/// Account allowed to review slash appeals in tests.
+pub const SLASH_APPEAL_REVIEWER_ACCOUNT: u64 = 78;
+
+pub struct TestSlashAppealAuthority {}
+impl EnsureOrigin<Origin> for TestSlashAppealAuthority {
+    type Success = ();
+
+    fn try_origin(origin: Origin) -> Result<(), Origin> {
+        origin.into().and_then(|origin| match origin {
+            system::RawOrigin::Signed(SLASH_APPEAL_REVIEWER_ACCOUNT) => Ok(()),
+            origin => Err(Origin::from(origin)),
+        })
+    }
+}
+
 impl Trait for Test {
+    type Event = ();
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
+    type SlashAppellants = TestSlashAppellants;
+    type SlashAppealAuthority = TestSlashAppealAuthority;
+    type SlashDestination = RewardReporter<Test, ReporterRewardPercentPts, ()>;
     type StakeId = u64;
     type SlashId = u64;
 }
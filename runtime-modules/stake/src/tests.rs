@@ -977,3 +977,409 @@ fn immediate_slashing_cannot_slash_zero() {
         );
     });
 }
+
+#[test]
+fn contributing_to_stake() {
+    build_test_externalities().execute_with(|| {
+        let starting_pool_stake = Balances::minimum_balance() + 5000;
+        let _ =
+            Balances::deposit_creating(&StakePool::stake_pool_account_id(), starting_pool_stake);
+
+        let starting_stake = Balances::minimum_balance() + 100;
+        <Stakes<Test>>::insert(
+            &100,
+            Stake {
+                created: 0,
+                staking_status: StakingStatus::Staked(StakedState {
+                    staked_amount: starting_stake,
+                    ongoing_slashes: BTreeMap::new(),
+                    next_slash_id: 0,
+                    staked_status: StakedStatus::Normal,
+                }),
+            },
+        );
+
+        let contribution: u64 = 500;
+        let contributor_account: u64 = 2;
+        let staker_account: u64 = 1;
+
+        let _ = Balances::deposit_creating(
+            &contributor_account,
+            Balances::minimum_balance() + 2 * contribution,
+        );
+
+        // contribution is recorded per contributor and accumulates
+        assert_eq!(
+            StakePool::contribute_to_stake_from_account(&100, &contributor_account, contribution),
+            Ok(starting_stake + contribution)
+        );
+        assert_eq!(
+            StakePool::contribute_to_stake_from_account(&100, &contributor_account, contribution),
+            Ok(starting_stake + 2 * contribution)
+        );
+        assert_eq!(
+            StakePool::contributions_by_stake_id(&100).get(&contributor_account),
+            Some(&(2 * contribution))
+        );
+        assert_eq!(StakePool::total_contributions(&100), 2 * contribution);
+
+        // failed contribution is not recorded
+        assert!(StakePool::contribute_to_stake_from_account(
+            &100,
+            &contributor_account,
+            Balances::free_balance(&contributor_account) + 1
+        )
+        .is_err());
+        assert_eq!(StakePool::total_contributions(&100), 2 * contribution);
+
+        // stake holder cannot withdraw contributed funds
+        assert_err!(
+            StakePool::decrease_stake_to_account(&100, &staker_account, starting_stake + 1),
+            StakeActionError::Error(DecreasingStakeError::CannotDecreaseStakeBelowContributions)
+        );
+        assert_err!(
+            StakePool::ensure_can_decrease_stake(&100, starting_stake + 1),
+            StakeActionError::Error(DecreasingStakeError::CannotDecreaseStakeBelowContributions)
+        );
+
+        assert_eq!(
+            StakePool::decrease_stake_to_account(&100, &staker_account, starting_stake),
+            Ok(2 * contribution)
+        );
+    });
+}
+
+#[test]
+fn contributions_are_reduced_on_slashing_and_refunded_on_unstaking() {
+    build_test_externalities().execute_with(|| {
+        let contributor_a: u64 = 2;
+        let contributor_b: u64 = 3;
+
+        let mut contributions = BTreeMap::new();
+        contributions.insert(contributor_a, 3000);
+        contributions.insert(contributor_b, 1000);
+        <ContributionsByStakeId<Test>>::insert(&100, contributions);
+
+        // half of a stake of 8000 was slashed
+        let _ = ContributionsStakingEventsHandler::<Test>::slashed(
+            &100,
+            None,
+            4000,
+            4000,
+            NegativeImbalance::<Test>::zero(),
+        );
+
+        assert_eq!(
+            StakePool::contributions_by_stake_id(&100).get(&contributor_a),
+            Some(&1500)
+        );
+        assert_eq!(
+            StakePool::contributions_by_stake_id(&100).get(&contributor_b),
+            Some(&500)
+        );
+
+        // unstaking refunds contributors and passes on the rest
+        let unstaked_amount = Balances::minimum_balance() * 2 + 4000;
+        let imbalance = Balances::issue(unstaked_amount);
+        let _ = Balances::deposit_creating(&contributor_a, Balances::minimum_balance());
+        let _ = Balances::deposit_creating(&contributor_b, Balances::minimum_balance());
+
+        let remaining_imbalance =
+            ContributionsStakingEventsHandler::<Test>::unstaked(&100, unstaked_amount, imbalance);
+
+        assert_eq!(remaining_imbalance.peek(), unstaked_amount - 2000);
+        assert_eq!(
+            Balances::free_balance(&contributor_a),
+            Balances::minimum_balance() + 1500
+        );
+        assert_eq!(
+            Balances::free_balance(&contributor_b),
+            Balances::minimum_balance() + 500
+        );
+        assert!(!<ContributionsByStakeId<Test>>::exists(&100));
+    });
+}
+
+fn setup_staked_stake_with_slash(staked_amount: u64) -> (u64, u64) {
+    let _ = Balances::deposit_creating(&StakePool::stake_pool_account_id(), staked_amount);
+
+    let stake_id = StakePool::create_stake();
+    <Stakes<Test>>::insert(
+        &stake_id,
+        Stake {
+            created: System::block_number(),
+            staking_status: StakingStatus::Staked(StakedState {
+                staked_amount,
+                ongoing_slashes: BTreeMap::new(),
+                next_slash_id: 0,
+                staked_status: StakedStatus::Normal,
+            }),
+        },
+    );
+
+    let slash_id = StakePool::initiate_slashing(&stake_id, 5000, 10)
+        .ok()
+        .unwrap();
+
+    (stake_id, slash_id)
+}
+
+fn slash_is_active(stake_id: u64, slash_id: u64) -> Option<bool> {
+    match StakePool::stakes(&stake_id).staking_status {
+        StakingStatus::Staked(staked_state) => staked_state
+            .ongoing_slashes
+            .get(&slash_id)
+            .map(|slash| slash.is_active),
+        _ => None,
+    }
+}
+
+#[test]
+fn filing_slash_appeal() {
+    build_test_externalities().execute_with(|| {
+        let (stake_id, slash_id) =
+            setup_staked_stake_with_slash(Balances::minimum_balance() + 10000);
+
+        assert_err!(
+            StakePool::file_slash_appeal(
+                Origin::signed(SLASH_APPELLANT_ACCOUNT + 1),
+                stake_id,
+                slash_id,
+                b"unwarranted".to_vec()
+            ),
+            "Account is not allowed to appeal slashes of this stake"
+        );
+
+        assert_err!(
+            StakePool::file_slash_appeal(
+                Origin::signed(SLASH_APPELLANT_ACCOUNT),
+                stake_id,
+                slash_id + 1,
+                b"unwarranted".to_vec()
+            ),
+            "Slash not found"
+        );
+
+        assert_err!(
+            StakePool::file_slash_appeal(
+                Origin::signed(SLASH_APPELLANT_ACCOUNT),
+                stake_id,
+                slash_id,
+                vec![0; MAX_SLASH_APPEAL_RATIONALE_LENGTH + 1]
+            ),
+            "Slash appeal rationale too long"
+        );
+
+        assert_ok!(StakePool::file_slash_appeal(
+            Origin::signed(SLASH_APPELLANT_ACCOUNT),
+            stake_id,
+            slash_id,
+            b"unwarranted".to_vec()
+        ));
+
+        // appealing pauses the slash
+        assert_eq!(slash_is_active(stake_id, slash_id), Some(false));
+        assert_eq!(
+            StakePool::slash_appeals(&(stake_id, slash_id)),
+            SlashAppeal {
+                appellant: SLASH_APPELLANT_ACCOUNT,
+                filed_at_block: System::block_number(),
+                rationale: b"unwarranted".to_vec(),
+                slash_was_active: true,
+            }
+        );
+
+        assert_err!(
+            StakePool::file_slash_appeal(
+                Origin::signed(SLASH_APPELLANT_ACCOUNT),
+                stake_id,
+                slash_id,
+                b"unwarranted".to_vec()
+            ),
+            "Slash has already been appealed"
+        );
+
+        // paused slash is not executed while the appeal is under review
+        for _ in 0..20 {
+            StakePool::on_finalize(System::block_number());
+        }
+        assert_eq!(slash_is_active(stake_id, slash_id), Some(false));
+    });
+}
+
+#[test]
+fn upholding_appealed_slash() {
+    build_test_externalities().execute_with(|| {
+        let (stake_id, slash_id) =
+            setup_staked_stake_with_slash(Balances::minimum_balance() + 10000);
+
+        assert_err!(
+            StakePool::uphold_appealed_slash(
+                Origin::signed(SLASH_APPEAL_REVIEWER_ACCOUNT),
+                stake_id,
+                slash_id
+            ),
+            "Slash appeal not found"
+        );
+
+        assert_ok!(StakePool::file_slash_appeal(
+            Origin::signed(SLASH_APPELLANT_ACCOUNT),
+            stake_id,
+            slash_id,
+            b"unwarranted".to_vec()
+        ));
+
+        assert!(StakePool::uphold_appealed_slash(
+            Origin::signed(SLASH_APPELLANT_ACCOUNT),
+            stake_id,
+            slash_id
+        )
+        .is_err());
+
+        assert!(StakePool::uphold_appealed_slash(Origin::ROOT, stake_id, slash_id).is_err());
+
+        assert_ok!(StakePool::uphold_appealed_slash(
+            Origin::signed(SLASH_APPEAL_REVIEWER_ACCOUNT),
+            stake_id,
+            slash_id
+        ));
+
+        assert_eq!(slash_is_active(stake_id, slash_id), Some(true));
+        assert!(!<SlashAppeals<Test>>::exists(&(stake_id, slash_id)));
+    });
+}
+
+#[test]
+fn cancelling_appealed_slash() {
+    build_test_externalities().execute_with(|| {
+        let (stake_id, slash_id) =
+            setup_staked_stake_with_slash(Balances::minimum_balance() + 10000);
+
+        assert_ok!(StakePool::file_slash_appeal(
+            Origin::signed(SLASH_APPELLANT_ACCOUNT),
+            stake_id,
+            slash_id,
+            b"unwarranted".to_vec()
+        ));
+
+        assert!(StakePool::cancel_appealed_slash(
+            Origin::signed(SLASH_APPELLANT_ACCOUNT),
+            stake_id,
+            slash_id
+        )
+        .is_err());
+
+        assert_ok!(StakePool::cancel_appealed_slash(
+            Origin::signed(SLASH_APPEAL_REVIEWER_ACCOUNT),
+            stake_id,
+            slash_id
+        ));
+
+        assert_eq!(slash_is_active(stake_id, slash_id), None);
+        assert!(!<SlashAppeals<Test>>::exists(&(stake_id, slash_id)));
+
+        assert_err!(
+            StakePool::cancel_appealed_slash(
+                Origin::signed(SLASH_APPEAL_REVIEWER_ACCOUNT),
+                stake_id,
+                slash_id
+            ),
+            "Slash appeal not found"
+        );
+    });
+}
+
+#[test]
+fn immediate_slashing_is_recorded_in_slash_ledger() {
+    build_test_externalities().execute_with(|| {
+        let staked_amount = Balances::minimum_balance() + 10000;
+        let (stake_id, _) = setup_staked_stake_with_slash(staked_amount);
+        let reporter: u64 = 5;
+
+        let outcome = StakePool::slash_immediate_with_details(
+            &stake_id,
+            10000,
+            false,
+            SlashDetails {
+                reason: b"misbehaved".to_vec(),
+                reporter: Some(reporter),
+            },
+        )
+        .ok()
+        .unwrap();
+
+        assert_eq!(outcome.actually_slashed, 10000);
+
+        assert_eq!(StakePool::slash_records_created(), 1);
+        assert_eq!(
+            StakePool::slash_ledger(0),
+            SlashRecord {
+                stake_id,
+                slash_id: None,
+                slashed_amount: 10000,
+                reason: b"misbehaved".to_vec(),
+                reporter: Some(reporter),
+                executed_at_block: System::block_number(),
+            }
+        );
+
+        // reporter receives its share of the slashed funds, the rest is burned
+        assert_eq!(
+            Balances::free_balance(&reporter),
+            10000 * ReporterRewardPercentPts::get() as u64 / 100
+        );
+    });
+}
+
+#[test]
+fn executed_slashing_is_recorded_in_slash_ledger() {
+    build_test_externalities().execute_with(|| {
+        let staked_amount = Balances::minimum_balance() + 10000;
+        let _ = Balances::deposit_creating(&StakePool::stake_pool_account_id(), staked_amount);
+
+        let stake_id = StakePool::create_stake();
+        <Stakes<Test>>::insert(
+            &stake_id,
+            Stake {
+                created: System::block_number(),
+                staking_status: StakingStatus::Staked(StakedState {
+                    staked_amount,
+                    ongoing_slashes: BTreeMap::new(),
+                    next_slash_id: 0,
+                    staked_status: StakedStatus::Normal,
+                }),
+            },
+        );
+
+        let details = SlashDetails {
+            reason: b"misbehaved".to_vec(),
+            reporter: None,
+        };
+
+        let slash_id =
+            StakePool::initiate_slashing_with_details(&stake_id, 5000, 1, details.clone())
+                .ok()
+                .unwrap();
+
+        assert_eq!(
+            StakePool::pending_slash_details(&(stake_id, slash_id)),
+            details
+        );
+
+        StakePool::on_finalize(System::block_number());
+
+        assert!(!<PendingSlashDetails<Test>>::exists(&(stake_id, slash_id)));
+        assert_eq!(StakePool::slash_records_created(), 1);
+        assert_eq!(
+            StakePool::slash_ledger(0),
+            SlashRecord {
+                stake_id,
+                slash_id: Some(slash_id),
+                slashed_amount: 5000,
+                reason: details.reason,
+                reporter: None,
+                executed_at_block: System::block_number(),
+            }
+        );
+    });
+}
@@ -1,10 +1,15 @@
 use crate::data_object_type_registry::Trait as DOTRTrait;
-use crate::traits::{ContentIdExists, IsActiveDataObjectType};
+use crate::traits::{
+    ContentIdExists, ContentRemoved, DataObjectTypeConstraints, IsActiveDataObjectType,
+    IsChannelOwner, IsContentCurator,
+};
 use codec::{Codec, Decode, Encode};
+use common::storage_migration::translate_map_value;
 use roles::actors;
 use roles::traits::Roles;
+use rstd::collections::btree_set::BTreeSet;
 use rstd::prelude::*;
-use sr_primitives::traits::{MaybeSerialize, Member, SimpleArithmetic};
+use sr_primitives::traits::{Hash, MaybeSerialize, Member, One, SimpleArithmetic, Zero};
 use srml_support::{decl_event, decl_module, decl_storage, dispatch, ensure, Parameter};
 use system::{self, ensure_root, ensure_signed};
 
@@ -22,8 +27,14 @@ pub trait Trait: timestamp::Trait + system::Trait + DOTRTrait + membership::memb
         + MaybeSerialize
         + PartialEq;
 
+    type ChannelId: Parameter + Member + MaybeSerialize + Copy + Ord + Default;
+
     type Roles: Roles<Self>;
     type IsActiveDataObjectType: IsActiveDataObjectType<Self>;
+    type DataObjectTypeConstraints: DataObjectTypeConstraints<Self>;
+    type ContentCurators: IsContentCurator<Self>;
+    type OnContentRemoved: ContentRemoved<Self>;
+    type ChannelOwners: IsChannelOwner<Self>;
 }
 
 static MSG_CID_NOT_FOUND: &str = "Content with this ID not found.";
@@ -31,6 +42,57 @@ static MSG_LIAISON_REQUIRED: &str = "Only the liaison for the content may modify
 static MSG_CREATOR_MUST_BE_MEMBER: &str = "Only active members may create content.";
 static MSG_DO_TYPE_MUST_BE_ACTIVE: &str =
     "Cannot create content for inactive or missing data object type.";
+static MSG_DO_SIZE_EXCEEDS_TYPE_MAX_SIZE: &str =
+    "Content size exceeds the maximum size of its data object type.";
+static MSG_QUOTA_SIZE_LIMIT_EXCEEDED: &str =
+    "Content size exceeds the remaining storage quota of the member.";
+static MSG_QUOTA_OBJECTS_LIMIT_EXCEEDED: &str =
+    "Member has reached the maximum number of data objects of their storage quota.";
+static MSG_ONLY_STORAGE_LEAD_OR_ROOT: &str =
+    "Only the storage lead or root may update storage quotas and metadata schemas.";
+static MSG_ONLY_OWNER_MAY_REMOVE_CONTENT: &str = "Only the owner of the content may remove it.";
+static MSG_ONLY_CURATOR_MAY_FORCE_REMOVE_CONTENT: &str =
+    "Only content curators may force the removal of content.";
+static MSG_ONLY_REJECTED_CONTENT_MAY_BE_FORCE_REMOVED: &str =
+    "Only content rejected by its liaison may be force removed.";
+static MSG_REJECTION_REASON_TOO_LONG: &str = "Rejection reason is too long.";
+static MSG_ONLY_STORAGE_PROVIDER_MAY_ADVERTISE_CAPACITY: &str =
+    "Only storage providers may advertise their capacity.";
+static MSG_NO_LIAISON_WITH_SUFFICIENT_CAPACITY: &str =
+    "No storage provider with sufficient capacity is available as liaison.";
+static MSG_ONLY_OWNER_MAY_SET_METADATA: &str =
+    "Only the owner of the content may set its metadata.";
+static MSG_METADATA_SCHEMA_NOT_FOUND: &str = "Content metadata schema not found.";
+static MSG_METADATA_TITLE_INVALID: &str =
+    "Content title must be non empty and within the length allowed by the schema.";
+static MSG_METADATA_DESCRIPTION_TOO_LONG: &str =
+    "Content description is longer than allowed by the schema.";
+static MSG_METADATA_THUMBNAIL_REQUIRED: &str = "The metadata schema requires a thumbnail.";
+static MSG_METADATA_THUMBNAIL_NOT_FOUND: &str = "Thumbnail content not found.";
+static MSG_ONLY_CHANNEL_OWNER_MAY_PUBLISH: &str =
+    "Only the channel owner may publish content into the channel.";
+static MSG_INVALID_MANIFEST: &str =
+    "Content manifest must have between one and the maximum number of non empty chunks.";
+static MSG_CONTENT_SIZE_OVERFLOW: &str = "Total size of the content chunks overflows.";
+static MSG_MANIFEST_ROOT_HASH_MISMATCH: &str =
+    "Manifest root hash does not match the hashes of its chunks.";
+static MSG_MANIFEST_NOT_FOUND: &str = "No chunk manifest found for this content.";
+static MSG_CHUNK_NOT_FOUND: &str = "No chunk found at this index of the manifest.";
+static MSG_CHUNK_ALREADY_ACCEPTED: &str = "Chunk was already accepted.";
+static MSG_CONTENT_NOT_PENDING: &str = "Content was already judged by its liaison.";
+static MSG_CHUNKED_CONTENT_ACCEPTED_BY_CHUNK: &str = "Chunked content is accepted chunk by chunk.";
+static MSG_LIAISON_JUDGEMENT_TIMEOUT_ZERO: &str = "Liaison judgement timeout must not be zero.";
+
+const MAX_REJECTION_REASON_LENGTH: usize = 1024;
+// Bounds the chunk acceptance records cleared one by one when a liaison is reassigned
+const MAX_CHUNKS_PER_MANIFEST: usize = 1_000;
+// Bounds the timed out content reassigned in a block, selecting a liaison walks all storage
+// providers. The rest is reassigned in the following blocks.
+const MAX_REASSIGNMENTS_PER_BLOCK: usize = 50;
+const DEFAULT_LIAISON_JUDGEMENT_TIMEOUT: u32 = 600; // ~1 hour with 6s blocks
+
+const DEFAULT_QUOTA_SIZE_LIMIT: u64 = 10 * 1024 * 1024 * 1024; // 10 GiB
+const DEFAULT_QUOTA_OBJECTS_LIMIT: u64 = 1000;
 
 #[derive(Clone, Encode, Decode, PartialEq, Debug)]
 pub struct BlockAndTime<T: Trait> {
@@ -54,20 +116,86 @@ impl Default for LiaisonJudgement {
 #[derive(Clone, Encode, Decode, PartialEq, Debug)]
 pub struct DataObject<T: Trait> {
     pub owner: T::AccountId,
+    pub owner_member_id: Option<T::MemberId>, // member whose storage quota it is charged to, if any
     pub added_at: BlockAndTime<T>,
     pub type_id: <T as DOTRTrait>::DataObjectTypeId,
     pub size: u64,
     pub liaison: T::AccountId,
     pub liaison_judgement: LiaisonJudgement,
     pub ipfs_content_id: Vec<u8>, // shoule we use rust multi-format crate?
-                                  // TODO signing_key: public key supplied by the uploader,
-                                  // they sigh the content with this key
-
-                                  // TODO add support for this field (Some if judgment == Rejected)
-                                  // pub rejection_reason: Option<Vec<u8>>,
+    // TODO signing_key: public key supplied by the uploader,
+    // they sigh the content with this key
+    pub rejection_reason: Option<Vec<u8>>, // Some if judgement == Rejected
+    pub judgement_deadline: Option<T::BlockNumber>, // Some if judgement == Pending
 }
 
+// Layout of data objects before storage quotas and rejection reasons were introduced.
 #[derive(Clone, Encode, Decode, PartialEq, Debug)]
+pub struct OldDataObject<T: Trait> {
+    pub owner: T::AccountId,
+    pub added_at: BlockAndTime<T>,
+    pub type_id: <T as DOTRTrait>::DataObjectTypeId,
+    pub size: u64,
+    pub liaison: T::AccountId,
+    pub liaison_judgement: LiaisonJudgement,
+    pub ipfs_content_id: Vec<u8>,
+}
+
+// A chunk of content, identified by the hash of its bytes.
+#[derive(Clone, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct ContentChunk<Hash> {
+    pub hash: Hash,
+    pub size: u64,
+}
+
+// Ordered chunks of content uploaded in parts, with the root hash over all chunks,
+// the hash of the list of chunk hashes.
+#[derive(Clone, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct ContentManifest<Hash> {
+    pub root_hash: Hash,
+    pub chunks: Vec<ContentChunk<Hash>>,
+}
+
+// Capacity advertised by a storage provider for acting as liaison.
+#[derive(Clone, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct StorageProviderCapacity {
+    pub free_bytes: u64,
+    pub max_concurrent_uploads: u32,
+}
+
+// Content awaiting the judgement of a liaison.
+#[derive(Clone, Encode, Decode, Default, PartialEq, Debug)]
+pub struct LiaisonLoad {
+    pub pending_bytes: u64,
+    pub pending_uploads: u32,
+}
+
+// Storage limits of a member, in total bytes and number of data objects.
+// The default limits also apply to members whose content was added before quotas were
+// introduced, their usage is computed from that content when upgrading.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
+pub struct QuotaLimits {
+    pub size_limit: u64,
+    pub objects_limit: u64,
+}
+
+impl Default for QuotaLimits {
+    fn default() -> Self {
+        QuotaLimits {
+            size_limit: DEFAULT_QUOTA_SIZE_LIMIT,
+            objects_limit: DEFAULT_QUOTA_OBJECTS_LIMIT,
+        }
+    }
+}
+
+// Storage used by a member, counted against their quota limits.
+#[derive(Clone, Encode, Decode, Default, PartialEq, Debug)]
+pub struct StorageUsage {
+    pub size_used: u64,
+    pub objects_used: u64,
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
 pub enum ContentVisibility {
     Draft, // TODO rename to Unlisted?
     Public,
@@ -79,6 +207,24 @@ impl Default for ContentVisibility {
     }
 }
 
+// Constraints on the content metadata referencing the schema.
+#[derive(Clone, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct ContentMetadataSchema {
+    pub max_title_length: u32,
+    pub max_description_length: u32,
+    pub thumbnail_required: bool,
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
+pub struct ContentMetadata<T: Trait> {
+    pub schema: T::SchemaId,
+    pub title: Vec<u8>,
+    pub description: Vec<u8>,
+    pub thumbnail: Option<T::ContentId>, // content id of the thumbnail image
+    pub visibility: ContentVisibility,
+    pub channel_id: Option<T::ChannelId>, // channel the content is published into
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataDirectory {
 
@@ -93,13 +239,59 @@ decl_storage! {
 
         // Default storage provider account id, overrides all active storage providers as liason if set
         pub PrimaryLiaisonAccountId get(primary_liaison_account_id): Option<T::AccountId>;
+
+        // Account which may update storage quotas, besides root
+        pub StorageLeadAccountId get(storage_lead_account_id): Option<T::AccountId>;
+
+        // Quota limits for members without individual limits
+        pub DefaultQuotaLimits get(default_quota_limits): QuotaLimits;
+
+        // Individual quota limits, overriding the default quota limits
+        pub QuotaLimitsByMemberId get(quota_limits_by_member_id):
+            map T::MemberId => Option<QuotaLimits>;
+
+        pub StorageUsageByMemberId get(storage_usage_by_member_id):
+            map T::MemberId => StorageUsage;
+
+        // Capacity advertised by storage providers, only those who advertised may become liaisons
+        pub CapacityByStorageProvider get(capacity_by_storage_provider):
+            map T::AccountId => Option<StorageProviderCapacity>;
+
+        pub LoadByLiaison get(load_by_liaison): map T::AccountId => LiaisonLoad;
+
+        // Number of blocks a liaison has to judge content before it is reassigned
+        pub LiaisonJudgementTimeout get(liaison_judgement_timeout): T::BlockNumber =
+            T::BlockNumber::from(DEFAULT_LIAISON_JUDGEMENT_TIMEOUT);
+
+        // Content to be reassigned at a block if still not judged by then
+        pub JudgementDeadlines get(judgement_deadlines): map T::BlockNumber => Vec<T::ContentId>;
+
+        pub MetadataSchemaById get(metadata_schema_by_id):
+            map T::SchemaId => Option<ContentMetadataSchema>;
+
+        pub MetadataByContentId get(metadata_by_content_id):
+            map T::ContentId => Option<ContentMetadata<T>>;
+
+        // Content published into a channel, in order of publication
+        pub ContentIdsByChannelId get(content_ids_by_channel_id):
+            map T::ChannelId => Vec<T::ContentId>;
+
+        pub ManifestByContentId get(manifest_by_content_id):
+            map T::ContentId => Option<ContentManifest<T::Hash>>;
+
+        // Chunks of the manifest acknowledged by the liaison, by chunk index
+        pub ChunkAccepted get(chunk_accepted): map (T::ContentId, u32) => bool;
+
+        pub AcceptedChunkCount get(accepted_chunk_count): map T::ContentId => u32;
     }
 }
 
 decl_event! {
     pub enum Event<T> where
         <T as Trait>::ContentId,
-        <T as system::Trait>::AccountId
+        <T as system::Trait>::AccountId,
+        <T as membership::members::Trait>::MemberId,
+        <T as Trait>::SchemaId
     {
         // The account is the one who uploaded the content.
         ContentAdded(ContentId, AccountId),
@@ -107,6 +299,27 @@ decl_event! {
         // The account is the liaison - only they can reject or accept
         ContentAccepted(ContentId, AccountId),
         ContentRejected(ContentId, AccountId),
+
+        // The account is the liaison which acknowledged the chunk at the index.
+        ContentChunkAccepted(ContentId, u32, AccountId),
+
+        // The account is the one who removed the content, storage providers
+        // may garbage collect the IPFS object.
+        ContentRemoved(ContentId, Vec<u8>, AccountId),
+
+        StorageProviderCapacityUpdated(AccountId, StorageProviderCapacity),
+
+        // The content was reassigned from the first to the second liaison after
+        // the judgement timed out.
+        LiaisonReassigned(ContentId, AccountId, AccountId),
+
+        DefaultQuotaLimitsUpdated(QuotaLimits),
+        MemberQuotaLimitsUpdated(MemberId, Option<QuotaLimits>),
+
+        MetadataSchemaUpdated(SchemaId, Option<ContentMetadataSchema>),
+
+        // The account is the owner who set the metadata.
+        ContentMetadataUpdated(ContentId, AccountId),
     }
 }
 
@@ -114,6 +327,21 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
+        fn on_initialize(now: T::BlockNumber) {
+            Self::reassign_timed_out_content(now);
+        }
+
+        // Storage providers advertise their capacity to be selected as liaisons. The free bytes
+        // shrink with the content they accept, they re-advertise to correct them.
+        pub fn set_storage_provider_capacity(origin, capacity: StorageProviderCapacity) {
+            let who = ensure_signed(origin)?;
+            ensure!(T::Roles::account_has_role(&who, actors::Role::StorageProvider),
+                MSG_ONLY_STORAGE_PROVIDER_MAY_ADVERTISE_CAPACITY);
+
+            <CapacityByStorageProvider<T>>::insert(&who, capacity.clone());
+            Self::deposit_event(RawEvent::StorageProviderCapacityUpdated(who, capacity));
+        }
+
         // TODO send file_name as param so we could create a Draft metadata in this fn
         pub fn add_content(
             origin,
@@ -123,51 +351,123 @@ decl_module! {
             ipfs_content_id: Vec<u8>
         ) {
             let who = ensure_signed(origin)?;
-            ensure!(<membership::members::Module<T>>::is_member_account(&who), MSG_CREATOR_MUST_BE_MEMBER);
-
-            ensure!(T::IsActiveDataObjectType::is_active_data_object_type(&type_id),
-                MSG_DO_TYPE_MUST_BE_ACTIVE);
-
-            ensure!(!<DataObjectByContentId<T>>::exists(content_id),
-                "Data object aready added under this content id");
+            Self::do_add_content(who, content_id, type_id, size, ipfs_content_id)?;
+        }
 
-            let liaison = match Self::primary_liaison_account_id() {
-                // Select primary liaison if set
-                Some(primary_liaison) => primary_liaison,
+        // Content uploaded in chunks, which the liaison accepts chunk by chunk.
+        pub fn add_chunked_content(
+            origin,
+            content_id: T::ContentId,
+            type_id: <T as DOTRTrait>::DataObjectTypeId,
+            manifest: ContentManifest<T::Hash>,
+            ipfs_content_id: Vec<u8>
+        ) {
+            let who = ensure_signed(origin)?;
 
-                // Select liaison from staked roles if available
-                _ => T::Roles::random_account_for_role(actors::Role::StorageProvider)?
-            };
+            ensure!(!manifest.chunks.is_empty() && manifest.chunks.len() <= MAX_CHUNKS_PER_MANIFEST,
+                MSG_INVALID_MANIFEST);
+            ensure!(manifest.chunks.iter().all(|chunk| chunk.size > 0), MSG_INVALID_MANIFEST);
 
-            // Let's create the entry then
-            let data: DataObject<T> = DataObject {
-                type_id,
-                size,
-                added_at: Self::current_block_and_time(),
-                owner: who.clone(),
-                liaison,
-                liaison_judgement: LiaisonJudgement::Pending,
-                ipfs_content_id,
-            };
+            let size = manifest.chunks
+                .iter()
+                .try_fold(0u64, |size, chunk| size.checked_add(chunk.size))
+                .ok_or(MSG_CONTENT_SIZE_OVERFLOW)?;
+            ensure!(manifest.root_hash == Self::manifest_root_hash(&manifest.chunks),
+                MSG_MANIFEST_ROOT_HASH_MISMATCH);
 
-            <DataObjectByContentId<T>>::insert(&content_id, data);
-            Self::deposit_event(RawEvent::ContentAdded(content_id, who));
+            Self::do_add_content(who, content_id, type_id, size, ipfs_content_id)?;
+            <ManifestByContentId<T>>::insert(content_id, manifest);
         }
 
         // The LiaisonJudgement can be updated, but only by the liaison.
         fn accept_content(origin, content_id: T::ContentId) {
             let who = ensure_signed(origin)?;
-            Self::update_content_judgement(&who, content_id, LiaisonJudgement::Accepted)?;
-            <KnownContentIds<T>>::mutate(|ids| ids.push(content_id));
-            Self::deposit_event(RawEvent::ContentAccepted(content_id, who));
+            ensure!(!<ManifestByContentId<T>>::exists(content_id),
+                MSG_CHUNKED_CONTENT_ACCEPTED_BY_CHUNK);
+            Self::do_accept_content(who, content_id)?;
         }
 
-        fn reject_content(origin, content_id: T::ContentId) {
+        // The liaison acknowledges the chunks it received, the content is accepted
+        // with its last chunk.
+        fn accept_content_chunk(origin, content_id: T::ContentId, chunk_index: u32) {
             let who = ensure_signed(origin)?;
-            Self::update_content_judgement(&who, content_id, LiaisonJudgement::Rejected)?;
+            let data = Self::data_object_by_content_id(&content_id).ok_or(MSG_CID_NOT_FOUND)?;
+            ensure!(data.liaison == who, MSG_LIAISON_REQUIRED);
+            ensure!(data.liaison_judgement == LiaisonJudgement::Pending, MSG_CONTENT_NOT_PENDING);
+
+            let manifest = Self::manifest_by_content_id(&content_id).ok_or(MSG_MANIFEST_NOT_FOUND)?;
+            ensure!((chunk_index as usize) < manifest.chunks.len(), MSG_CHUNK_NOT_FOUND);
+            ensure!(!Self::chunk_accepted((content_id, chunk_index)), MSG_CHUNK_ALREADY_ACCEPTED);
+
+            <ChunkAccepted<T>>::insert((content_id, chunk_index), true);
+            let accepted = <AcceptedChunkCount<T>>::mutate(content_id, |count| {
+                *count += 1;
+                *count
+            });
+            Self::deposit_event(RawEvent::ContentChunkAccepted(content_id, chunk_index, who.clone()));
+
+            if accepted as usize == manifest.chunks.len() {
+                Self::do_accept_content(who, content_id)?;
+            }
+        }
+
+        fn reject_content(origin, content_id: T::ContentId, rejection_reason: Vec<u8>) {
+            let who = ensure_signed(origin)?;
+            ensure!(rejection_reason.len() <= MAX_REJECTION_REASON_LENGTH, MSG_REJECTION_REASON_TOO_LONG);
+            Self::update_content_judgement(&who, content_id, LiaisonJudgement::Rejected, Some(rejection_reason))?;
             Self::deposit_event(RawEvent::ContentRejected(content_id, who));
         }
 
+        // Owners may remove their content, which releases its storage quota.
+        pub fn remove_content(origin, content_id: T::ContentId) {
+            let who = ensure_signed(origin)?;
+            let data = Self::data_object_by_content_id(&content_id).ok_or(MSG_CID_NOT_FOUND)?;
+            ensure!(data.owner == who, MSG_ONLY_OWNER_MAY_REMOVE_CONTENT);
+
+            Self::do_remove_content(content_id, data, who);
+        }
+
+        // Curators may remove content which was rejected by its liaison.
+        pub fn force_remove_content(origin, content_id: T::ContentId) {
+            let who = ensure_signed(origin)?;
+            ensure!(T::ContentCurators::is_content_curator(&who), MSG_ONLY_CURATOR_MAY_FORCE_REMOVE_CONTENT);
+
+            let data = Self::data_object_by_content_id(&content_id).ok_or(MSG_CID_NOT_FOUND)?;
+            ensure!(data.liaison_judgement == LiaisonJudgement::Rejected,
+                MSG_ONLY_REJECTED_CONTENT_MAY_BE_FORCE_REMOVED);
+
+            Self::do_remove_content(content_id, data, who);
+        }
+
+        // Owners attach metadata to their content, which publishes it into the channel if any.
+        pub fn set_content_metadata(origin, content_id: T::ContentId, metadata: ContentMetadata<T>) {
+            let who = ensure_signed(origin)?;
+            let data = Self::data_object_by_content_id(&content_id).ok_or(MSG_CID_NOT_FOUND)?;
+            ensure!(data.owner == who, MSG_ONLY_OWNER_MAY_SET_METADATA);
+
+            Self::ensure_valid_metadata(content_id, &metadata)?;
+
+            if let Some(channel_id) = metadata.channel_id {
+                ensure!(T::ChannelOwners::is_channel_owner(&channel_id, &who),
+                    MSG_ONLY_CHANNEL_OWNER_MAY_PUBLISH);
+            }
+
+            let previous_channel_id = Self::metadata_by_content_id(&content_id)
+                .and_then(|previous| previous.channel_id);
+
+            if previous_channel_id != metadata.channel_id {
+                if let Some(channel_id) = metadata.channel_id {
+                    <ContentIdsByChannelId<T>>::mutate(channel_id, |ids| ids.push(content_id));
+                }
+                if let Some(channel_id) = previous_channel_id {
+                    <ContentIdsByChannelId<T>>::mutate(channel_id, |ids| ids.retain(|id| *id != content_id));
+                }
+            }
+
+            <MetadataByContentId<T>>::insert(content_id, metadata);
+            Self::deposit_event(RawEvent::ContentMetadataUpdated(content_id, who));
+        }
+
         // Sudo methods
 
         fn set_primary_liaison_account_id(origin, account: T::AccountId) {
@@ -180,6 +480,54 @@ decl_module! {
             <PrimaryLiaisonAccountId<T>>::take();
         }
 
+        fn set_liaison_judgement_timeout(origin, timeout: T::BlockNumber) {
+            ensure_root(origin)?;
+            ensure!(!timeout.is_zero(), MSG_LIAISON_JUDGEMENT_TIMEOUT_ZERO);
+            <LiaisonJudgementTimeout<T>>::put(timeout);
+        }
+
+        fn set_storage_lead_account_id(origin, account: T::AccountId) {
+            ensure_root(origin)?;
+            <StorageLeadAccountId<T>>::put(account);
+        }
+
+        fn unset_storage_lead_account_id(origin) {
+            ensure_root(origin)?;
+            <StorageLeadAccountId<T>>::take();
+        }
+
+        // Quota management, by the storage lead or root
+
+        fn set_default_quota_limits(origin, limits: QuotaLimits) {
+            Self::ensure_storage_lead_or_root(origin)?;
+            DefaultQuotaLimits::put(limits.clone());
+            Self::deposit_event(RawEvent::DefaultQuotaLimitsUpdated(limits));
+        }
+
+        // Setting no limits makes the member fall back to the default quota limits.
+        fn set_member_quota_limits(origin, member_id: T::MemberId, limits: Option<QuotaLimits>) {
+            Self::ensure_storage_lead_or_root(origin)?;
+
+            match limits.clone() {
+                Some(limits) => <QuotaLimitsByMemberId<T>>::insert(member_id, limits),
+                None => <QuotaLimitsByMemberId<T>>::remove(member_id),
+            }
+
+            Self::deposit_event(RawEvent::MemberQuotaLimitsUpdated(member_id, limits));
+        }
+
+        // Removing a schema keeps existing metadata, but new metadata can't reference it.
+        fn set_metadata_schema(origin, schema_id: T::SchemaId, schema: Option<ContentMetadataSchema>) {
+            Self::ensure_storage_lead_or_root(origin)?;
+
+            match schema.clone() {
+                Some(schema) => <MetadataSchemaById<T>>::insert(schema_id, schema),
+                None => <MetadataSchemaById<T>>::remove(schema_id),
+            }
+
+            Self::deposit_event(RawEvent::MetadataSchemaUpdated(schema_id, schema));
+        }
+
         fn remove_known_content_id(origin, content_id: T::ContentId) {
             ensure_root(origin)?;
             let upd_content_ids: Vec<T::ContentId> = Self::known_content_ids()
@@ -193,6 +541,25 @@ decl_module! {
             ensure_root(origin)?;
             <KnownContentIds<T>>::put(content_ids);
         }
+
+        // Migrates data objects left in the layout before storage quotas by the runtime upgrade,
+        // which can't enumerate pending and rejected content. Their ids are known from the
+        // ContentAdded events.
+        fn migrate_data_objects_by_content_id(origin, content_ids: Vec<T::ContentId>) {
+            ensure_root(origin)?;
+
+            for content_id in content_ids {
+                Self::migrate_data_object(content_id);
+            }
+        }
+    }
+}
+
+substrate_client::decl_runtime_apis! {
+    /// Storage quotas of members.
+    pub trait StorageQuotaApi<MemberId> where MemberId: Codec {
+        /// Remaining storage quota of the member, as bytes and number of data objects.
+        fn remaining_quota(member_id: MemberId) -> (u64, u64);
     }
 }
 
@@ -210,6 +577,378 @@ impl<T: Trait> ContentIdExists<T> for Module<T> {
 }
 
 impl<T: Trait> Module<T> {
+    /// Quota limits applying to the member, either individual or default.
+    pub fn quota_limits(member_id: T::MemberId) -> QuotaLimits {
+        Self::quota_limits_by_member_id(member_id).unwrap_or_else(Self::default_quota_limits)
+    }
+
+    /// Remaining storage quota of the member, as bytes and number of data objects.
+    pub fn remaining_quota(member_id: T::MemberId) -> (u64, u64) {
+        let limits = Self::quota_limits(member_id);
+        let usage = Self::storage_usage_by_member_id(member_id);
+
+        (
+            limits.size_limit.saturating_sub(usage.size_used),
+            limits.objects_limit.saturating_sub(usage.objects_used),
+        )
+    }
+
+    /// Re-encodes the data objects stored before storage quotas and rejection reasons were
+    /// introduced, see `migrate_data_object`. Data objects can't be enumerated, so only the known
+    /// content ids and the given ones are migrated. Other data objects, such as pending and
+    /// rejected content, are read as missing until root migrates them by their ids.
+    pub fn migrate_data_objects(content_ids: Vec<T::ContentId>) {
+        let content_ids: BTreeSet<T::ContentId> = Self::known_content_ids()
+            .into_iter()
+            .chain(content_ids.into_iter())
+            .collect();
+
+        for content_id in content_ids {
+            Self::migrate_data_object(content_id);
+        }
+    }
+
+    // Re-encodes a data object stored in the previous layout, charging it to the quota of the
+    // member owning it and assigning the load of pending content to its liaison, who is given
+    // a new judgement timeout. Data objects already in the current layout are left as they are.
+    fn migrate_data_object(content_id: T::ContentId) {
+        if Self::data_object_by_content_id(content_id).is_some() {
+            return;
+        }
+
+        let migrated = translate_map_value::<
+            DataObjectByContentId<T>,
+            _,
+            _,
+            OldDataObject<T>,
+            DataObject<T>,
+            _,
+        >(&content_id, |old| DataObject {
+            // Content could only be added by members, it isn't charged to any quota
+            // if the owner account no longer belongs to a member.
+            owner_member_id: Self::member_id_by_account_id(&old.owner),
+            owner: old.owner,
+            added_at: old.added_at,
+            type_id: old.type_id,
+            size: old.size,
+            liaison: old.liaison,
+            liaison_judgement: old.liaison_judgement,
+            ipfs_content_id: old.ipfs_content_id,
+            rejection_reason: None,
+            judgement_deadline: None,
+        });
+
+        let mut data = match (migrated, Self::data_object_by_content_id(content_id)) {
+            (true, Some(data)) => data,
+            _ => return,
+        };
+
+        if let Some(member_id) = data.owner_member_id {
+            <StorageUsageByMemberId<T>>::mutate(member_id, |usage| {
+                usage.size_used = usage.size_used.saturating_add(data.size);
+                usage.objects_used = usage.objects_used.saturating_add(1);
+            });
+        }
+
+        if data.liaison_judgement == LiaisonJudgement::Pending {
+            data.judgement_deadline = Some(Self::assign_liaison(
+                content_id,
+                &data.liaison,
+                data.size,
+                <system::Module<T>>::block_number(),
+            ));
+            <DataObjectByContentId<T>>::insert(content_id, data);
+        }
+    }
+
+    fn member_id_by_account_id(who: &T::AccountId) -> Option<T::MemberId> {
+        <membership::members::Module<T>>::member_ids_by_controller_account_id(who)
+            .first()
+            .cloned()
+            .or_else(|| {
+                <membership::members::Module<T>>::member_ids_by_root_account_id(who)
+                    .first()
+                    .cloned()
+            })
+    }
+
+    // Returns the storage usage of the member after uploading a data object of the given size.
+    fn ensure_quota_allows_upload(
+        member_id: T::MemberId,
+        size: u64,
+    ) -> Result<StorageUsage, &'static str> {
+        let (remaining_size, remaining_objects) = Self::remaining_quota(member_id);
+
+        ensure!(remaining_objects > 0, MSG_QUOTA_OBJECTS_LIMIT_EXCEEDED);
+        ensure!(size <= remaining_size, MSG_QUOTA_SIZE_LIMIT_EXCEEDED);
+
+        let usage = Self::storage_usage_by_member_id(member_id);
+
+        Ok(StorageUsage {
+            size_used: usage.size_used + size,
+            objects_used: usage.objects_used + 1,
+        })
+    }
+
+    fn ensure_storage_lead_or_root(origin: T::Origin) -> dispatch::Result {
+        let raw_origin: Result<system::RawOrigin<T::AccountId>, T::Origin> = origin.into();
+
+        match raw_origin {
+            Ok(system::RawOrigin::Root) => Ok(()),
+            Ok(system::RawOrigin::Signed(who)) => {
+                ensure!(
+                    Self::storage_lead_account_id() == Some(who),
+                    MSG_ONLY_STORAGE_LEAD_OR_ROOT
+                );
+                Ok(())
+            }
+            _ => Err(MSG_ONLY_STORAGE_LEAD_OR_ROOT),
+        }
+    }
+
+    /// Root hash of a manifest with the chunks, the hash of the list of chunk hashes.
+    pub fn manifest_root_hash(chunks: &[ContentChunk<T::Hash>]) -> T::Hash {
+        let chunk_hashes: Vec<T::Hash> = chunks.iter().map(|chunk| chunk.hash).collect();
+        T::Hashing::hash_of(&chunk_hashes)
+    }
+
+    /// Indices of the chunks of the manifest not yet accepted by the liaison, the parts
+    /// of the upload to resume.
+    pub fn pending_chunks(content_id: T::ContentId) -> Vec<u32> {
+        let chunk_count = Self::manifest_by_content_id(&content_id)
+            .map_or(0, |manifest| manifest.chunks.len() as u32);
+
+        (0..chunk_count)
+            .filter(|chunk_index| !Self::chunk_accepted((content_id, *chunk_index)))
+            .collect()
+    }
+
+    fn do_add_content(
+        who: T::AccountId,
+        content_id: T::ContentId,
+        type_id: <T as DOTRTrait>::DataObjectTypeId,
+        size: u64,
+        ipfs_content_id: Vec<u8>,
+    ) -> dispatch::Result {
+        let owner_member_id =
+            Self::member_id_by_account_id(&who).ok_or(MSG_CREATOR_MUST_BE_MEMBER)?;
+
+        ensure!(
+            T::IsActiveDataObjectType::is_active_data_object_type(&type_id),
+            MSG_DO_TYPE_MUST_BE_ACTIVE
+        );
+
+        if let Some(max_size) = T::DataObjectTypeConstraints::max_data_object_size(&type_id) {
+            ensure!(size <= max_size, MSG_DO_SIZE_EXCEEDS_TYPE_MAX_SIZE);
+        }
+
+        ensure!(
+            !<DataObjectByContentId<T>>::exists(content_id),
+            "Data object aready added under this content id"
+        );
+
+        let usage = Self::ensure_quota_allows_upload(owner_member_id, size)?;
+
+        let liaison =
+            Self::select_liaison(size, None).ok_or(MSG_NO_LIAISON_WITH_SUFFICIENT_CAPACITY)?;
+
+        let judgement_deadline = Self::assign_liaison(
+            content_id,
+            &liaison,
+            size,
+            <system::Module<T>>::block_number(),
+        );
+
+        // Let's create the entry then
+        let data: DataObject<T> = DataObject {
+            type_id,
+            size,
+            added_at: Self::current_block_and_time(),
+            owner: who.clone(),
+            owner_member_id: Some(owner_member_id),
+            liaison: liaison.clone(),
+            liaison_judgement: LiaisonJudgement::Pending,
+            ipfs_content_id,
+            rejection_reason: None,
+            judgement_deadline: Some(judgement_deadline),
+        };
+
+        <DataObjectByContentId<T>>::insert(&content_id, data);
+        <StorageUsageByMemberId<T>>::insert(owner_member_id, usage);
+        Self::deposit_event(RawEvent::ContentAdded(content_id, who));
+
+        Ok(())
+    }
+
+    fn do_accept_content(who: T::AccountId, content_id: T::ContentId) -> dispatch::Result {
+        Self::update_content_judgement(&who, content_id, LiaisonJudgement::Accepted, None)?;
+        <KnownContentIds<T>>::mutate(|ids| ids.push(content_id));
+        Self::deposit_event(RawEvent::ContentAccepted(content_id, who));
+        Ok(())
+    }
+
+    // Chunks acknowledged by the previous liaison have to be acknowledged again.
+    fn clear_accepted_chunks(content_id: T::ContentId) {
+        if let Some(manifest) = Self::manifest_by_content_id(&content_id) {
+            for chunk_index in 0..manifest.chunks.len() as u32 {
+                <ChunkAccepted<T>>::remove((content_id, chunk_index));
+            }
+            <AcceptedChunkCount<T>>::remove(content_id);
+        }
+    }
+
+    fn ensure_valid_metadata(
+        content_id: T::ContentId,
+        metadata: &ContentMetadata<T>,
+    ) -> dispatch::Result {
+        let schema =
+            Self::metadata_schema_by_id(metadata.schema).ok_or(MSG_METADATA_SCHEMA_NOT_FOUND)?;
+
+        ensure!(
+            !metadata.title.is_empty() && metadata.title.len() <= schema.max_title_length as usize,
+            MSG_METADATA_TITLE_INVALID
+        );
+        ensure!(
+            metadata.description.len() <= schema.max_description_length as usize,
+            MSG_METADATA_DESCRIPTION_TOO_LONG
+        );
+
+        match metadata.thumbnail {
+            Some(thumbnail) => ensure!(
+                thumbnail != content_id && <DataObjectByContentId<T>>::exists(thumbnail),
+                MSG_METADATA_THUMBNAIL_NOT_FOUND
+            ),
+            None => ensure!(!schema.thumbnail_required, MSG_METADATA_THUMBNAIL_REQUIRED),
+        }
+
+        Ok(())
+    }
+
+    // The primary liaison if set, otherwise the storage provider with the least pending
+    // bytes which has the capacity for the upload. Ties go to the first provider in role.
+    // While no storage provider has advertised its capacity, e.g. right after the upgrade
+    // introducing capacities, a random storage provider is selected as before.
+    fn select_liaison(size: u64, excluded: Option<&T::AccountId>) -> Option<T::AccountId> {
+        if let Some(primary_liaison) = Self::primary_liaison_account_id() {
+            if excluded != Some(&primary_liaison) {
+                return Some(primary_liaison);
+            }
+        }
+
+        let providers = T::Roles::accounts_for_role(actors::Role::StorageProvider);
+
+        if providers
+            .iter()
+            .all(|provider| !<CapacityByStorageProvider<T>>::exists(provider))
+        {
+            return T::Roles::random_account_for_role(actors::Role::StorageProvider)
+                .ok()
+                .filter(|provider| excluded != Some(provider));
+        }
+
+        providers
+            .into_iter()
+            .filter(|provider| excluded != Some(provider))
+            .filter_map(|provider| {
+                let capacity = Self::capacity_by_storage_provider(&provider)?;
+                let load = Self::load_by_liaison(&provider);
+
+                let has_capacity = load.pending_uploads < capacity.max_concurrent_uploads
+                    && load.pending_bytes.saturating_add(size) <= capacity.free_bytes;
+
+                if has_capacity {
+                    Some((load.pending_bytes, provider))
+                } else {
+                    None
+                }
+            })
+            .fold(
+                None,
+                |selected: Option<(u64, T::AccountId)>, candidate| match selected {
+                    Some(selected) if selected.0 <= candidate.0 => Some(selected),
+                    _ => Some(candidate),
+                },
+            )
+            .map(|(_, provider)| provider)
+    }
+
+    // Returns the deadline for the judgement of the liaison.
+    fn assign_liaison(
+        content_id: T::ContentId,
+        liaison: &T::AccountId,
+        size: u64,
+        assigned_at: T::BlockNumber,
+    ) -> T::BlockNumber {
+        <LoadByLiaison<T>>::mutate(liaison, |load| {
+            load.pending_bytes = load.pending_bytes.saturating_add(size);
+            load.pending_uploads += 1;
+        });
+
+        let deadline = assigned_at + Self::liaison_judgement_timeout();
+        <JudgementDeadlines<T>>::mutate(deadline, |ids| ids.push(content_id));
+
+        deadline
+    }
+
+    fn release_liaison_load(liaison: &T::AccountId, size: u64) {
+        <LoadByLiaison<T>>::mutate(liaison, |load| {
+            load.pending_bytes = load.pending_bytes.saturating_sub(size);
+            load.pending_uploads = load.pending_uploads.saturating_sub(1);
+        });
+    }
+
+    // Content still pending at its deadline is reassigned to another liaison, or given
+    // more time with the same liaison if no other one is available. Deadline entries are
+    // left behind when content is judged or removed, only those matching the deadline of the
+    // data object count.
+    fn reassign_timed_out_content(now: T::BlockNumber) {
+        let mut content_ids = <JudgementDeadlines<T>>::take(now);
+
+        if content_ids.len() > MAX_REASSIGNMENTS_PER_BLOCK {
+            let deferred = content_ids.split_off(MAX_REASSIGNMENTS_PER_BLOCK);
+            <JudgementDeadlines<T>>::mutate(now + One::one(), |ids| ids.extend(deferred));
+        }
+
+        for content_id in content_ids {
+            let mut data = match Self::data_object_by_content_id(&content_id) {
+                Some(data)
+                    if data.liaison_judgement == LiaisonJudgement::Pending
+                        && data
+                            .judgement_deadline
+                            .map_or(false, |deadline| deadline <= now) =>
+                {
+                    data
+                }
+                _ => continue,
+            };
+
+            Self::release_liaison_load(&data.liaison, data.size);
+
+            let previous_liaison = data.liaison.clone();
+            if let Some(liaison) = Self::select_liaison(data.size, Some(&previous_liaison)) {
+                data.liaison = liaison;
+            }
+
+            data.judgement_deadline = Some(Self::assign_liaison(
+                content_id,
+                &data.liaison,
+                data.size,
+                now,
+            ));
+
+            if data.liaison != previous_liaison {
+                Self::clear_accepted_chunks(content_id);
+                Self::deposit_event(RawEvent::LiaisonReassigned(
+                    content_id,
+                    previous_liaison,
+                    data.liaison.clone(),
+                ));
+            }
+
+            <DataObjectByContentId<T>>::insert(content_id, data);
+        }
+    }
+
     fn current_block_and_time() -> BlockAndTime<T> {
         BlockAndTime {
             block: <system::Module<T>>::block_number(),
@@ -221,22 +960,78 @@ impl<T: Trait> Module<T> {
         who: &T::AccountId,
         content_id: T::ContentId,
         judgement: LiaisonJudgement,
+        rejection_reason: Option<Vec<u8>>,
     ) -> dispatch::Result {
         let mut data = Self::data_object_by_content_id(&content_id).ok_or(MSG_CID_NOT_FOUND)?;
 
         // Make sure the liaison matches
         ensure!(data.liaison == *who, MSG_LIAISON_REQUIRED);
 
+        if data.liaison_judgement == LiaisonJudgement::Pending {
+            Self::release_liaison_load(&data.liaison, data.size);
+        }
+
+        // The accepted content takes up the space the liaison advertised, until it advertises
+        // its capacity again.
+        if judgement == LiaisonJudgement::Accepted
+            && data.liaison_judgement != LiaisonJudgement::Accepted
+        {
+            <CapacityByStorageProvider<T>>::mutate(&data.liaison, |capacity| {
+                if let Some(capacity) = capacity {
+                    capacity.free_bytes = capacity.free_bytes.saturating_sub(data.size);
+                }
+            });
+        }
+
         data.liaison_judgement = judgement;
+        data.rejection_reason = rejection_reason;
+        data.judgement_deadline = None;
         <DataObjectByContentId<T>>::insert(content_id, data);
 
         Ok(())
     }
+
+    fn do_remove_content(content_id: T::ContentId, data: DataObject<T>, removed_by: T::AccountId) {
+        if data.liaison_judgement == LiaisonJudgement::Pending {
+            Self::release_liaison_load(&data.liaison, data.size);
+        }
+
+        <DataObjectByContentId<T>>::remove(content_id);
+        <KnownContentIds<T>>::mutate(|ids| ids.retain(|id| *id != content_id));
+
+        Self::clear_accepted_chunks(content_id);
+        <ManifestByContentId<T>>::remove(content_id);
+
+        if let Some(metadata) = <MetadataByContentId<T>>::take(content_id) {
+            if let Some(channel_id) = metadata.channel_id {
+                <ContentIdsByChannelId<T>>::mutate(channel_id, |ids| {
+                    ids.retain(|id| *id != content_id)
+                });
+            }
+        }
+
+        if let Some(member_id) = data.owner_member_id {
+            <StorageUsageByMemberId<T>>::mutate(member_id, |usage| {
+                usage.size_used = usage.size_used.saturating_sub(data.size);
+                usage.objects_used = usage.objects_used.saturating_sub(1);
+            });
+        }
+
+        T::OnContentRemoved::content_removed(&content_id, &data);
+
+        Self::deposit_event(RawEvent::ContentRemoved(
+            content_id,
+            data.ipfs_content_id,
+            removed_by,
+        ));
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::mock::*;
+    use common::storage_migration::put_map_value;
+    use sr_primitives::traits::OnInitialize;
 
     #[test]
     fn succeed_adding_content() {
@@ -255,21 +1050,496 @@ mod tests {
     }
 
     #[test]
-    fn accept_content_as_liaison() {
+    fn migrate_data_objects() {
         with_default_mock_builder(|| {
             let sender = 1 as u64;
-            let res = TestDataDirectory::add_content(
+            let member_id = 0;
+
+            for (content_id, size) in vec![(1, 100), (2, 200), (3, 300)] {
+                let res = TestDataDirectory::add_content(
+                    Origin::signed(sender),
+                    content_id,
+                    1234,
+                    size,
+                    vec![content_id as u8],
+                );
+                assert!(res.is_ok());
+            }
+            let res = TestDataDirectory::accept_content(Origin::signed(TEST_MOCK_LIAISON), 2);
+            assert!(res.is_ok());
+            let res =
+                TestDataDirectory::reject_content(Origin::signed(TEST_MOCK_LIAISON), 3, vec![]);
+            assert!(res.is_ok());
+
+            // Store the data objects in the layout before quotas, without any usage or load
+            let data_objects: Vec<_> = vec![1, 2, 3]
+                .into_iter()
+                .map(|content_id| {
+                    let data = TestDataDirectory::data_object_by_content_id(content_id).unwrap();
+                    put_map_value::<data_directory::DataObjectByContentId<Test>, _, _, _>(
+                        &content_id,
+                        &data_directory::OldDataObject::<Test> {
+                            owner: data.owner,
+                            added_at: data.added_at.clone(),
+                            type_id: data.type_id,
+                            size: data.size,
+                            liaison: data.liaison,
+                            liaison_judgement: data.liaison_judgement.clone(),
+                            ipfs_content_id: data.ipfs_content_id.clone(),
+                        },
+                    );
+                    assert_eq!(
+                        TestDataDirectory::data_object_by_content_id(content_id),
+                        None
+                    );
+                    (content_id, data)
+                })
+                .collect();
+            <data_directory::StorageUsageByMemberId<Test>>::remove(member_id);
+            <data_directory::LoadByLiaison<Test>>::remove(TEST_MOCK_LIAISON);
+
+            // Content owned by an account which no longer belongs to a member
+            let non_member = 999;
+            let orphan_content_id = 4;
+            put_map_value::<data_directory::DataObjectByContentId<Test>, _, _, _>(
+                &orphan_content_id,
+                &data_directory::OldDataObject::<Test> {
+                    owner: non_member,
+                    added_at: data_objects[0].1.added_at.clone(),
+                    type_id: 1234,
+                    size: 400,
+                    liaison: TEST_MOCK_LIAISON,
+                    liaison_judgement: data_directory::LiaisonJudgement::Accepted,
+                    ipfs_content_id: vec![4],
+                },
+            );
+
+            // Only the accepted content is known to the runtime upgrade
+            TestDataDirectory::migrate_data_objects(vec![]);
+            assert!(TestDataDirectory::data_object_by_content_id(1).is_none());
+            assert!(TestDataDirectory::data_object_by_content_id(2).is_some());
+            assert!(TestDataDirectory::data_object_by_content_id(3).is_none());
+
+            // The rest is migrated by root, the already migrated content is left as it is
+            let res = TestDataDirectory::migrate_data_objects_by_content_id(
                 Origin::signed(sender),
-                1,
-                1234,
-                0,
-                vec![1, 2, 3, 4],
+                vec![1, 3],
+            );
+            assert!(res.is_err());
+            let res = TestDataDirectory::migrate_data_objects_by_content_id(
+                system::RawOrigin::Root.into(),
+                vec![1, 2, 3, orphan_content_id],
             );
             assert!(res.is_ok());
 
-            // An appropriate event should have been fired.
-            let (content_id, creator) = match System::events().last().unwrap().event {
-                MetaEvent::data_directory(data_directory::RawEvent::ContentAdded(
+            for (content_id, data) in data_objects {
+                assert_eq!(
+                    TestDataDirectory::data_object_by_content_id(content_id),
+                    Some(data)
+                );
+            }
+            assert_eq!(
+                TestDataDirectory::storage_usage_by_member_id(member_id),
+                data_directory::StorageUsage {
+                    size_used: 600,
+                    objects_used: 3,
+                }
+            );
+
+            // The pending content is assigned to its liaison again
+            assert_eq!(
+                TestDataDirectory::load_by_liaison(TEST_MOCK_LIAISON),
+                data_directory::LiaisonLoad {
+                    pending_bytes: 100,
+                    pending_uploads: 1,
+                }
+            );
+
+            // The content of the former member isn't charged to any quota
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(orphan_content_id)
+                    .unwrap()
+                    .owner_member_id,
+                None
+            );
+        });
+    }
+
+    #[test]
+    fn fail_adding_content_larger_than_type_max_size() {
+        with_default_mock_builder(|| {
+            let sender = 1 as u64;
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                1,
+                1234,
+                TEST_MOCK_MAX_DATA_OBJECT_SIZE + 1,
+                vec![1, 3, 3, 7],
+            );
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                1,
+                1234,
+                TEST_MOCK_MAX_DATA_OBJECT_SIZE,
+                vec![1, 3, 3, 7],
+            );
+            assert!(res.is_ok());
+        });
+    }
+
+    #[test]
+    fn fail_adding_content_beyond_member_quota() {
+        with_default_mock_builder(|| {
+            let sender = 1 as u64;
+            let member_id = 0;
+            let limits = data_directory::QuotaLimits {
+                size_limit: 2000,
+                objects_limit: 2,
+            };
+            assert!(TestDataDirectory::set_member_quota_limits(
+                system::RawOrigin::Root.into(),
+                member_id,
+                Some(limits)
+            )
+            .is_ok());
+
+            let res =
+                TestDataDirectory::add_content(Origin::signed(sender), 1, 1234, 1500, vec![1]);
+            assert!(res.is_ok());
+            assert_eq!(TestDataDirectory::remaining_quota(member_id), (500, 1));
+
+            // Exceeds the remaining size
+            let res = TestDataDirectory::add_content(Origin::signed(sender), 2, 1234, 501, vec![2]);
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::add_content(Origin::signed(sender), 2, 1234, 500, vec![2]);
+            assert!(res.is_ok());
+            assert_eq!(TestDataDirectory::remaining_quota(member_id), (0, 0));
+
+            // Exceeds the number of objects
+            let res = TestDataDirectory::add_content(Origin::signed(sender), 3, 1234, 0, vec![3]);
+            assert!(res.is_err());
+
+            // Falling back to the default limits lifts the restriction
+            assert!(TestDataDirectory::set_member_quota_limits(
+                system::RawOrigin::Root.into(),
+                member_id,
+                None
+            )
+            .is_ok());
+            let res = TestDataDirectory::add_content(Origin::signed(sender), 3, 1234, 0, vec![3]);
+            assert!(res.is_ok());
+            assert_eq!(
+                TestDataDirectory::storage_usage_by_member_id(member_id),
+                data_directory::StorageUsage {
+                    size_used: 2000,
+                    objects_used: 3,
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn only_storage_lead_or_root_may_update_quotas() {
+        with_default_mock_builder(|| {
+            let lead = 7 as u64;
+            let limits = data_directory::QuotaLimits {
+                size_limit: 1,
+                objects_limit: 1,
+            };
+
+            let res =
+                TestDataDirectory::set_default_quota_limits(Origin::signed(lead), limits.clone());
+            assert!(res.is_err());
+
+            assert!(TestDataDirectory::set_storage_lead_account_id(
+                system::RawOrigin::Root.into(),
+                lead
+            )
+            .is_ok());
+
+            let res =
+                TestDataDirectory::set_default_quota_limits(Origin::signed(lead), limits.clone());
+            assert!(res.is_ok());
+            assert_eq!(TestDataDirectory::default_quota_limits(), limits);
+        });
+    }
+
+    #[test]
+    fn liaison_is_selected_by_load_and_capacity() {
+        with_default_mock_builder(|| {
+            let sender = 1 as u64;
+            let capacity = data_directory::StorageProviderCapacity {
+                free_bytes: 1000,
+                max_concurrent_uploads: 1,
+            };
+            assert!(TestDataDirectory::set_storage_provider_capacity(
+                Origin::signed(TEST_MOCK_SECOND_LIAISON),
+                capacity
+            )
+            .is_ok());
+
+            // Equal load, the first provider is selected
+            assert!(
+                TestDataDirectory::add_content(Origin::signed(sender), 1, 1234, 500, vec![1])
+                    .is_ok()
+            );
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(1)
+                    .unwrap()
+                    .liaison,
+                TEST_MOCK_LIAISON
+            );
+
+            // Least pending bytes
+            assert!(
+                TestDataDirectory::add_content(Origin::signed(sender), 2, 1234, 100, vec![2])
+                    .is_ok()
+            );
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(2)
+                    .unwrap()
+                    .liaison,
+                TEST_MOCK_SECOND_LIAISON
+            );
+
+            // Second liaison has reached its maximum concurrent uploads
+            assert!(
+                TestDataDirectory::add_content(Origin::signed(sender), 3, 1234, 100, vec![3])
+                    .is_ok()
+            );
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(3)
+                    .unwrap()
+                    .liaison,
+                TEST_MOCK_LIAISON
+            );
+
+            // Judging releases the load of the liaison
+            assert!(
+                TestDataDirectory::accept_content(Origin::signed(TEST_MOCK_SECOND_LIAISON), 2)
+                    .is_ok()
+            );
+            assert_eq!(
+                TestDataDirectory::load_by_liaison(TEST_MOCK_SECOND_LIAISON),
+                data_directory::LiaisonLoad::default()
+            );
+            assert_eq!(
+                TestDataDirectory::load_by_liaison(TEST_MOCK_LIAISON),
+                data_directory::LiaisonLoad {
+                    pending_bytes: 600,
+                    pending_uploads: 2,
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn liaison_is_selected_at_random_without_advertised_capacities() {
+        with_default_mock_builder(|| {
+            let sender = 1 as u64;
+            <data_directory::CapacityByStorageProvider<Test>>::remove(TEST_MOCK_LIAISON);
+
+            assert!(
+                TestDataDirectory::add_content(Origin::signed(sender), 1, 1234, 500, vec![1])
+                    .is_ok()
+            );
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(1)
+                    .unwrap()
+                    .liaison,
+                TEST_MOCK_LIAISON
+            );
+
+            // Once a storage provider advertised its capacity, only capacities are considered
+            let capacity = data_directory::StorageProviderCapacity {
+                free_bytes: 100,
+                max_concurrent_uploads: 1,
+            };
+            assert!(TestDataDirectory::set_storage_provider_capacity(
+                Origin::signed(TEST_MOCK_SECOND_LIAISON),
+                capacity
+            )
+            .is_ok());
+
+            assert!(
+                TestDataDirectory::add_content(Origin::signed(sender), 2, 1234, 500, vec![2])
+                    .is_err()
+            );
+        });
+    }
+
+    #[test]
+    fn fail_setting_zero_judgement_timeout() {
+        with_default_mock_builder(|| {
+            let timeout = TestDataDirectory::liaison_judgement_timeout();
+            let res =
+                TestDataDirectory::set_liaison_judgement_timeout(system::RawOrigin::Root.into(), 0);
+            assert!(res.is_err());
+            assert_eq!(TestDataDirectory::liaison_judgement_timeout(), timeout);
+        });
+    }
+
+    #[test]
+    fn liaison_is_reassigned_after_judgement_timeout() {
+        with_default_mock_builder(|| {
+            let sender = 1 as u64;
+            let content_id = 1;
+            let timeout = 10;
+            assert!(TestDataDirectory::set_liaison_judgement_timeout(
+                system::RawOrigin::Root.into(),
+                timeout
+            )
+            .is_ok());
+
+            assert!(TestDataDirectory::add_content(
+                Origin::signed(sender),
+                content_id,
+                1234,
+                100,
+                vec![1]
+            )
+            .is_ok());
+
+            // Without another liaison with capacity the content stays with its liaison
+            TestDataDirectory::on_initialize(timeout);
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(content_id)
+                    .unwrap()
+                    .liaison,
+                TEST_MOCK_LIAISON
+            );
+
+            let capacity = data_directory::StorageProviderCapacity {
+                free_bytes: 1000,
+                max_concurrent_uploads: 1,
+            };
+            assert!(TestDataDirectory::set_storage_provider_capacity(
+                Origin::signed(TEST_MOCK_SECOND_LIAISON),
+                capacity
+            )
+            .is_ok());
+
+            TestDataDirectory::on_initialize(2 * timeout);
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(content_id)
+                    .unwrap()
+                    .liaison,
+                TEST_MOCK_SECOND_LIAISON
+            );
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_directory(data_directory::RawEvent::LiaisonReassigned(
+                    content_id,
+                    TEST_MOCK_LIAISON,
+                    TEST_MOCK_SECOND_LIAISON
+                ))
+            );
+            assert_eq!(
+                TestDataDirectory::load_by_liaison(TEST_MOCK_LIAISON),
+                data_directory::LiaisonLoad::default()
+            );
+
+            // The previous liaison can no longer judge the content
+            let res =
+                TestDataDirectory::accept_content(Origin::signed(TEST_MOCK_LIAISON), content_id);
+            assert!(res.is_err());
+
+            // The accepted content takes up the free bytes of the liaison
+            let res = TestDataDirectory::accept_content(
+                Origin::signed(TEST_MOCK_SECOND_LIAISON),
+                content_id,
+            );
+            assert!(res.is_ok());
+            assert_eq!(
+                TestDataDirectory::capacity_by_storage_provider(TEST_MOCK_SECOND_LIAISON)
+                    .unwrap()
+                    .free_bytes,
+                900
+            );
+
+            // The deadline left behind by the accepted content is ignored
+            let data = TestDataDirectory::data_object_by_content_id(content_id).unwrap();
+            assert_eq!(data.judgement_deadline, None);
+            assert_eq!(
+                TestDataDirectory::judgement_deadlines(3 * timeout),
+                vec![content_id]
+            );
+            TestDataDirectory::on_initialize(3 * timeout);
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(content_id),
+                Some(data)
+            );
+            assert_eq!(
+                TestDataDirectory::load_by_liaison(TEST_MOCK_SECOND_LIAISON),
+                data_directory::LiaisonLoad::default()
+            );
+        });
+    }
+
+    #[test]
+    fn timed_out_content_beyond_block_limit_is_reassigned_in_next_block() {
+        with_default_mock_builder(|| {
+            let sender = 1 as u64;
+            let timeout = 10;
+            assert!(TestDataDirectory::set_liaison_judgement_timeout(
+                system::RawOrigin::Root.into(),
+                timeout
+            )
+            .is_ok());
+
+            let content_ids: Vec<u64> =
+                (0..super::MAX_REASSIGNMENTS_PER_BLOCK as u64 + 1).collect();
+            for content_id in content_ids.iter() {
+                assert!(TestDataDirectory::add_content(
+                    Origin::signed(sender),
+                    *content_id,
+                    1234,
+                    100,
+                    vec![1]
+                )
+                .is_ok());
+            }
+
+            let deadline = |content_id| {
+                TestDataDirectory::data_object_by_content_id(content_id)
+                    .unwrap()
+                    .judgement_deadline
+            };
+            let last_content_id = *content_ids.last().unwrap();
+
+            // Without another liaison with capacity the content is given more time
+            TestDataDirectory::on_initialize(timeout);
+            assert_eq!(deadline(0), Some(2 * timeout));
+            assert_eq!(deadline(last_content_id), Some(timeout));
+            assert_eq!(
+                TestDataDirectory::judgement_deadlines(timeout + 1),
+                vec![last_content_id]
+            );
+
+            TestDataDirectory::on_initialize(timeout + 1);
+            assert_eq!(deadline(last_content_id), Some(2 * timeout + 1));
+        });
+    }
+
+    #[test]
+    fn accept_content_as_liaison() {
+        with_default_mock_builder(|| {
+            let sender = 1 as u64;
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                1,
+                1234,
+                0,
+                vec![1, 2, 3, 4],
+            );
+            assert!(res.is_ok());
+
+            // An appropriate event should have been fired.
+            let (content_id, creator) = match System::events().last().unwrap().event {
+                MetaEvent::data_directory(data_directory::RawEvent::ContentAdded(
                     content_id,
                     creator,
                 )) => (content_id, creator),
@@ -314,13 +1584,411 @@ mod tests {
             assert_eq!(creator, sender);
 
             // Rejecting content should not work with some random origin
-            let res = TestDataDirectory::reject_content(Origin::signed(1), content_id);
+            let res = TestDataDirectory::reject_content(Origin::signed(1), content_id, vec![]);
             assert!(res.is_err());
 
             // However, with the liaison as origin it should.
+            let res = TestDataDirectory::reject_content(
+                Origin::signed(TEST_MOCK_LIAISON),
+                content_id,
+                b"corrupted".to_vec(),
+            );
+            assert!(res.is_ok());
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(content_id)
+                    .unwrap()
+                    .rejection_reason,
+                Some(b"corrupted".to_vec())
+            );
+        });
+    }
+
+    #[test]
+    fn remove_content_as_owner() {
+        with_default_mock_builder(|| {
+            let sender = 1 as u64;
+            let content_id = 1;
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                content_id,
+                1234,
+                100,
+                vec![1, 2, 3, 4],
+            );
+            assert!(res.is_ok());
+            assert!(TestDataDirectory::accept_content(
+                Origin::signed(TEST_MOCK_LIAISON),
+                content_id
+            )
+            .is_ok());
+
+            // Only the owner may remove the content
+            let res = TestDataDirectory::remove_content(Origin::signed(2), content_id);
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::remove_content(Origin::signed(sender), content_id);
+            assert!(res.is_ok());
+            assert!(TestDataDirectory::data_object_by_content_id(content_id).is_none());
+            assert!(TestDataDirectory::known_content_ids().is_empty());
+            assert_eq!(
+                TestDataDirectory::storage_usage_by_member_id(0),
+                data_directory::StorageUsage::default()
+            );
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_directory(data_directory::RawEvent::ContentRemoved(
+                    content_id,
+                    vec![1, 2, 3, 4],
+                    sender
+                ))
+            );
+        });
+    }
+
+    #[test]
+    fn force_remove_rejected_content_as_curator() {
+        with_default_mock_builder(|| {
+            let sender = 1 as u64;
+            let content_id = 1;
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                content_id,
+                1234,
+                100,
+                vec![1, 2, 3, 4],
+            );
+            assert!(res.is_ok());
+
+            // Content must have been rejected first
+            let res = TestDataDirectory::force_remove_content(
+                Origin::signed(TEST_MOCK_CURATOR),
+                content_id,
+            );
+            assert!(res.is_err());
+
+            assert!(TestDataDirectory::reject_content(
+                Origin::signed(TEST_MOCK_LIAISON),
+                content_id,
+                vec![]
+            )
+            .is_ok());
+
+            // Only curators may force the removal
+            let res = TestDataDirectory::force_remove_content(Origin::signed(sender), content_id);
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::force_remove_content(
+                Origin::signed(TEST_MOCK_CURATOR),
+                content_id,
+            );
+            assert!(res.is_ok());
+            assert!(TestDataDirectory::data_object_by_content_id(content_id).is_none());
+        });
+    }
+
+    fn set_mock_metadata_schema() -> u64 {
+        let schema_id = 1;
+        let schema = data_directory::ContentMetadataSchema {
+            max_title_length: 10,
+            max_description_length: 100,
+            thumbnail_required: false,
+        };
+        assert!(TestDataDirectory::set_metadata_schema(
+            system::RawOrigin::Root.into(),
+            schema_id,
+            Some(schema)
+        )
+        .is_ok());
+        schema_id
+    }
+
+    fn mock_metadata(
+        schema: u64,
+        channel_id: Option<u64>,
+    ) -> data_directory::ContentMetadata<Test> {
+        data_directory::ContentMetadata {
+            schema,
+            title: b"title".to_vec(),
+            description: b"description".to_vec(),
+            thumbnail: None,
+            visibility: data_directory::ContentVisibility::Public,
+            channel_id,
+        }
+    }
+
+    #[test]
+    fn set_content_metadata_as_owner() {
+        with_default_mock_builder(|| {
+            let owner = 1 as u64;
+            let content_id = 1;
+            let res =
+                TestDataDirectory::add_content(Origin::signed(owner), content_id, 1234, 0, vec![]);
+            assert!(res.is_ok());
+
+            let schema_id = set_mock_metadata_schema();
+            let metadata = mock_metadata(schema_id, None);
+
+            // Only the owner may set the metadata
+            let res = TestDataDirectory::set_content_metadata(
+                Origin::signed(2),
+                content_id,
+                metadata.clone(),
+            );
+            assert!(res.is_err());
+
+            // The metadata has to satisfy its schema
+            let res = TestDataDirectory::set_content_metadata(
+                Origin::signed(owner),
+                content_id,
+                mock_metadata(schema_id + 1, None),
+            );
+            assert!(res.is_err());
+
+            let mut invalid_metadata = metadata.clone();
+            invalid_metadata.title = vec![];
+            let res = TestDataDirectory::set_content_metadata(
+                Origin::signed(owner),
+                content_id,
+                invalid_metadata.clone(),
+            );
+            assert!(res.is_err());
+
+            invalid_metadata.title = b"too long title".to_vec();
+            let res = TestDataDirectory::set_content_metadata(
+                Origin::signed(owner),
+                content_id,
+                invalid_metadata,
+            );
+            assert!(res.is_err());
+
+            let mut invalid_metadata = metadata.clone();
+            invalid_metadata.thumbnail = Some(content_id + 1);
+            let res = TestDataDirectory::set_content_metadata(
+                Origin::signed(owner),
+                content_id,
+                invalid_metadata,
+            );
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::set_content_metadata(
+                Origin::signed(owner),
+                content_id,
+                metadata.clone(),
+            );
+            assert!(res.is_ok());
+            assert_eq!(
+                TestDataDirectory::metadata_by_content_id(content_id),
+                Some(metadata)
+            );
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_directory(data_directory::RawEvent::ContentMetadataUpdated(
+                    content_id, owner
+                ))
+            );
+        });
+    }
+
+    #[test]
+    fn publish_content_into_owned_channel() {
+        with_default_mock_builder(|| {
+            let owner = 1 as u64;
+            let content_id = 1;
+            let res =
+                TestDataDirectory::add_content(Origin::signed(owner), content_id, 1234, 0, vec![]);
+            assert!(res.is_ok());
+
+            let schema_id = set_mock_metadata_schema();
+
+            // Only owners of the channel may publish into it
+            let res = TestDataDirectory::set_content_metadata(
+                Origin::signed(owner),
+                content_id,
+                mock_metadata(schema_id, Some(TEST_MOCK_CHANNEL_ID + 1)),
+            );
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::set_content_metadata(
+                Origin::signed(owner),
+                content_id,
+                mock_metadata(schema_id, Some(TEST_MOCK_CHANNEL_ID)),
+            );
+            assert!(res.is_ok());
+            assert_eq!(
+                TestDataDirectory::content_ids_by_channel_id(TEST_MOCK_CHANNEL_ID),
+                vec![content_id]
+            );
+
+            // Updating the metadata keeps the content in the channel index once
+            let res = TestDataDirectory::set_content_metadata(
+                Origin::signed(owner),
+                content_id,
+                mock_metadata(schema_id, Some(TEST_MOCK_CHANNEL_ID)),
+            );
+            assert!(res.is_ok());
+            assert_eq!(
+                TestDataDirectory::content_ids_by_channel_id(TEST_MOCK_CHANNEL_ID),
+                vec![content_id]
+            );
+
+            // Removing the content removes its metadata and takes it off the channel
+            let res = TestDataDirectory::remove_content(Origin::signed(owner), content_id);
+            assert!(res.is_ok());
+            assert!(TestDataDirectory::metadata_by_content_id(content_id).is_none());
+            assert!(TestDataDirectory::content_ids_by_channel_id(TEST_MOCK_CHANNEL_ID).is_empty());
+        });
+    }
+
+    fn mock_manifest(chunk_sizes: &[u64]) -> data_directory::ContentManifest<H256> {
+        let chunks: Vec<_> = chunk_sizes
+            .iter()
+            .enumerate()
+            .map(|(index, size)| data_directory::ContentChunk {
+                hash: H256::from_low_u64_be(index as u64 + 2),
+                size: *size,
+            })
+            .collect();
+
+        data_directory::ContentManifest {
+            root_hash: TestDataDirectory::manifest_root_hash(&chunks),
+            chunks,
+        }
+    }
+
+    #[test]
+    fn add_chunked_content_with_valid_manifest() {
+        with_default_mock_builder(|| {
+            let sender = 1 as u64;
+
+            let res = TestDataDirectory::add_chunked_content(
+                Origin::signed(sender),
+                1,
+                1234,
+                mock_manifest(&[]),
+                vec![1],
+            );
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::add_chunked_content(
+                Origin::signed(sender),
+                1,
+                1234,
+                mock_manifest(&[100, 0]),
+                vec![1],
+            );
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::add_chunked_content(
+                Origin::signed(sender),
+                1,
+                1234,
+                mock_manifest(&[u64::max_value(), 1]),
+                vec![1],
+            );
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::add_chunked_content(
+                Origin::signed(sender),
+                1,
+                1234,
+                data_directory::ContentManifest {
+                    root_hash: H256::from_low_u64_be(1),
+                    ..mock_manifest(&[100, 200, 50])
+                },
+                vec![1],
+            );
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::add_chunked_content(
+                Origin::signed(sender),
+                1,
+                1234,
+                mock_manifest(&[100, 200, 50]),
+                vec![1],
+            );
+            assert!(res.is_ok());
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(1)
+                    .unwrap()
+                    .size,
+                350
+            );
+            assert_eq!(TestDataDirectory::pending_chunks(1), vec![0, 1, 2]);
+        });
+    }
+
+    #[test]
+    fn chunked_content_is_accepted_with_its_last_chunk() {
+        with_default_mock_builder(|| {
+            let sender = 1 as u64;
+            let content_id = 1;
+            assert!(TestDataDirectory::add_chunked_content(
+                Origin::signed(sender),
+                content_id,
+                1234,
+                mock_manifest(&[100, 200]),
+                vec![1]
+            )
+            .is_ok());
+
+            // Chunked content can't be accepted at once
             let res =
-                TestDataDirectory::reject_content(Origin::signed(TEST_MOCK_LIAISON), content_id);
+                TestDataDirectory::accept_content(Origin::signed(TEST_MOCK_LIAISON), content_id);
+            assert!(res.is_err());
+
+            // Only the liaison may accept chunks
+            let res =
+                TestDataDirectory::accept_content_chunk(Origin::signed(sender), content_id, 1);
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::accept_content_chunk(
+                Origin::signed(TEST_MOCK_LIAISON),
+                content_id,
+                2,
+            );
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::accept_content_chunk(
+                Origin::signed(TEST_MOCK_LIAISON),
+                content_id,
+                1,
+            );
+            assert!(res.is_ok());
+            assert_eq!(TestDataDirectory::pending_chunks(content_id), vec![0]);
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(content_id)
+                    .unwrap()
+                    .liaison_judgement,
+                data_directory::LiaisonJudgement::Pending
+            );
+
+            let res = TestDataDirectory::accept_content_chunk(
+                Origin::signed(TEST_MOCK_LIAISON),
+                content_id,
+                1,
+            );
+            assert!(res.is_err());
+
+            let res = TestDataDirectory::accept_content_chunk(
+                Origin::signed(TEST_MOCK_LIAISON),
+                content_id,
+                0,
+            );
+            assert!(res.is_ok());
+            assert!(TestDataDirectory::pending_chunks(content_id).is_empty());
+            assert_eq!(
+                TestDataDirectory::data_object_by_content_id(content_id)
+                    .unwrap()
+                    .liaison_judgement,
+                data_directory::LiaisonJudgement::Accepted
+            );
+            assert_eq!(TestDataDirectory::known_content_ids(), vec![content_id]);
+
+            // Removing the content drops its manifest
+            let res = TestDataDirectory::remove_content(Origin::signed(sender), content_id);
             assert!(res.is_ok());
+            assert!(TestDataDirectory::manifest_by_content_id(content_id).is_none());
+            assert_eq!(TestDataDirectory::accepted_chunk_count(content_id), 0);
         });
     }
 }
@@ -2,20 +2,28 @@
 #![allow(clippy::redundant_closure_call)] // disable it because of the substrate lib design
                                           // example:  pub NextRelationshipId get(next_relationship_id) build(|config: &GenesisConfig<T>|
 
-use crate::data_directory::Trait as DDTrait;
-use crate::traits::{ContentHasStorage, ContentIdExists};
+use crate::data_directory::{DataObject, LiaisonJudgement, Trait as DDTrait};
+use crate::traits::{
+    ContentHasStorage, ContentIdExists, ContentRemoved, DataObjectTypeConstraints,
+};
 use codec::{Codec, Decode, Encode};
+use common::currency::BalanceOf;
 use roles::actors;
 use roles::traits::Roles;
 use rstd::prelude::*;
-use sr_primitives::traits::{MaybeSerialize, Member, SimpleArithmetic};
+use sr_primitives::traits::{
+    Hash, MaybeSerialize, Member, One, SaturatedConversion, Saturating, SimpleArithmetic, Zero,
+};
+use srml_support::traits::{Imbalance, OnUnbalanced, Randomness};
 use srml_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
-use system::{self, ensure_signed};
+use system::{self, ensure_root, ensure_signed};
 
-pub trait Trait: timestamp::Trait + system::Trait + DDTrait {
+pub trait Trait:
+    timestamp::Trait + system::Trait + DDTrait + actors::Trait + recurringrewards::Trait
+{
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
-    // TODO deprecated
+    // Deprecated, only keys the relationships left for the migration
     type DataObjectStorageRelationshipId: Parameter
         + Member
         + SimpleArithmetic
@@ -27,19 +35,54 @@ pub trait Trait: timestamp::Trait + system::Trait + DDTrait {
 
     type Roles: Roles<Self>;
     type ContentIdExists: ContentIdExists<Self>;
+
+    // Source of the randomness used to select proof of storage challenges
+    type Randomness: Randomness<Self::Hash>;
+
+    // Receives the funds slashed from storage providers evicted for missed challenges
+    type SlashDestination: OnUnbalanced<actors::NegativeImbalance<Self>>;
 }
 
 static MSG_CID_NOT_FOUND: &str = "Content with this ID not found.";
-static MSG_DOSR_NOT_FOUND: &str = "No data object storage relationship found for this ID.";
-static MSG_ONLY_STORAGE_PROVIDER_MAY_CREATE_DOSR: &str =
-    "Only storage providers can create data object storage relationships.";
-static MSG_ONLY_STORAGE_PROVIDER_MAY_CLAIM_READY: &str =
-    "Only the storage provider in a DOSR can decide whether they're ready.";
+static MSG_ONLY_STORAGE_PROVIDER_MAY_SERVE_CONTENT: &str =
+    "Only storage providers may declare the content they are ready to serve.";
+static MSG_CONTENT_NOT_SERVED: &str = "The storage provider does not serve this content.";
+static MSG_REWARD_MINT_NOT_CREATED: &str = "Storage provider reward mint could not be created.";
+static MSG_REWARD_MINT_CAPACITY_NOT_SET: &str =
+    "Storage provider reward mint capacity could not be set.";
+static MSG_ONLY_OWNER_MAY_COMMIT_PROOF: &str =
+    "Only the owner of the content may commit its proof of storage.";
+static MSG_PROOF_ALREADY_COMMITTED: &str = "Proof of storage already committed for this content.";
+static MSG_PROOF_NOT_COMMITTED: &str = "No proof of storage committed for this content.";
+static MSG_ONLY_LIAISON_MAY_CONFIRM_PROOF: &str =
+    "Only the liaison of the content may confirm its proof of storage.";
+static MSG_CONTENT_NOT_ACCEPTED: &str =
+    "Proof of storage can only be confirmed for content accepted by its liaison.";
+static MSG_PROOF_NOT_PROPOSED: &str = "No proof of storage proposed for this content.";
+static MSG_PROOF_MISMATCH: &str =
+    "Proof of storage does not match the one proposed by the content owner.";
+static MSG_INVALID_PROOF_CHUNK_SIZE: &str = "Invalid proof of storage chunk size.";
+static MSG_INVALID_CHALLENGE_PARAMETERS: &str =
+    "Response period and missed challenges limit must be greater than zero.";
+static MSG_CHALLENGE_NOT_FOUND: &str = "No open challenge found for this ID.";
+static MSG_ONLY_CHALLENGED_STORAGE_PROVIDER_MAY_RESPOND: &str =
+    "Only the challenged storage provider may respond to the challenge.";
+static MSG_INVALID_CHALLENGE_RESPONSE: &str =
+    "Chunk and proof do not match the committed proof of storage.";
+static MSG_ONLY_STORAGE_PROVIDER_MAY_SET_ZONE: &str =
+    "Only storage providers may declare the zone they operate in.";
+static MSG_INVALID_ZONE_TAG: &str = "Zone tag must not be empty or too long.";
+
+// Upper bound of the chunk size, a challenge response carries the whole chunk
+pub const MAX_PROOF_CHUNK_SIZE: u64 = 64 * 1024;
+
+pub const MAX_ZONE_TAG_LENGTH: usize = 32;
 
-// TODO deprecated
+// Deprecated
 const DEFAULT_FIRST_RELATIONSHIP_ID: u32 = 1;
 
-// TODO deprecated
+// Deprecated, replaced by the storage providers serving content. Relationships
+// are only kept until the migration converts them.
 #[derive(Clone, Encode, Decode, PartialEq, Debug)]
 pub struct DataObjectStorageRelationship<T: Trait> {
     pub content_id: <T as DDTrait>::ContentId,
@@ -47,27 +90,79 @@ pub struct DataObjectStorageRelationship<T: Trait> {
     pub ready: bool,
 }
 
+pub type ChallengeId = u64;
+
+// Council set parameters of the proof of storage challenges
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
+pub struct ChallengeParameters<Balance, BlockNumber> {
+    // A round of challenges is issued at this block interval, zero disables challenges
+    pub challenge_period: BlockNumber,
+
+    // Number of blocks a storage provider has to respond to a challenge
+    pub response_period: BlockNumber,
+
+    // Number of challenges issued each round
+    pub challenges_per_round: u32,
+
+    // Missed challenges, less the passed ones, after which the storage provider is slashed
+    // and evicted
+    pub max_missed_challenges: u32,
+
+    // Amount slashed from the evicted storage provider
+    pub slash_amount: Balance,
+}
+
+impl<Balance: From<u32>, BlockNumber: From<u32>> Default
+    for ChallengeParameters<Balance, BlockNumber>
+{
+    fn default() -> Self {
+        Self {
+            challenge_period: BlockNumber::from(600),
+            response_period: BlockNumber::from(100),
+            challenges_per_round: 5,
+            max_missed_challenges: 3,
+            slash_amount: Balance::from(1000),
+        }
+    }
+}
+
+// The content owner proposes the root of a merkle tree over the hashes of the content
+// chunks, which the liaison confirms over the content it accepted. Unpaired nodes are
+// promoted to the next level unchanged.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
+pub struct ContentProofCommitment<Hash> {
+    pub merkle_root: Hash,
+    pub chunk_size: u64,
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
+pub struct StorageChallenge<AccountId, ContentId, BlockNumber> {
+    pub storage_provider: AccountId,
+    pub content_id: ContentId,
+    pub chunk_index: u64,
+    pub deadline: BlockNumber,
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataObjectStorageRegistry {
 
-        // TODO deprecated
+        // Deprecated, read only by the migration of the relationships
         // Start at this value
         pub FirstRelationshipId get(first_relationship_id) config(first_relationship_id): T::DataObjectStorageRelationshipId = T::DataObjectStorageRelationshipId::from(DEFAULT_FIRST_RELATIONSHIP_ID);
 
-        // TODO deprecated
+        // Deprecated
         // Increment
         pub NextRelationshipId get(next_relationship_id) build(|config: &GenesisConfig<T>| config.first_relationship_id): T::DataObjectStorageRelationshipId = T::DataObjectStorageRelationshipId::from(DEFAULT_FIRST_RELATIONSHIP_ID);
 
-        // TODO deprecated
+        // Deprecated
         // Mapping of Data object types
         pub Relationships get(relationships): map T::DataObjectStorageRelationshipId => Option<DataObjectStorageRelationship<T>>;
 
-        // TODO deprecated
+        // Deprecated
         // Keep a list of storage relationships per CID
         pub RelationshipsByContentId get(relationships_by_content_id): map T::ContentId => Vec<T::DataObjectStorageRelationshipId>;
 
         // ------------------------------------------
-        // TODO use next storage items insteam:
 
         // TODO save only if metadata exists and there is at least one relation w/ ready == true.
         ReadyContentIds get(ready_content_ids): Vec<T::ContentId> = vec![];
@@ -78,50 +173,124 @@ decl_storage! {
 
         pub StorageProvidersByContentId get(storage_providers_by_content_id):
             map T::ContentId => Vec<T::AccountId>;
+
+        // Content a storage provider is ready to serve, the reverse of StorageProvidersByContentId
+        pub ContentIdsByStorageProvider get(content_ids_by_storage_provider):
+            map T::AccountId => Vec<T::ContentId>;
+
+        // Total size of the content a storage provider is ready to serve, the basis of its reward
+        pub BytesServedByStorageProvider get(bytes_served_by_storage_provider):
+            map T::AccountId => u64;
+
+        // Reward paid per byte served each reward period of the storage provider role, set by the
+        // council. It is paid on top of the flat reward of the role, which is the base reward.
+        pub RewardRatePerByte get(reward_rate_per_byte): minting::BalanceOf<T>;
+
+        // Mint storage provider rewards are paid from
+        pub RewardMintId get(reward_mint_id): Option<T::MintId>;
+
+        pub RewardRelationshipByStorageProvider get(reward_relationship_by_storage_provider):
+            map T::AccountId => Option<T::RewardRelationshipId>;
+
+        // Proof of storage
+
+        // Commitments proposed by content owners, awaiting the confirmation of the liaison
+        pub ProposedProofCommitmentByContentId get(proposed_proof_commitment_by_content_id):
+            map T::ContentId => Option<ContentProofCommitment<T::Hash>>;
+
+        // Commitments confirmed by the liaison, which challenges are verified against
+        pub ProofCommitmentByContentId get(proof_commitment_by_content_id):
+            map T::ContentId => Option<ContentProofCommitment<T::Hash>>;
+
+        // Content with a committed proof of storage, the candidates for challenges
+        pub ProvableContentIds get(provable_content_ids): Vec<T::ContentId>;
+
+        pub ChallengeParams get(challenge_parameters):
+            ChallengeParameters<BalanceOf<T>, T::BlockNumber> = ChallengeParameters::default();
+
+        pub NextChallengeId get(next_challenge_id): ChallengeId;
+
+        pub Challenges get(challenges): map ChallengeId => Option<StorageChallenge<T::AccountId, T::ContentId, T::BlockNumber>>;
+
+        // Open challenges by the block their response period ends
+        pub ChallengeDeadlines get(challenge_deadlines): map T::BlockNumber => Vec<ChallengeId>;
+
+        // Missed challenges, each passed challenge takes one off
+        pub MissedChallengesByStorageProvider get(missed_challenges_by_storage_provider):
+            map T::AccountId => u32;
+
+        // Zone or region tag declared by the storage provider, the basis of the replica placement
+        pub ZoneByStorageProvider get(zone_by_storage_provider): map T::AccountId => Option<Vec<u8>>;
     }
 }
 
 decl_event! {
     pub enum Event<T> where
         <T as DDTrait>::ContentId,
-        <T as Trait>::DataObjectStorageRelationshipId,
-        <T as system::Trait>::AccountId
+        <T as system::Trait>::AccountId,
+        <T as system::Trait>::BlockNumber,
+        Balance = minting::BalanceOf<T>,
+        StakeBalance = BalanceOf<T>
     {
-        // TODO deprecated
-        DataObjectStorageRelationshipAdded(DataObjectStorageRelationshipId, ContentId, AccountId),
-        DataObjectStorageRelationshipReadyUpdated(DataObjectStorageRelationshipId, bool),
-
-        // NEW & COOL
         StorageProviderAddedContent(AccountId, ContentId),
         StorageProviderRemovedContent(AccountId, ContentId),
+
+        // Content is served by fewer storage providers than its data object type requires,
+        // with the current and target number of replicas.
+        ContentUnderReplicated(ContentId, u32, u32),
+
+        StorageProviderZoneSet(AccountId, Vec<u8>),
+
+        // Content is served by enough storage providers, but in fewer zones than its data
+        // object type requires, with the current and target number of zones.
+        ContentPlacementViolated(ContentId, u32, u32),
+
+        RewardRatePerByteUpdated(Balance),
+
+        // A reward for the bytes served was scheduled for the storage provider.
+        StorageProviderRewarded(AccountId, u64, Balance),
+
+        // Proof of storage
+        ContentProofProposed(ContentId),
+        ContentProofCommitted(ContentId),
+        ChallengeParametersUpdated(ChallengeParameters<StakeBalance, BlockNumber>),
+        ChallengeIssued(ChallengeId, AccountId, ContentId, u64, BlockNumber),
+        ChallengePassed(ChallengeId, AccountId),
+        ChallengeMissed(ChallengeId, AccountId, u32),
+        StorageProviderEvicted(AccountId, StakeBalance),
     }
 }
 
 impl<T: Trait> ContentHasStorage<T> for Module<T> {
-    // TODO deprecated
     fn has_storage_provider(which: &T::ContentId) -> bool {
-        let dosr_list = Self::relationships_by_content_id(which);
-        dosr_list.iter().any(|&dosr_id| {
-            let res = Self::relationships(dosr_id);
-            if res.is_none() {
-                return false;
-            }
-            let dosr = res.unwrap();
-            dosr.ready
-        })
+        !Self::storage_providers_by_content_id(which).is_empty()
     }
 
-    // TODO deprecated
     fn is_ready_at_storage_provider(which: &T::ContentId, provider: &T::AccountId) -> bool {
-        let dosr_list = Self::relationships_by_content_id(which);
-        dosr_list.iter().any(|&dosr_id| {
-            let res = Self::relationships(dosr_id);
-            if res.is_none() {
-                return false;
-            }
-            let dosr = res.unwrap();
-            dosr.storage_provider == *provider && dosr.ready
-        })
+        Self::storage_provider_serves_content((provider.clone(), *which))
+    }
+}
+
+impl<T: Trait> ContentRemoved<T> for Module<T> {
+    fn content_removed(which: &T::ContentId, data_object: &DataObject<T>) {
+        for provider in <StorageProvidersByContentId<T>>::take(which) {
+            <StorageProviderServesContent<T>>::remove((provider.clone(), *which));
+            <ContentIdsByStorageProvider<T>>::mutate(&provider, |ids| ids.retain(|id| id != which));
+            <BytesServedByStorageProvider<T>>::mutate(&provider, |bytes| {
+                *bytes = bytes.saturating_sub(data_object.size)
+            });
+            Self::deposit_event(RawEvent::StorageProviderRemovedContent(provider, *which));
+        }
+
+        <ReadyContentIds<T>>::mutate(|ids| ids.retain(|id| id != which));
+
+        <ProposedProofCommitmentByContentId<T>>::remove(which);
+
+        // Open challenges for the content are dropped when their deadline passes
+        if <ProofCommitmentByContentId<T>>::exists(which) {
+            <ProofCommitmentByContentId<T>>::remove(which);
+            <ProvableContentIds<T>>::mutate(|ids| ids.retain(|id| id != which));
+        }
     }
 }
 
@@ -129,80 +298,541 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
-        pub fn add_relationship(origin, cid: T::ContentId) {
-            // Origin has to be a storage provider
+        fn on_finalize(now: T::BlockNumber) {
+            if let Some(params) = <actors::Module<T>>::parameters(actors::Role::StorageProvider) {
+                if !params.reward_period.is_zero() && (now % params.reward_period).is_zero() {
+                    Self::reward_storage_providers(now);
+                }
+            }
+
+            Self::expire_challenges(now);
+
+            let challenge_period = Self::challenge_parameters().challenge_period;
+            if !challenge_period.is_zero() && (now % challenge_period).is_zero() {
+                Self::issue_challenges(now);
+            }
+        }
+
+        // A storage provider declares the content it is ready to serve.
+        pub fn set_content_ready(origin, content_id: T::ContentId) {
             let who = ensure_signed(origin)?;
+            ensure!(<T as Trait>::Roles::account_has_role(&who, actors::Role::StorageProvider),
+                MSG_ONLY_STORAGE_PROVIDER_MAY_SERVE_CONTENT);
+            ensure!(T::ContentIdExists::has_content(&content_id), MSG_CID_NOT_FOUND);
 
-            // Check that the origin is a storage provider
-            ensure!(<T as Trait>::Roles::account_has_role(&who, actors::Role::StorageProvider), MSG_ONLY_STORAGE_PROVIDER_MAY_CREATE_DOSR);
+            Self::update_storage_provider_serves_content(&who, content_id, true);
+        }
 
-            // Content ID must exist
-            ensure!(T::ContentIdExists::has_content(&cid), MSG_CID_NOT_FOUND);
+        pub fn unset_content_ready(origin, content_id: T::ContentId) {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::storage_provider_serves_content((who.clone(), content_id)),
+                MSG_CONTENT_NOT_SERVED);
 
-            // Create new ID, data.
-            let new_id = Self::next_relationship_id();
-            let dosr: DataObjectStorageRelationship<T> = DataObjectStorageRelationship {
-                content_id: cid,
-                storage_provider: who.clone(),
-                ready: false,
-            };
+            Self::update_storage_provider_serves_content(&who, content_id, false);
+        }
+
+        // A storage provider declares the zone it operates in, so replicas can be spread
+        // across zones and distributors can pick nearby providers.
+        pub fn set_storage_provider_zone(origin, zone: Vec<u8>) {
+            let who = ensure_signed(origin)?;
+            ensure!(<T as Trait>::Roles::account_has_role(&who, actors::Role::StorageProvider),
+                MSG_ONLY_STORAGE_PROVIDER_MAY_SET_ZONE);
+            ensure!(!zone.is_empty() && zone.len() <= MAX_ZONE_TAG_LENGTH, MSG_INVALID_ZONE_TAG);
+
+            <ZoneByStorageProvider<T>>::insert(&who, zone.clone());
+            Self::deposit_event(RawEvent::StorageProviderZoneSet(who, zone));
+        }
+
+        // The content owner proposes the merkle root of the content chunks. A proposal
+        // can be replaced until the liaison confirms it.
+        pub fn commit_content_proof(origin, content_id: T::ContentId, merkle_root: T::Hash, chunk_size: u64) {
+            let who = ensure_signed(origin)?;
+
+            let data = T::ContentIdExists::get_data_object(&content_id)?;
+            ensure!(data.owner == who, MSG_ONLY_OWNER_MAY_COMMIT_PROOF);
+            ensure!(!<ProofCommitmentByContentId<T>>::exists(content_id), MSG_PROOF_ALREADY_COMMITTED);
+            ensure!(chunk_size > 0 && chunk_size <= MAX_PROOF_CHUNK_SIZE, MSG_INVALID_PROOF_CHUNK_SIZE);
+
+            <ProposedProofCommitmentByContentId<T>>::insert(content_id, ContentProofCommitment {
+                merkle_root,
+                chunk_size,
+            });
+
+            Self::deposit_event(RawEvent::ContentProofProposed(content_id));
+        }
+
+        // The liaison confirms the proposed merkle root against the content it accepted, which
+        // makes the content subject to proof of storage challenges. Without the confirmation an
+        // owner could commit a root no storage provider can prove, getting them slashed.
+        pub fn confirm_content_proof(origin, content_id: T::ContentId, merkle_root: T::Hash, chunk_size: u64) {
+            let who = ensure_signed(origin)?;
+
+            let data = T::ContentIdExists::get_data_object(&content_id)?;
+            ensure!(data.liaison == who, MSG_ONLY_LIAISON_MAY_CONFIRM_PROOF);
+            ensure!(data.liaison_judgement == LiaisonJudgement::Accepted, MSG_CONTENT_NOT_ACCEPTED);
+
+            let commitment = Self::proposed_proof_commitment_by_content_id(content_id)
+                .ok_or(MSG_PROOF_NOT_PROPOSED)?;
+            ensure!(
+                commitment.merkle_root == merkle_root && commitment.chunk_size == chunk_size,
+                MSG_PROOF_MISMATCH
+            );
+
+            <ProposedProofCommitmentByContentId<T>>::remove(content_id);
+            <ProofCommitmentByContentId<T>>::insert(content_id, commitment);
+            <ProvableContentIds<T>>::mutate(|ids| ids.push(content_id));
+
+            Self::deposit_event(RawEvent::ContentProofCommitted(content_id));
+        }
 
-            <Relationships<T>>::insert(new_id, dosr);
-            <NextRelationshipId<T>>::mutate(|n| { *n += T::DataObjectStorageRelationshipId::from(1); });
+        // The challenged storage provider responds with the challenged chunk and
+        // the merkle proof of its inclusion in the committed content.
+        pub fn respond_to_challenge(origin, challenge_id: ChallengeId, chunk: Vec<u8>, proof: Vec<T::Hash>) {
+            let who = ensure_signed(origin)?;
+
+            let challenge = Self::challenges(challenge_id).ok_or(MSG_CHALLENGE_NOT_FOUND)?;
+            ensure!(challenge.storage_provider == who, MSG_ONLY_CHALLENGED_STORAGE_PROVIDER_MAY_RESPOND);
+
+            let commitment = Self::proof_commitment_by_content_id(challenge.content_id)
+                .ok_or(MSG_PROOF_NOT_COMMITTED)?;
+            let data = T::ContentIdExists::get_data_object(&challenge.content_id)?;
+            ensure!(
+                Self::verify_chunk_proof(&commitment, data.size, challenge.chunk_index, &chunk, &proof),
+                MSG_INVALID_CHALLENGE_RESPONSE
+            );
 
-            // Also add the DOSR to the list of DOSRs for the CID. Uniqueness is guaranteed
-            // by the map, so we can just append the new_id to the list.
-            let mut dosr_list = Self::relationships_by_content_id(cid);
-            dosr_list.push(new_id);
-            <RelationshipsByContentId<T>>::insert(cid, dosr_list);
+            <Challenges<T>>::remove(challenge_id);
+            <ChallengeDeadlines<T>>::mutate(challenge.deadline, |ids| ids.retain(|id| *id != challenge_id));
+            <MissedChallengesByStorageProvider<T>>::mutate(&who, |missed| {
+                *missed = missed.saturating_sub(1)
+            });
 
-            // Emit event
-            Self::deposit_event(RawEvent::DataObjectStorageRelationshipAdded(new_id, cid, who));
+            Self::deposit_event(RawEvent::ChallengePassed(challenge_id, who));
         }
 
-        // A storage provider may flip their own ready state, but nobody else.
-        pub fn set_relationship_ready(origin, id: T::DataObjectStorageRelationshipId) {
-            Self::toggle_dosr_ready(origin, id, true)?;
+        // Council methods
+
+        pub fn set_reward_rate_per_byte(origin, rate: minting::BalanceOf<T>) {
+            ensure_root(origin)?;
+            <RewardRatePerByte<T>>::put(rate);
+            Self::deposit_event(RawEvent::RewardRatePerByteUpdated(rate));
         }
 
-        pub fn unset_relationship_ready(origin, id: T::DataObjectStorageRelationshipId) {
-            Self::toggle_dosr_ready(origin, id, false)?;
+        pub fn set_challenge_parameters(origin, params: ChallengeParameters<BalanceOf<T>, T::BlockNumber>) {
+            ensure_root(origin)?;
+            ensure!(
+                !params.response_period.is_zero() && params.max_missed_challenges > 0,
+                MSG_INVALID_CHALLENGE_PARAMETERS
+            );
+
+            <ChallengeParams<T>>::put(params.clone());
+            Self::deposit_event(RawEvent::ChallengeParametersUpdated(params));
+        }
+
+        pub fn set_reward_mint_capacity(origin, capacity: minting::BalanceOf<T>) {
+            ensure_root(origin)?;
+
+            match Self::reward_mint_id() {
+                Some(mint_id) => {
+                    <minting::Module<T>>::set_mint_capacity(mint_id, capacity)
+                        .map_err(|_| MSG_REWARD_MINT_CAPACITY_NOT_SET)?;
+                }
+                None => {
+                    let mint_id = <minting::Module<T>>::add_mint(capacity, None)
+                        .map_err(|_| MSG_REWARD_MINT_NOT_CREATED)?;
+                    <RewardMintId<T>>::put(mint_id);
+                }
+            }
         }
     }
 }
 
 impl<T: Trait> Module<T> {
-    fn toggle_dosr_ready(
-        origin: T::Origin,
-        id: T::DataObjectStorageRelationshipId,
-        ready: bool,
-    ) -> Result<(), &'static str> {
-        // Origin has to be the storage provider mentioned in the DOSR
-        let who = ensure_signed(origin)?;
-
-        // For that, we need to fetch the identified DOSR
-        let mut dosr = Self::relationships(id).ok_or(MSG_DOSR_NOT_FOUND)?;
-        ensure!(
-            dosr.storage_provider == who,
-            MSG_ONLY_STORAGE_PROVIDER_MAY_CLAIM_READY
-        );
-
-        // Flip to ready
-        dosr.ready = ready;
-
-        // Update DOSR and fire event.
-        <Relationships<T>>::insert(id, dosr);
-        Self::deposit_event(RawEvent::DataObjectStorageRelationshipReadyUpdated(
-            id, true,
-        ));
-
-        Ok(())
+    /// Content ids of the deprecated storage relationships, which may reference data objects
+    /// to migrate along with them.
+    pub fn relationship_content_ids() -> Vec<T::ContentId> {
+        let mut content_ids = Vec::new();
+        let mut id = Self::first_relationship_id();
+
+        while id < Self::next_relationship_id() {
+            if let Some(dosr) = Self::relationships(id) {
+                content_ids.push(dosr.content_id);
+            }
+            id += One::one();
+        }
+
+        content_ids
+    }
+
+    /// Converts the ready relationships of the deprecated storage relationship model
+    /// into the storage providers serving content, and clears the relationships. The data
+    /// objects of the relationships must have been migrated before, see
+    /// `data_directory::Module::migrate_data_objects`, or they are read as missing.
+    pub fn migrate_relationships() {
+        let mut id = Self::first_relationship_id();
+        let next_id = Self::next_relationship_id();
+
+        while id < next_id {
+            if let Some(dosr) = <Relationships<T>>::take(id) {
+                <RelationshipsByContentId<T>>::remove(dosr.content_id);
+
+                if dosr.ready && T::ContentIdExists::has_content(&dosr.content_id) {
+                    Self::update_storage_provider_serves_content(
+                        &dosr.storage_provider,
+                        dosr.content_id,
+                        true,
+                    );
+                }
+            }
+            id += One::one();
+        }
+
+        <NextRelationshipId<T>>::put(Self::first_relationship_id());
+    }
+
+    // Schedules a one-off payout from the reward mint to each storage provider, in proportion
+    // to the bytes it serves, for the next block. The actors module pays the flat reward of the
+    // role in the same period, as the base this reward adds to.
+    fn reward_storage_providers(now: T::BlockNumber) {
+        let mint_id = match Self::reward_mint_id() {
+            Some(mint_id) => mint_id,
+            None => return,
+        };
+        let rate = Self::reward_rate_per_byte();
+        let payout_at = now + One::one();
+
+        for provider in <T as Trait>::Roles::accounts_for_role(actors::Role::StorageProvider) {
+            let bytes = Self::bytes_served_by_storage_provider(&provider);
+            let reward = rate.saturating_mul(bytes.saturated_into());
+
+            if reward.is_zero() {
+                continue;
+            }
+
+            let scheduled = match Self::reward_relationship_by_storage_provider(&provider) {
+                Some(relationship_id) => <recurringrewards::Module<T>>::set_reward_relationship(
+                    relationship_id,
+                    Some(provider.clone()),
+                    Some(reward),
+                    Some(Some(payout_at)),
+                    Some(None),
+                )
+                .is_ok(),
+                None => {
+                    let recipient = <recurringrewards::Module<T>>::add_recipient();
+                    match <recurringrewards::Module<T>>::add_reward_relationship(
+                        mint_id,
+                        recipient,
+                        provider.clone(),
+                        reward,
+                        payout_at,
+                        None,
+                    ) {
+                        Ok(relationship_id) => {
+                            <RewardRelationshipByStorageProvider<T>>::insert(
+                                &provider,
+                                relationship_id,
+                            );
+                            true
+                        }
+                        Err(_) => false,
+                    }
+                }
+            };
+
+            if scheduled {
+                Self::deposit_event(RawEvent::StorageProviderRewarded(provider, bytes, reward));
+            }
+        }
+    }
+
+    // Challenges random chunks of random content at one of the storage providers serving it.
+    fn issue_challenges(now: T::BlockNumber) {
+        let content_ids = Self::provable_content_ids();
+        if content_ids.is_empty() {
+            return;
+        }
+
+        let params = Self::challenge_parameters();
+        let seed = T::Randomness::random(b"storage_challenges");
+
+        for round in 0..params.challenges_per_round {
+            let content_id = content_ids
+                [Self::random_index(&seed, b"content", round, content_ids.len() as u64) as usize];
+
+            let providers: Vec<T::AccountId> = Self::storage_providers_by_content_id(content_id)
+                .into_iter()
+                .filter(|provider| {
+                    <T as Trait>::Roles::account_has_role(provider, actors::Role::StorageProvider)
+                })
+                .collect();
+            if providers.is_empty() {
+                continue;
+            }
+            let storage_provider = providers
+                [Self::random_index(&seed, b"provider", round, providers.len() as u64) as usize]
+                .clone();
+
+            let chunk_count = match (
+                Self::proof_commitment_by_content_id(content_id),
+                T::ContentIdExists::get_data_object(&content_id),
+            ) {
+                (Some(commitment), Ok(data)) => Self::chunk_count(data.size, commitment.chunk_size),
+                _ => continue,
+            };
+            let chunk_index = Self::random_index(&seed, b"chunk", round, chunk_count);
+
+            let challenge_id = Self::next_challenge_id();
+            NextChallengeId::mutate(|id| *id += 1);
+
+            let deadline = now + params.response_period;
+            <Challenges<T>>::insert(
+                challenge_id,
+                StorageChallenge {
+                    storage_provider: storage_provider.clone(),
+                    content_id,
+                    chunk_index,
+                    deadline,
+                },
+            );
+            <ChallengeDeadlines<T>>::mutate(deadline, |ids| ids.push(challenge_id));
+
+            Self::deposit_event(RawEvent::ChallengeIssued(
+                challenge_id,
+                storage_provider,
+                content_id,
+                chunk_index,
+                deadline,
+            ));
+        }
+    }
+
+    // Counts the challenges left unanswered at their deadline against the storage
+    // providers, slashing and evicting those that missed too many more than they passed.
+    fn expire_challenges(now: T::BlockNumber) {
+        for challenge_id in <ChallengeDeadlines<T>>::take(now) {
+            let challenge = match <Challenges<T>>::take(challenge_id) {
+                Some(challenge) => challenge,
+                None => continue,
+            };
+
+            // Challenges for removed content can no longer be answered
+            if !<ProofCommitmentByContentId<T>>::exists(challenge.content_id) {
+                continue;
+            }
+
+            let provider = challenge.storage_provider;
+            let missed = <MissedChallengesByStorageProvider<T>>::mutate(&provider, |missed| {
+                *missed += 1;
+                *missed
+            });
+            Self::deposit_event(RawEvent::ChallengeMissed(
+                challenge_id,
+                provider.clone(),
+                missed,
+            ));
+
+            let params = Self::challenge_parameters();
+            if missed >= params.max_missed_challenges {
+                if let Ok(imbalance) =
+                    <actors::Module<T>>::slash_and_evict_actor(&provider, params.slash_amount)
+                {
+                    let slashed = imbalance.peek();
+                    T::SlashDestination::on_unbalanced(imbalance);
+
+                    // The evicted provider no longer serves any of its content
+                    for content_id in Self::content_ids_by_storage_provider(&provider) {
+                        Self::update_storage_provider_serves_content(&provider, content_id, false);
+                    }
+                    <ContentIdsByStorageProvider<T>>::remove(&provider);
+                    <BytesServedByStorageProvider<T>>::remove(&provider);
+                    <MissedChallengesByStorageProvider<T>>::remove(&provider);
+                    <ZoneByStorageProvider<T>>::remove(&provider);
+                    Self::deposit_event(RawEvent::StorageProviderEvicted(provider, slashed));
+                }
+            }
+        }
+    }
+
+    fn random_index(seed: &T::Hash, subject: &[u8], round: u32, len: u64) -> u64 {
+        let random = T::Hashing::hash_of(&(seed, subject, round));
+        let value = u64::decode(&mut random.as_ref()).unwrap_or_default();
+        value % len.max(1)
+    }
+
+    /// Number of chunks the content is split into for the proof of storage.
+    /// Empty content is a single empty chunk.
+    pub fn chunk_count(size: u64, chunk_size: u64) -> u64 {
+        if chunk_size == 0 {
+            return 0;
+        }
+        let count = size / chunk_size + if size % chunk_size == 0 { 0 } else { 1 };
+        count.max(1)
+    }
+
+    /// Checks that the chunk at the given index, together with the proof siblings
+    /// ordered from the leaves up, hashes to the committed merkle root.
+    pub fn verify_chunk_proof(
+        commitment: &ContentProofCommitment<T::Hash>,
+        size: u64,
+        chunk_index: u64,
+        chunk: &[u8],
+        proof: &[T::Hash],
+    ) -> bool {
+        let chunk_count = Self::chunk_count(size, commitment.chunk_size);
+        if chunk_index >= chunk_count {
+            return false;
+        }
+
+        let chunk_start = chunk_index * commitment.chunk_size;
+        if chunk.len() as u64 != commitment.chunk_size.min(size - chunk_start) {
+            return false;
+        }
+
+        let mut node = T::Hashing::hash(chunk);
+        let mut index = chunk_index;
+        let mut width = chunk_count;
+        let mut siblings = proof.iter();
+
+        while width > 1 {
+            if index % 2 == 1 {
+                match siblings.next() {
+                    Some(sibling) => node = T::Hashing::hash_of(&(sibling, node)),
+                    None => return false,
+                }
+            } else if index + 1 < width {
+                match siblings.next() {
+                    Some(sibling) => node = T::Hashing::hash_of(&(node, sibling)),
+                    None => return false,
+                }
+            }
+            index /= 2;
+            width = width / 2 + width % 2;
+        }
+
+        siblings.next().is_none() && node == commitment.merkle_root
+    }
+
+    /// Returns the number of storage providers serving the content and the
+    /// replication factor required by its data object type.
+    pub fn replication_status(content_id: &T::ContentId) -> (u32, u32) {
+        let replicas = Self::storage_providers_by_content_id(content_id).len() as u32;
+        let target = T::ContentIdExists::get_data_object(content_id)
+            .map(|data| {
+                <T as DDTrait>::DataObjectTypeConstraints::replication_factor(&data.type_id)
+            })
+            .unwrap_or(0);
+
+        (replicas, target)
+    }
+
+    pub fn is_under_replicated(content_id: &T::ContentId) -> bool {
+        let (replicas, target) = Self::replication_status(content_id);
+        replicas < target
+    }
+
+    /// Returns the number of distinct zones of the storage providers serving the
+    /// content and the number of zones required by its data object type. Storage
+    /// providers without a zone don't count towards any zone.
+    pub fn placement_status(content_id: &T::ContentId) -> (u32, u32) {
+        let mut zones: Vec<Vec<u8>> = Self::storage_providers_by_content_id(content_id)
+            .iter()
+            .filter_map(Self::zone_by_storage_provider)
+            .collect();
+        zones.sort();
+        zones.dedup();
+
+        let min_zones = T::ContentIdExists::get_data_object(content_id)
+            .map(|data| <T as DDTrait>::DataObjectTypeConstraints::min_zones(&data.type_id))
+            .unwrap_or(0);
+
+        (zones.len() as u32, min_zones)
+    }
+
+    /// Whether the content lacks the copies or the zones its data object type requires.
+    pub fn has_placement_violation(content_id: &T::ContentId) -> bool {
+        let (zones, min_zones) = Self::placement_status(content_id);
+        Self::is_under_replicated(content_id) || zones < min_zones
+    }
+
+    /// Storage providers serving the content in the given zone.
+    pub fn storage_providers_in_zone(content_id: &T::ContentId, zone: &[u8]) -> Vec<T::AccountId> {
+        Self::storage_providers_by_content_id(content_id)
+            .into_iter()
+            .filter(|provider| {
+                Self::zone_by_storage_provider(provider).map_or(false, |z| z.as_slice() == zone)
+            })
+            .collect()
+    }
+
+    // Keeps the per content storage provider index and the bytes served by the
+    // provider in sync with the content it is ready to serve.
+    fn update_storage_provider_serves_content(
+        provider: &T::AccountId,
+        content_id: T::ContentId,
+        serves: bool,
+    ) {
+        if serves == Self::storage_provider_serves_content((provider.clone(), content_id)) {
+            return;
+        }
+
+        let mut providers = Self::storage_providers_by_content_id(content_id);
+        let size = T::ContentIdExists::get_data_object(&content_id).map_or(0, |data| data.size);
+
+        if serves {
+            <BytesServedByStorageProvider<T>>::mutate(provider, |bytes| {
+                *bytes = bytes.saturating_add(size)
+            });
+            providers.push(provider.clone());
+            <StorageProvidersByContentId<T>>::insert(content_id, providers);
+            <StorageProviderServesContent<T>>::insert((provider.clone(), content_id), true);
+            <ContentIdsByStorageProvider<T>>::mutate(provider, |ids| ids.push(content_id));
+
+            Self::deposit_event(RawEvent::StorageProviderAddedContent(
+                provider.clone(),
+                content_id,
+            ));
+        } else {
+            <BytesServedByStorageProvider<T>>::mutate(provider, |bytes| {
+                *bytes = bytes.saturating_sub(size)
+            });
+            providers.retain(|account_id| account_id != provider);
+            <StorageProvidersByContentId<T>>::insert(content_id, providers);
+            <StorageProviderServesContent<T>>::remove((provider.clone(), content_id));
+            <ContentIdsByStorageProvider<T>>::mutate(provider, |ids| {
+                ids.retain(|id| *id != content_id)
+            });
+
+            Self::deposit_event(RawEvent::StorageProviderRemovedContent(
+                provider.clone(),
+                content_id,
+            ));
+
+            let (replicas, target) = Self::replication_status(&content_id);
+            if replicas < target {
+                Self::deposit_event(RawEvent::ContentUnderReplicated(
+                    content_id, replicas, target,
+                ));
+            }
+        }
+
+        let (replicas, target) = Self::replication_status(&content_id);
+        let (zones, min_zones) = Self::placement_status(&content_id);
+        if replicas >= target && zones < min_zones {
+            Self::deposit_event(RawEvent::ContentPlacementViolated(
+                content_id, zones, min_zones,
+            ));
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::mock::*;
+    use crate::traits::ContentHasStorage;
+    use crate::traits::ContentIdExists;
+    use crate::traits::ContentRemoved;
+    use srml_support::traits::Currency;
 
     #[test]
     fn initial_state() {
@@ -215,21 +845,31 @@ mod tests {
     }
 
     #[test]
-    fn test_add_relationship() {
+    fn test_set_content_ready() {
         with_default_mock_builder(|| {
             // The content needs to exist - in our mock, that's with the content ID TEST_MOCK_EXISTING_CID
-            let res = TestDataObjectStorageRegistry::add_relationship(
+            let res = TestDataObjectStorageRegistry::set_content_ready(
                 Origin::signed(TEST_MOCK_LIAISON),
                 TEST_MOCK_EXISTING_CID,
             );
             assert!(res.is_ok());
+            assert!(TestDataObjectStorageRegistry::is_ready_at_storage_provider(
+                &TEST_MOCK_EXISTING_CID,
+                &TEST_MOCK_LIAISON
+            ));
+            assert!(TestDataObjectStorageRegistry::has_storage_provider(
+                &TEST_MOCK_EXISTING_CID
+            ));
         });
     }
 
     #[test]
-    fn test_fail_adding_relationship_with_bad_content() {
+    fn test_fail_setting_ready_for_bad_content() {
         with_default_mock_builder(|| {
-            let res = TestDataObjectStorageRegistry::add_relationship(Origin::signed(1), 24);
+            let res = TestDataObjectStorageRegistry::set_content_ready(
+                Origin::signed(TEST_MOCK_LIAISON),
+                24,
+            );
             assert!(res.is_err());
         });
     }
@@ -237,48 +877,827 @@ mod tests {
     #[test]
     fn test_toggle_ready() {
         with_default_mock_builder(|| {
-            // Create a DOSR
-            let res = TestDataObjectStorageRegistry::add_relationship(
+            // Only storage providers may serve content
+            let res = TestDataObjectStorageRegistry::set_content_ready(
+                Origin::signed(2),
+                TEST_MOCK_EXISTING_CID,
+            );
+            assert!(res.is_err());
+
+            // Content which isn't served can't be unset
+            let res = TestDataObjectStorageRegistry::unset_content_ready(
+                Origin::signed(TEST_MOCK_LIAISON),
+                TEST_MOCK_EXISTING_CID,
+            );
+            assert!(res.is_err());
+
+            let res = TestDataObjectStorageRegistry::set_content_ready(
+                Origin::signed(TEST_MOCK_LIAISON),
+                TEST_MOCK_EXISTING_CID,
+            );
+            assert!(res.is_ok());
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_object_storage_registry(
+                    data_object_storage_registry::RawEvent::StorageProviderAddedContent(
+                        TEST_MOCK_LIAISON,
+                        TEST_MOCK_EXISTING_CID,
+                    )
+                )
+            );
+
+            // Unsetting from a different account should fail
+            let res = TestDataObjectStorageRegistry::unset_content_ready(
+                Origin::signed(TEST_MOCK_SECOND_LIAISON),
+                TEST_MOCK_EXISTING_CID,
+            );
+            assert!(res.is_err());
+
+            let res = TestDataObjectStorageRegistry::unset_content_ready(
+                Origin::signed(TEST_MOCK_LIAISON),
+                TEST_MOCK_EXISTING_CID,
+            );
+            assert!(res.is_ok());
+            assert!(
+                !TestDataObjectStorageRegistry::is_ready_at_storage_provider(
+                    &TEST_MOCK_EXISTING_CID,
+                    &TEST_MOCK_LIAISON
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn test_migrate_relationships() {
+        with_default_mock_builder(|| {
+            // Populate the deprecated relationships: a ready one, one which isn't
+            // ready and a ready one for content which no longer exists.
+            let relationships = vec![
+                (TEST_MOCK_EXISTING_CID, TEST_MOCK_LIAISON, true),
+                (TEST_MOCK_EXISTING_CID, TEST_MOCK_SECOND_LIAISON, false),
+                (TEST_MOCK_EXISTING_CID + 1, TEST_MOCK_LIAISON, true),
+            ];
+            for (offset, (content_id, storage_provider, ready)) in
+                relationships.into_iter().enumerate()
+            {
+                let dosr_id = TEST_FIRST_RELATIONSHIP_ID + offset as u64;
+                <data_object_storage_registry::Relationships<Test>>::insert(
+                    dosr_id,
+                    data_object_storage_registry::DataObjectStorageRelationship {
+                        content_id,
+                        storage_provider,
+                        ready,
+                    },
+                );
+                <data_object_storage_registry::RelationshipsByContentId<Test>>::mutate(
+                    content_id,
+                    |ids| ids.push(dosr_id),
+                );
+                <data_object_storage_registry::NextRelationshipId<Test>>::put(dosr_id + 1);
+            }
+
+            TestDataObjectStorageRegistry::migrate_relationships();
+
+            assert_eq!(
+                TestDataObjectStorageRegistry::storage_providers_by_content_id(
+                    TEST_MOCK_EXISTING_CID
+                ),
+                vec![TEST_MOCK_LIAISON]
+            );
+            assert!(TestDataObjectStorageRegistry::is_ready_at_storage_provider(
+                &TEST_MOCK_EXISTING_CID,
+                &TEST_MOCK_LIAISON
+            ));
+            assert!(
+                !TestDataObjectStorageRegistry::is_ready_at_storage_provider(
+                    &TEST_MOCK_EXISTING_CID,
+                    &TEST_MOCK_SECOND_LIAISON
+                )
+            );
+            assert!(!TestDataObjectStorageRegistry::has_storage_provider(
+                &(TEST_MOCK_EXISTING_CID + 1)
+            ));
+            assert_eq!(
+                TestDataObjectStorageRegistry::bytes_served_by_storage_provider(TEST_MOCK_LIAISON),
+                MockContent::get_data_object(&TEST_MOCK_EXISTING_CID)
+                    .unwrap()
+                    .size
+            );
+
+            // The deprecated relationships are cleared
+            for dosr_id in TEST_FIRST_RELATIONSHIP_ID..TEST_FIRST_RELATIONSHIP_ID + 3 {
+                assert!(TestDataObjectStorageRegistry::relationships(dosr_id).is_none());
+            }
+            assert!(TestDataObjectStorageRegistry::relationships_by_content_id(
+                TEST_MOCK_EXISTING_CID
+            )
+            .is_empty());
+            assert_eq!(
+                TestDataObjectStorageRegistry::next_relationship_id(),
+                TEST_FIRST_RELATIONSHIP_ID
+            );
+        });
+    }
+
+    #[test]
+    fn test_replication_tracking() {
+        with_default_mock_builder(|| {
+            assert_eq!(
+                TestDataObjectStorageRegistry::replication_status(&TEST_MOCK_EXISTING_CID),
+                (0, TEST_MOCK_REPLICATION_FACTOR)
+            );
+
+            let res = TestDataObjectStorageRegistry::set_content_ready(
                 Origin::signed(TEST_MOCK_LIAISON),
                 TEST_MOCK_EXISTING_CID,
             );
             assert!(res.is_ok());
+            assert_eq!(
+                TestDataObjectStorageRegistry::storage_providers_by_content_id(
+                    TEST_MOCK_EXISTING_CID
+                ),
+                vec![TEST_MOCK_LIAISON]
+            );
+            assert_eq!(
+                TestDataObjectStorageRegistry::replication_status(&TEST_MOCK_EXISTING_CID),
+                (1, TEST_MOCK_REPLICATION_FACTOR)
+            );
+            assert!(TestDataObjectStorageRegistry::is_under_replicated(
+                &TEST_MOCK_EXISTING_CID
+            ));
 
-            // Grab DOSR ID from event
-            let dosr_id = match System::events().last().unwrap().event {
+            let res = TestDataObjectStorageRegistry::unset_content_ready(
+                Origin::signed(TEST_MOCK_LIAISON),
+                TEST_MOCK_EXISTING_CID,
+            );
+            assert!(res.is_ok());
+            assert!(
+                TestDataObjectStorageRegistry::storage_providers_by_content_id(
+                    TEST_MOCK_EXISTING_CID
+                )
+                .is_empty()
+            );
+            assert_eq!(
+                System::events().last().unwrap().event,
                 MetaEvent::data_object_storage_registry(
-                    data_object_storage_registry::RawEvent::DataObjectStorageRelationshipAdded(
-                        dosr_id,
-                        _content_id,
-                        _account_id,
-                    ),
-                ) => dosr_id,
-                _ => 0xdeadbeefu64, // invalid value, unlikely to match
-            };
-            assert_ne!(dosr_id, 0xdeadbeefu64);
+                    data_object_storage_registry::RawEvent::ContentUnderReplicated(
+                        TEST_MOCK_EXISTING_CID,
+                        0,
+                        TEST_MOCK_REPLICATION_FACTOR
+                    )
+                )
+            );
+        });
+    }
 
-            // Toggling from a different account should fail
-            let res =
-                TestDataObjectStorageRegistry::set_relationship_ready(Origin::signed(2), dosr_id);
+    #[test]
+    fn test_zone_placement() {
+        with_default_mock_builder(|| {
+            // Only storage providers may declare a zone
+            let res = TestDataObjectStorageRegistry::set_storage_provider_zone(
+                Origin::signed(1),
+                b"eu".to_vec(),
+            );
             assert!(res.is_err());
 
-            // Toggling with the wrong ID should fail.
-            let res = TestDataObjectStorageRegistry::set_relationship_ready(
+            let res = TestDataObjectStorageRegistry::set_storage_provider_zone(
                 Origin::signed(TEST_MOCK_LIAISON),
-                dosr_id + 1,
+                vec![],
             );
             assert!(res.is_err());
 
-            // Toggling with the correct ID and origin should succeed
-            let res = TestDataObjectStorageRegistry::set_relationship_ready(
+            let res = TestDataObjectStorageRegistry::set_storage_provider_zone(
                 Origin::signed(TEST_MOCK_LIAISON),
-                dosr_id,
+                b"eu".to_vec(),
             );
             assert!(res.is_ok());
-            assert_eq!(System::events().last().unwrap().event,
-                MetaEvent::data_object_storage_registry(data_object_storage_registry::RawEvent::DataObjectStorageRelationshipReadyUpdated(
-                    dosr_id,
-                    true,
+            let res = TestDataObjectStorageRegistry::set_storage_provider_zone(
+                Origin::signed(TEST_MOCK_SECOND_LIAISON),
+                b"eu".to_vec(),
+            );
+            assert!(res.is_ok());
+
+            for provider in &[TEST_MOCK_LIAISON, TEST_MOCK_SECOND_LIAISON] {
+                assert!(TestDataObjectStorageRegistry::set_content_ready(
+                    Origin::signed(*provider),
+                    TEST_MOCK_EXISTING_CID
+                )
+                .is_ok());
+            }
+
+            // Enough copies, but all in the same zone
+            assert!(!TestDataObjectStorageRegistry::is_under_replicated(
+                &TEST_MOCK_EXISTING_CID
+            ));
+            assert_eq!(
+                TestDataObjectStorageRegistry::placement_status(&TEST_MOCK_EXISTING_CID),
+                (1, TEST_MOCK_MIN_ZONES)
+            );
+            assert!(TestDataObjectStorageRegistry::has_placement_violation(
+                &TEST_MOCK_EXISTING_CID
+            ));
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_object_storage_registry(
+                    data_object_storage_registry::RawEvent::ContentPlacementViolated(
+                        TEST_MOCK_EXISTING_CID,
+                        1,
+                        TEST_MOCK_MIN_ZONES
+                    )
+                )
+            );
+
+            let res = TestDataObjectStorageRegistry::set_storage_provider_zone(
+                Origin::signed(TEST_MOCK_SECOND_LIAISON),
+                b"us".to_vec(),
+            );
+            assert!(res.is_ok());
+            assert_eq!(
+                TestDataObjectStorageRegistry::placement_status(&TEST_MOCK_EXISTING_CID),
+                (2, TEST_MOCK_MIN_ZONES)
+            );
+            assert!(!TestDataObjectStorageRegistry::has_placement_violation(
+                &TEST_MOCK_EXISTING_CID
+            ));
+            assert_eq!(
+                TestDataObjectStorageRegistry::storage_providers_in_zone(
+                    &TEST_MOCK_EXISTING_CID,
+                    b"us"
+                ),
+                vec![TEST_MOCK_SECOND_LIAISON]
+            );
+        });
+    }
+
+    #[test]
+    fn test_content_removal_cascades() {
+        with_default_mock_builder(|| {
+            let res = TestDataObjectStorageRegistry::set_content_ready(
+                Origin::signed(TEST_MOCK_LIAISON),
+                TEST_MOCK_EXISTING_CID,
+            );
+            assert!(res.is_ok());
+
+            let data_object = MockContent::get_data_object(&TEST_MOCK_EXISTING_CID).unwrap();
+            TestDataObjectStorageRegistry::content_removed(&TEST_MOCK_EXISTING_CID, &data_object);
+
+            assert!(
+                TestDataObjectStorageRegistry::storage_providers_by_content_id(
+                    TEST_MOCK_EXISTING_CID
+                )
+                .is_empty()
+            );
+            assert!(
+                !TestDataObjectStorageRegistry::storage_provider_serves_content((
+                    TEST_MOCK_LIAISON,
+                    TEST_MOCK_EXISTING_CID
+                ))
+            );
+            assert_eq!(
+                TestDataObjectStorageRegistry::bytes_served_by_storage_provider(TEST_MOCK_LIAISON),
+                0
+            );
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_object_storage_registry(
+                    data_object_storage_registry::RawEvent::StorageProviderRemovedContent(
+                        TEST_MOCK_LIAISON,
+                        TEST_MOCK_EXISTING_CID
+                    )
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn test_storage_providers_are_rewarded_per_byte_served() {
+        with_default_mock_builder(|| {
+            let reward_period = 10;
+            let rate = 2;
+            let params = actors::RoleParameters {
+                reward: 0,
+                reward_period,
+                ..Default::default()
+            };
+            assert!(TestActors::set_role_parameters(
+                system::RawOrigin::Root.into(),
+                actors::Role::StorageProvider,
+                params
+            )
+            .is_ok());
+            assert!(TestDataObjectStorageRegistry::set_reward_mint_capacity(
+                system::RawOrigin::Root.into(),
+                1_000_000
+            )
+            .is_ok());
+            assert!(TestDataObjectStorageRegistry::set_reward_rate_per_byte(
+                system::RawOrigin::Root.into(),
+                rate
+            )
+            .is_ok());
+
+            let res = TestDataObjectStorageRegistry::set_content_ready(
+                Origin::signed(TEST_MOCK_LIAISON),
+                TEST_MOCK_EXISTING_CID,
+            );
+            assert!(res.is_ok());
+
+            let bytes = MockContent::get_data_object(&TEST_MOCK_EXISTING_CID)
+                .unwrap()
+                .size;
+            assert_eq!(
+                TestDataObjectStorageRegistry::bytes_served_by_storage_provider(TEST_MOCK_LIAISON),
+                bytes
+            );
+
+            // Nothing is scheduled outside of the reward period
+            TestDataObjectStorageRegistry::on_finalize(reward_period - 1);
+            assert!(
+                TestDataObjectStorageRegistry::reward_relationship_by_storage_provider(
+                    TEST_MOCK_LIAISON
+                )
+                .is_none()
+            );
+
+            TestDataObjectStorageRegistry::on_finalize(reward_period);
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_object_storage_registry(
+                    data_object_storage_registry::RawEvent::StorageProviderRewarded(
+                        TEST_MOCK_LIAISON,
+                        bytes,
+                        bytes * rate
+                    )
+                )
+            );
+
+            // The reward is paid from the mint by the recurring rewards module
+            let balance_before = Balances::free_balance(TEST_MOCK_LIAISON);
+            TestRecurringRewards::on_finalize(reward_period + 1);
+            assert_eq!(
+                Balances::free_balance(TEST_MOCK_LIAISON),
+                balance_before + bytes * rate
+            );
+        });
+    }
+
+    #[test]
+    fn test_per_byte_reward_adds_to_base_role_reward() {
+        with_default_mock_builder(|| {
+            let rate = 2;
+            let params = actors::RoleParameters::default();
+            assert!(TestActors::set_role_parameters(
+                system::RawOrigin::Root.into(),
+                actors::Role::StorageProvider,
+                params
+            )
+            .is_ok());
+            assert!(TestDataObjectStorageRegistry::set_reward_mint_capacity(
+                system::RawOrigin::Root.into(),
+                1_000_000
+            )
+            .is_ok());
+            assert!(TestDataObjectStorageRegistry::set_reward_rate_per_byte(
+                system::RawOrigin::Root.into(),
+                rate
+            )
+            .is_ok());
+
+            // The liaison is a storage provider in the actors module, below the minimum stake
+            // so the base reward is paid to its own account
+            let _ = Balances::deposit_creating(&TEST_MOCK_LIAISON, 1);
+            <actors::ActorByAccountId<Test>>::insert(
+                TEST_MOCK_LIAISON,
+                actors::Actor {
+                    member_id: 0,
+                    role: actors::Role::StorageProvider,
+                    account: TEST_MOCK_LIAISON,
+                    joined_at: 0,
+                },
+            );
+            <actors::AccountIdsByRole<Test>>::insert(
+                actors::Role::StorageProvider,
+                vec![TEST_MOCK_LIAISON],
+            );
+
+            assert!(TestDataObjectStorageRegistry::set_content_ready(
+                Origin::signed(TEST_MOCK_LIAISON),
+                TEST_MOCK_EXISTING_CID,
+            )
+            .is_ok());
+            let bytes = MockContent::get_data_object(&TEST_MOCK_EXISTING_CID)
+                .unwrap()
+                .size;
+
+            // Actors are rewarded after their first full reward period
+            let now = 2 * params.reward_period;
+            let balance_before = Balances::free_balance(TEST_MOCK_LIAISON);
+            TestActors::on_finalize(now);
+            TestDataObjectStorageRegistry::on_finalize(now);
+            TestRecurringRewards::on_finalize(now + 1);
+
+            assert_eq!(
+                Balances::free_balance(TEST_MOCK_LIAISON),
+                balance_before + params.reward + bytes * rate
+            );
+        });
+    }
+
+    fn serve_mock_content_with_committed_proof() -> Vec<u8> {
+        let data_object = MockContent::get_data_object(&TEST_MOCK_EXISTING_CID).unwrap();
+        let bytes = mock_content_bytes(data_object.size);
+        let levels = mock_merkle_levels(&bytes, TEST_MOCK_PROOF_CHUNK_SIZE);
+
+        assert!(TestDataObjectStorageRegistry::commit_content_proof(
+            Origin::signed(data_object.owner),
+            TEST_MOCK_EXISTING_CID,
+            levels.last().unwrap()[0],
+            TEST_MOCK_PROOF_CHUNK_SIZE
+        )
+        .is_ok());
+        assert!(TestDataObjectStorageRegistry::confirm_content_proof(
+            Origin::signed(data_object.liaison),
+            TEST_MOCK_EXISTING_CID,
+            levels.last().unwrap()[0],
+            TEST_MOCK_PROOF_CHUNK_SIZE
+        )
+        .is_ok());
+
+        assert!(TestDataObjectStorageRegistry::set_content_ready(
+            Origin::signed(TEST_MOCK_LIAISON),
+            TEST_MOCK_EXISTING_CID,
+        )
+        .is_ok());
+
+        bytes
+    }
+
+    fn set_challenge_parameters(
+        challenge_period: u64,
+        response_period: u64,
+        max_missed_challenges: u32,
+        slash_amount: u64,
+    ) {
+        assert!(TestDataObjectStorageRegistry::set_challenge_parameters(
+            system::RawOrigin::Root.into(),
+            data_object_storage_registry::ChallengeParameters {
+                challenge_period,
+                response_period,
+                challenges_per_round: 1,
+                max_missed_challenges,
+                slash_amount,
+            }
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_commit_content_proof() {
+        with_default_mock_builder(|| {
+            let data_object = MockContent::get_data_object(&TEST_MOCK_EXISTING_CID).unwrap();
+            let merkle_root = H256::from_low_u64_be(1);
+
+            // Only the owner of existing content may commit
+            assert!(TestDataObjectStorageRegistry::commit_content_proof(
+                Origin::signed(TEST_MOCK_LIAISON),
+                TEST_MOCK_EXISTING_CID,
+                merkle_root,
+                TEST_MOCK_PROOF_CHUNK_SIZE
+            )
+            .is_err());
+            assert!(TestDataObjectStorageRegistry::commit_content_proof(
+                Origin::signed(data_object.owner),
+                TEST_MOCK_EXISTING_CID + 1,
+                merkle_root,
+                TEST_MOCK_PROOF_CHUNK_SIZE
+            )
+            .is_err());
+            assert!(TestDataObjectStorageRegistry::commit_content_proof(
+                Origin::signed(data_object.owner),
+                TEST_MOCK_EXISTING_CID,
+                merkle_root,
+                0
+            )
+            .is_err());
+
+            // A proposal can be replaced until confirmed
+            assert!(TestDataObjectStorageRegistry::commit_content_proof(
+                Origin::signed(data_object.owner),
+                TEST_MOCK_EXISTING_CID,
+                H256::from_low_u64_be(2),
+                TEST_MOCK_PROOF_CHUNK_SIZE
+            )
+            .is_ok());
+            assert!(TestDataObjectStorageRegistry::commit_content_proof(
+                Origin::signed(data_object.owner),
+                TEST_MOCK_EXISTING_CID,
+                merkle_root,
+                TEST_MOCK_PROOF_CHUNK_SIZE
+            )
+            .is_ok());
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_object_storage_registry(
+                    data_object_storage_registry::RawEvent::ContentProofProposed(
+                        TEST_MOCK_EXISTING_CID
+                    )
+                )
+            );
+
+            // Content isn't challenged before the liaison confirms the proposal
+            assert!(TestDataObjectStorageRegistry::provable_content_ids().is_empty());
+
+            // Only the liaison may confirm, and only the proposed commitment
+            assert!(TestDataObjectStorageRegistry::confirm_content_proof(
+                Origin::signed(data_object.owner),
+                TEST_MOCK_EXISTING_CID,
+                merkle_root,
+                TEST_MOCK_PROOF_CHUNK_SIZE
+            )
+            .is_err());
+            assert!(TestDataObjectStorageRegistry::confirm_content_proof(
+                Origin::signed(data_object.liaison),
+                TEST_MOCK_EXISTING_CID,
+                H256::from_low_u64_be(2),
+                TEST_MOCK_PROOF_CHUNK_SIZE
+            )
+            .is_err());
+
+            assert!(TestDataObjectStorageRegistry::confirm_content_proof(
+                Origin::signed(data_object.liaison),
+                TEST_MOCK_EXISTING_CID,
+                merkle_root,
+                TEST_MOCK_PROOF_CHUNK_SIZE
+            )
+            .is_ok());
+            assert_eq!(
+                TestDataObjectStorageRegistry::provable_content_ids(),
+                vec![TEST_MOCK_EXISTING_CID]
+            );
+            assert!(
+                TestDataObjectStorageRegistry::proposed_proof_commitment_by_content_id(
+                    TEST_MOCK_EXISTING_CID
+                )
+                .is_none()
+            );
+
+            // The commitment can't be replaced
+            assert!(TestDataObjectStorageRegistry::commit_content_proof(
+                Origin::signed(data_object.owner),
+                TEST_MOCK_EXISTING_CID,
+                merkle_root,
+                TEST_MOCK_PROOF_CHUNK_SIZE
+            )
+            .is_err());
+        });
+    }
+
+    #[test]
+    fn test_verify_chunk_proof() {
+        with_default_mock_builder(|| {
+            let size = 1234;
+            let bytes = mock_content_bytes(size);
+            let levels = mock_merkle_levels(&bytes, TEST_MOCK_PROOF_CHUNK_SIZE);
+            let commitment = data_object_storage_registry::ContentProofCommitment {
+                merkle_root: levels.last().unwrap()[0],
+                chunk_size: TEST_MOCK_PROOF_CHUNK_SIZE,
+            };
+            let chunk_count =
+                TestDataObjectStorageRegistry::chunk_count(size, TEST_MOCK_PROOF_CHUNK_SIZE);
+            assert_eq!(chunk_count, 13);
+
+            for (chunk_index, chunk) in bytes
+                .chunks(TEST_MOCK_PROOF_CHUNK_SIZE as usize)
+                .enumerate()
+            {
+                let chunk_index = chunk_index as u64;
+                let proof = mock_merkle_proof(&levels, chunk_index);
+                assert!(TestDataObjectStorageRegistry::verify_chunk_proof(
+                    &commitment,
+                    size,
+                    chunk_index,
+                    chunk,
+                    &proof
+                ));
+
+                // Neither another chunk position nor tampered data pass
+                assert!(!TestDataObjectStorageRegistry::verify_chunk_proof(
+                    &commitment,
+                    size,
+                    (chunk_index + 1) % chunk_count,
+                    chunk,
+                    &proof
+                ));
+                let mut tampered = chunk.to_vec();
+                tampered[0] ^= 1;
+                assert!(!TestDataObjectStorageRegistry::verify_chunk_proof(
+                    &commitment,
+                    size,
+                    chunk_index,
+                    &tampered,
+                    &proof
+                ));
+            }
+        });
+    }
+
+    #[test]
+    fn test_storage_challenge_response() {
+        with_default_mock_builder(|| {
+            let challenge_period = 10;
+            let response_period = 5;
+            set_challenge_parameters(challenge_period, response_period, 2, 100);
+            let bytes = serve_mock_content_with_committed_proof();
+            let levels = mock_merkle_levels(&bytes, TEST_MOCK_PROOF_CHUNK_SIZE);
+
+            TestDataObjectStorageRegistry::on_finalize(challenge_period);
+
+            let challenge_id = 0;
+            let challenge = TestDataObjectStorageRegistry::challenges(challenge_id).unwrap();
+            assert_eq!(challenge.storage_provider, TEST_MOCK_LIAISON);
+            assert_eq!(challenge.content_id, TEST_MOCK_EXISTING_CID);
+            assert_eq!(challenge.deadline, challenge_period + response_period);
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_object_storage_registry(
+                    data_object_storage_registry::RawEvent::ChallengeIssued(
+                        challenge_id,
+                        TEST_MOCK_LIAISON,
+                        TEST_MOCK_EXISTING_CID,
+                        challenge.chunk_index,
+                        challenge.deadline
+                    )
+                )
+            );
+
+            let chunk_size = TEST_MOCK_PROOF_CHUNK_SIZE as usize;
+            let chunk_start = challenge.chunk_index as usize * chunk_size;
+            let chunk = bytes[chunk_start..bytes.len().min(chunk_start + chunk_size)].to_vec();
+            let proof = mock_merkle_proof(&levels, challenge.chunk_index);
+
+            <data_object_storage_registry::MissedChallengesByStorageProvider<Test>>::insert(
+                TEST_MOCK_LIAISON,
+                1,
+            );
+
+            // Only the challenged storage provider may respond, and only with the right chunk
+            assert!(TestDataObjectStorageRegistry::respond_to_challenge(
+                Origin::signed(TEST_MOCK_SECOND_LIAISON),
+                challenge_id,
+                chunk.clone(),
+                proof.clone()
+            )
+            .is_err());
+            assert!(TestDataObjectStorageRegistry::respond_to_challenge(
+                Origin::signed(TEST_MOCK_LIAISON),
+                challenge_id,
+                vec![0; chunk.len()],
+                proof.clone()
+            )
+            .is_err());
+
+            assert!(TestDataObjectStorageRegistry::respond_to_challenge(
+                Origin::signed(TEST_MOCK_LIAISON),
+                challenge_id,
+                chunk,
+                proof
+            )
+            .is_ok());
+            assert!(TestDataObjectStorageRegistry::challenges(challenge_id).is_none());
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_object_storage_registry(
+                    data_object_storage_registry::RawEvent::ChallengePassed(
+                        challenge_id,
+                        TEST_MOCK_LIAISON
+                    )
+                )
+            );
+
+            // A passed challenge takes one off the missed challenges, and isn't counted as missed
+            TestDataObjectStorageRegistry::on_finalize(challenge.deadline);
+            assert_eq!(
+                TestDataObjectStorageRegistry::missed_challenges_by_storage_provider(
+                    TEST_MOCK_LIAISON
+                ),
+                0
+            );
+        });
+    }
+
+    #[test]
+    fn test_missed_challenges_slash_and_evict_storage_provider() {
+        with_default_mock_builder(|| {
+            let challenge_period = 10;
+            let response_period = 5;
+            let max_missed_challenges = 2;
+            let slash_amount = 100;
+            set_challenge_parameters(
+                challenge_period,
+                response_period,
+                max_missed_challenges,
+                slash_amount,
+            );
+            serve_mock_content_with_committed_proof();
+
+            // Make the liaison an actor staked in the storage provider role
+            let role_parameters = actors::RoleParameters {
+                min_stake: 1000,
+                ..Default::default()
+            };
+            assert!(TestActors::set_role_parameters(
+                system::RawOrigin::Root.into(),
+                actors::Role::StorageProvider,
+                role_parameters
+            )
+            .is_ok());
+            <actors::ActorByAccountId<Test>>::insert(
+                TEST_MOCK_LIAISON,
+                actors::Actor {
+                    member_id: 0,
+                    role: actors::Role::StorageProvider,
+                    account: TEST_MOCK_LIAISON,
+                    joined_at: 1,
+                },
+            );
+            <actors::AccountIdsByRole<Test>>::insert(
+                actors::Role::StorageProvider,
+                vec![TEST_MOCK_LIAISON],
+            );
+            let _ = Balances::deposit_creating(&TEST_MOCK_LIAISON, 1000);
+
+            TestDataObjectStorageRegistry::on_finalize(challenge_period);
+            TestDataObjectStorageRegistry::on_finalize(challenge_period + response_period);
+            assert_eq!(
+                TestDataObjectStorageRegistry::missed_challenges_by_storage_provider(
+                    TEST_MOCK_LIAISON
+                ),
+                1
+            );
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_object_storage_registry(
+                    data_object_storage_registry::RawEvent::ChallengeMissed(
+                        0,
+                        TEST_MOCK_LIAISON,
+                        1
+                    )
+                )
+            );
+            assert!(TestActors::actor_by_account_id(TEST_MOCK_LIAISON).is_some());
+
+            TestDataObjectStorageRegistry::on_finalize(2 * challenge_period);
+            TestDataObjectStorageRegistry::on_finalize(2 * challenge_period + response_period);
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::data_object_storage_registry(
+                    data_object_storage_registry::RawEvent::StorageProviderEvicted(
+                        TEST_MOCK_LIAISON,
+                        slash_amount
+                    )
+                )
+            );
+            assert!(TestActors::actor_by_account_id(TEST_MOCK_LIAISON).is_none());
+            assert_eq!(
+                Balances::free_balance(TEST_MOCK_LIAISON),
+                1000 - slash_amount
+            );
+            assert_eq!(
+                TestDataObjectStorageRegistry::missed_challenges_by_storage_provider(
+                    TEST_MOCK_LIAISON
+                ),
+                0
+            );
+
+            // The content served by the evicted provider is left unreplicated
+            assert!(!TestDataObjectStorageRegistry::has_storage_provider(
+                &TEST_MOCK_EXISTING_CID
+            ));
+            assert!(
+                !TestDataObjectStorageRegistry::is_ready_at_storage_provider(
+                    &TEST_MOCK_EXISTING_CID,
+                    &TEST_MOCK_LIAISON
+                )
+            );
+            assert!(
+                TestDataObjectStorageRegistry::content_ids_by_storage_provider(TEST_MOCK_LIAISON)
+                    .is_empty()
+            );
+            assert_eq!(
+                TestDataObjectStorageRegistry::bytes_served_by_storage_provider(TEST_MOCK_LIAISON),
+                0
+            );
+            assert_eq!(
+                TestDataObjectStorageRegistry::replication_status(&TEST_MOCK_EXISTING_CID),
+                (0, TEST_MOCK_REPLICATION_FACTOR)
+            );
+            assert!(System::events().iter().any(|record| record.event
+                == MetaEvent::data_object_storage_registry(
+                    data_object_storage_registry::RawEvent::ContentUnderReplicated(
+                        TEST_MOCK_EXISTING_CID,
+                        0,
+                        TEST_MOCK_REPLICATION_FACTOR
+                    )
                 )));
         });
     }
@@ -4,9 +4,10 @@
 
 use crate::traits;
 use codec::{Codec, Decode, Encode};
+use common::storage_migration::translate_map_value;
 use rstd::prelude::*;
 use sr_primitives::traits::{MaybeSerialize, Member, SimpleArithmetic};
-use srml_support::{decl_event, decl_module, decl_storage, Parameter};
+use srml_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
 use system::ensure_root;
 
 pub trait Trait: system::Trait {
@@ -23,9 +24,16 @@ pub trait Trait: system::Trait {
 }
 
 static MSG_DO_TYPE_NOT_FOUND: &str = "Data Object Type with the given ID not found.";
+static MSG_DO_TYPE_REPLICATION_FACTOR_ZERO: &str =
+    "Data Object Type replication factor must be at least one.";
+static MSG_DO_TYPE_MAX_SIZE_ZERO: &str = "Data Object Type maximum size must not be zero.";
+static MSG_DO_TYPE_MIN_ZONES_EXCEED_REPLICATION_FACTOR: &str =
+    "Data Object Type minimum number of zones must not exceed its replication factor.";
 
 const DEFAULT_TYPE_DESCRIPTION: &str = "Default data object type for audio and video content.";
 const DEFAULT_TYPE_ACTIVE: bool = true;
+const DEFAULT_TYPE_REPLICATION_FACTOR: u32 = 1;
+const DEFAULT_TYPE_MIN_ZONES: u32 = 1;
 const CREATE_DETAULT_TYPE: bool = true;
 
 const DEFAULT_FIRST_DATA_OBJECT_TYPE_ID: u32 = 1;
@@ -34,10 +42,33 @@ const DEFAULT_FIRST_DATA_OBJECT_TYPE_ID: u32 = 1;
 pub struct DataObjectType {
     pub description: Vec<u8>,
     pub active: bool,
-    // TODO in future releases
-    // - maximum size
-    // - replication factor
-    // - storage tranches (empty is ok)
+
+    // Maximum size in bytes of a single data object of this type, unbounded if not set.
+    pub max_size: Option<u64>,
+
+    // Number of storage providers that should be serving each data object of this type.
+    pub replication_factor: u32,
+
+    // Number of distinct zones the storage providers serving each data object of this
+    // type should operate in.
+    pub min_zones: u32,
+}
+
+// Layout of data object types before their storage constraints were introduced.
+#[derive(Clone, Encode, Decode, PartialEq, Debug)]
+pub struct OldDataObjectType {
+    pub description: Vec<u8>,
+    pub active: bool,
+}
+
+impl From<OldDataObjectType> for DataObjectType {
+    fn from(old: OldDataObjectType) -> Self {
+        DataObjectType {
+            description: old.description,
+            active: old.active,
+            ..DataObjectType::default()
+        }
+    }
 }
 
 impl Default for DataObjectType {
@@ -45,6 +76,9 @@ impl Default for DataObjectType {
         DataObjectType {
             description: DEFAULT_TYPE_DESCRIPTION.as_bytes().to_vec(),
             active: DEFAULT_TYPE_ACTIVE,
+            max_size: None,
+            replication_factor: DEFAULT_TYPE_REPLICATION_FACTOR,
+            min_zones: DEFAULT_TYPE_MIN_ZONES,
         }
     }
 }
@@ -82,6 +116,28 @@ impl<T: Trait> traits::IsActiveDataObjectType<T> for Module<T> {
     }
 }
 
+impl<T: Trait> traits::DataObjectTypeConstraints<T> for Module<T> {
+    fn max_data_object_size(which: &T::DataObjectTypeId) -> Option<u64> {
+        Self::ensure_data_object_type(*which)
+            .ok()
+            .and_then(|do_type| do_type.max_size)
+    }
+
+    fn replication_factor(which: &T::DataObjectTypeId) -> u32 {
+        match Self::ensure_data_object_type(*which) {
+            Ok(do_type) => do_type.replication_factor,
+            Err(_err) => 0,
+        }
+    }
+
+    fn min_zones(which: &T::DataObjectTypeId) -> u32 {
+        match Self::ensure_data_object_type(*which) {
+            Ok(do_type) => do_type.min_zones,
+            Err(_err) => 0,
+        }
+    }
+}
+
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
@@ -99,10 +155,15 @@ decl_module! {
 
         pub fn register_data_object_type(origin, data_object_type: DataObjectType) {
             ensure_root(origin)?;
+            Self::ensure_valid_constraints(&data_object_type)?;
+
             let new_do_type_id = Self::next_data_object_type_id();
             let do_type: DataObjectType = DataObjectType {
                 description: data_object_type.description.clone(),
                 active: data_object_type.active,
+                max_size: data_object_type.max_size,
+                replication_factor: data_object_type.replication_factor,
+                min_zones: data_object_type.min_zones,
             };
 
             <DataObjectTypes<T>>::insert(new_do_type_id, do_type);
@@ -114,10 +175,14 @@ decl_module! {
         // TODO use DataObjectTypeUpdate
         pub fn update_data_object_type(origin, id: T::DataObjectTypeId, data_object_type: DataObjectType) {
             ensure_root(origin)?;
+            Self::ensure_valid_constraints(&data_object_type)?;
             let mut do_type = Self::ensure_data_object_type(id)?;
 
             do_type.description = data_object_type.description.clone();
             do_type.active = data_object_type.active;
+            do_type.max_size = data_object_type.max_size;
+            do_type.replication_factor = data_object_type.replication_factor;
+            do_type.min_zones = data_object_type.min_zones;
 
             <DataObjectTypes<T>>::insert(id, do_type);
 
@@ -156,12 +221,44 @@ impl<T: Trait> Module<T> {
     fn ensure_data_object_type(id: T::DataObjectTypeId) -> Result<DataObjectType, &'static str> {
         Self::data_object_types(&id).ok_or(MSG_DO_TYPE_NOT_FOUND)
     }
+
+    fn ensure_valid_constraints(data_object_type: &DataObjectType) -> Result<(), &'static str> {
+        ensure!(
+            data_object_type.replication_factor > 0,
+            MSG_DO_TYPE_REPLICATION_FACTOR_ZERO
+        );
+        ensure!(
+            data_object_type.max_size != Some(0),
+            MSG_DO_TYPE_MAX_SIZE_ZERO
+        );
+        ensure!(
+            data_object_type.min_zones <= data_object_type.replication_factor,
+            MSG_DO_TYPE_MIN_ZONES_EXCEED_REPLICATION_FACTOR
+        );
+        Ok(())
+    }
+
+    /// Adds the default storage constraints to the data object types stored before the
+    /// constraints were introduced. Must only run when upgrading from a runtime without them.
+    pub fn migrate_data_object_types() {
+        let mut id = Self::first_data_object_type_id();
+
+        while id < Self::next_data_object_type_id() {
+            translate_map_value::<DataObjectTypes<T>, _, _, OldDataObjectType, DataObjectType, _>(
+                &id,
+                DataObjectType::from,
+            );
+
+            id += T::DataObjectTypeId::from(1);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     //use super::*;
     use crate::mock::*;
+    use common::storage_migration::put_map_value;
 
     use system::{self, EventRecord, Phase};
 
@@ -181,6 +278,7 @@ mod tests {
             let data: TestDataObjectType = TestDataObjectType {
                 description: "foo".as_bytes().to_vec(),
                 active: false,
+                ..TestDataObjectType::default()
             };
             let res = TestDataObjectTypeRegistry::register_data_object_type(
                 system::RawOrigin::Root.into(),
@@ -190,6 +288,58 @@ mod tests {
         });
     }
 
+    #[test]
+    fn fail_register_with_invalid_constraints() {
+        with_default_mock_builder(|| {
+            let data: TestDataObjectType = TestDataObjectType {
+                replication_factor: 0,
+                ..TestDataObjectType::default()
+            };
+            let res = TestDataObjectTypeRegistry::register_data_object_type(
+                system::RawOrigin::Root.into(),
+                data,
+            );
+            assert!(res.is_err());
+
+            let data: TestDataObjectType = TestDataObjectType {
+                max_size: Some(0),
+                ..TestDataObjectType::default()
+            };
+            let res = TestDataObjectTypeRegistry::register_data_object_type(
+                system::RawOrigin::Root.into(),
+                data,
+            );
+            assert!(res.is_err());
+
+            let data: TestDataObjectType = TestDataObjectType {
+                replication_factor: 2,
+                min_zones: 3,
+                ..TestDataObjectType::default()
+            };
+            let res = TestDataObjectTypeRegistry::register_data_object_type(
+                system::RawOrigin::Root.into(),
+                data,
+            );
+            assert!(res.is_err());
+
+            let data: TestDataObjectType = TestDataObjectType {
+                max_size: Some(1024),
+                replication_factor: 3,
+                min_zones: 2,
+                ..TestDataObjectType::default()
+            };
+            let res = TestDataObjectTypeRegistry::register_data_object_type(
+                system::RawOrigin::Root.into(),
+                data.clone(),
+            );
+            assert!(res.is_ok());
+            assert_eq!(
+                TestDataObjectTypeRegistry::data_object_types(TEST_FIRST_DATA_OBJECT_TYPE_ID),
+                Some(data)
+            );
+        });
+    }
+
     #[test]
     fn update_existing() {
         with_default_mock_builder(|| {
@@ -197,6 +347,7 @@ mod tests {
             let data: TestDataObjectType = TestDataObjectType {
                 description: "foo".as_bytes().to_vec(),
                 active: false,
+                ..TestDataObjectType::default()
             };
             let id_res = TestDataObjectTypeRegistry::register_data_object_type(
                 system::RawOrigin::Root.into(),
@@ -217,6 +368,7 @@ mod tests {
             let updated1: TestDataObjectType = TestDataObjectType {
                 description: "bar".as_bytes().to_vec(),
                 active: false,
+                ..TestDataObjectType::default()
             };
             let res = TestDataObjectTypeRegistry::update_data_object_type(
                 system::RawOrigin::Root.into(),
@@ -229,6 +381,7 @@ mod tests {
             let updated3: TestDataObjectType = TestDataObjectType {
                 description: "bar".as_bytes().to_vec(),
                 active: false,
+                ..TestDataObjectType::default()
             };
             let res = TestDataObjectTypeRegistry::update_data_object_type(
                 system::RawOrigin::Root.into(),
@@ -256,6 +409,7 @@ mod tests {
             let data: TestDataObjectType = TestDataObjectType {
                 description: "foo".as_bytes().to_vec(),
                 active: false,
+                ..TestDataObjectType::default()
             };
             let id_res = TestDataObjectTypeRegistry::register_data_object_type(
                 system::RawOrigin::Root.into(),
@@ -318,4 +472,40 @@ mod tests {
             assert!(!data.unwrap().active);
         });
     }
+
+    #[test]
+    fn migrate_data_object_types() {
+        with_default_mock_builder(|| {
+            let data: TestDataObjectType = TestDataObjectType {
+                description: "foo".as_bytes().to_vec(),
+                active: false,
+                ..TestDataObjectType::default()
+            };
+            let res = TestDataObjectTypeRegistry::register_data_object_type(
+                system::RawOrigin::Root.into(),
+                data.clone(),
+            );
+            assert!(res.is_ok());
+
+            // Store the type in the layout without storage constraints
+            put_map_value::<data_object_type_registry::DataObjectTypes<Test>, _, _, _>(
+                &TEST_FIRST_DATA_OBJECT_TYPE_ID,
+                &data_object_type_registry::OldDataObjectType {
+                    description: data.description.clone(),
+                    active: data.active,
+                },
+            );
+            assert_eq!(
+                TestDataObjectTypeRegistry::data_object_types(TEST_FIRST_DATA_OBJECT_TYPE_ID),
+                None
+            );
+
+            TestDataObjectTypeRegistry::migrate_data_object_types();
+
+            assert_eq!(
+                TestDataObjectTypeRegistry::data_object_types(TEST_FIRST_DATA_OBJECT_TYPE_ID),
+                Some(data)
+            );
+        });
+    }
 }
@@ -4,18 +4,18 @@ pub use super::{data_directory, data_object_storage_registry, data_object_type_r
 use crate::traits;
 pub use common::currency::GovernanceCurrency;
 use membership::members;
-use roles::actors;
+pub use roles::actors;
 pub use system;
 
 pub use primitives::{Blake2Hasher, H256};
 pub use sr_primitives::{
     testing::{Digest, DigestItem, Header, UintAuthorityId},
-    traits::{BlakeTwo256, Convert, IdentityLookup, OnFinalize},
+    traits::{BlakeTwo256, Convert, Hash, IdentityLookup, OnFinalize},
     weights::Weight,
     BuildStorage, Perbill,
 };
 
-use srml_support::{impl_outer_event, impl_outer_origin, parameter_types};
+use srml_support::{impl_outer_event, impl_outer_origin, parameter_types, traits::Randomness};
 
 impl_outer_origin! {
     pub enum Origin for Test {}
@@ -38,7 +38,14 @@ pub const TEST_FIRST_RELATIONSHIP_ID: u64 = 3000;
 pub const TEST_FIRST_METADATA_ID: u64 = 4000;
 
 pub const TEST_MOCK_LIAISON: u64 = 0xd00du64;
+pub const TEST_MOCK_SECOND_LIAISON: u64 = 0xd00eu64;
 pub const TEST_MOCK_EXISTING_CID: u64 = 42;
+pub const TEST_MOCK_CURATOR: u64 = 0xc0deu64;
+pub const TEST_MOCK_MAX_DATA_OBJECT_SIZE: u64 = 1_000_000;
+pub const TEST_MOCK_REPLICATION_FACTOR: u32 = 2;
+pub const TEST_MOCK_MIN_ZONES: u32 = 2;
+pub const TEST_MOCK_PROOF_CHUNK_SIZE: u64 = 100;
+pub const TEST_MOCK_CHANNEL_ID: u64 = 7;
 
 pub struct MockRoles {}
 impl roles::traits::Roles<Test> for MockRoles {
@@ -50,7 +57,7 @@ impl roles::traits::Roles<Test> for MockRoles {
         account_id: &<Test as system::Trait>::AccountId,
         _role: actors::Role,
     ) -> bool {
-        *account_id == TEST_MOCK_LIAISON
+        *account_id == TEST_MOCK_LIAISON || *account_id == TEST_MOCK_SECOND_LIAISON
     }
 
     fn random_account_for_role(
@@ -59,6 +66,10 @@ impl roles::traits::Roles<Test> for MockRoles {
         // We "randomly" select an account Id.
         Ok(TEST_MOCK_LIAISON)
     }
+
+    fn accounts_for_role(_role: actors::Role) -> Vec<<Test as system::Trait>::AccountId> {
+        vec![TEST_MOCK_LIAISON, TEST_MOCK_SECOND_LIAISON]
+    }
 }
 
 pub struct AnyDataObjectTypeIsActive {}
@@ -70,6 +81,41 @@ impl<T: data_object_type_registry::Trait> traits::IsActiveDataObjectType<T>
     }
 }
 
+pub struct MockDataObjectTypeConstraints {}
+impl<T: data_object_type_registry::Trait> traits::DataObjectTypeConstraints<T>
+    for MockDataObjectTypeConstraints
+{
+    fn max_data_object_size(_which: &T::DataObjectTypeId) -> Option<u64> {
+        Some(TEST_MOCK_MAX_DATA_OBJECT_SIZE)
+    }
+
+    fn replication_factor(_which: &T::DataObjectTypeId) -> u32 {
+        TEST_MOCK_REPLICATION_FACTOR
+    }
+
+    fn min_zones(_which: &T::DataObjectTypeId) -> u32 {
+        TEST_MOCK_MIN_ZONES
+    }
+}
+
+pub struct MockContentCurators {}
+impl traits::IsContentCurator<Test> for MockContentCurators {
+    fn is_content_curator(account: &<Test as system::Trait>::AccountId) -> bool {
+        *account == TEST_MOCK_CURATOR
+    }
+}
+
+// Alice owns the mock channel
+pub struct MockChannelOwners {}
+impl traits::IsChannelOwner<Test> for MockChannelOwners {
+    fn is_channel_owner(
+        channel_id: &<Test as data_directory::Trait>::ChannelId,
+        account: &<Test as system::Trait>::AccountId,
+    ) -> bool {
+        *channel_id == TEST_MOCK_CHANNEL_ID && *account == 1
+    }
+}
+
 pub struct MockContent {}
 impl traits::ContentIdExists<Test> for MockContent {
     fn has_content(which: &<Test as data_directory::Trait>::ContentId) -> bool {
@@ -88,15 +134,70 @@ impl traits::ContentIdExists<Test> for MockContent {
                     time: 1024,
                 },
                 owner: 1,
+                owner_member_id: Some(0),
                 liaison: TEST_MOCK_LIAISON,
-                liaison_judgement: data_directory::LiaisonJudgement::Pending,
+                liaison_judgement: data_directory::LiaisonJudgement::Accepted,
                 ipfs_content_id: vec![],
+                rejection_reason: None,
+                judgement_deadline: None,
             }),
             _ => Err("nope, missing"),
         }
     }
 }
 
+pub struct MockRandomness {}
+impl Randomness<H256> for MockRandomness {
+    fn random(subject: &[u8]) -> H256 {
+        BlakeTwo256::hash(subject)
+    }
+}
+
+// Stand-in for the bytes of the mock content, as held by a storage provider
+pub fn mock_content_bytes(size: u64) -> Vec<u8> {
+    (0..size).map(|i| (i % 251) as u8).collect()
+}
+
+// Levels of the proof of storage merkle tree over the content chunks, leaves first
+pub fn mock_merkle_levels(bytes: &[u8], chunk_size: u64) -> Vec<Vec<H256>> {
+    let mut level: Vec<H256> = bytes
+        .chunks(chunk_size as usize)
+        .map(BlakeTwo256::hash)
+        .collect();
+    if level.is_empty() {
+        level.push(BlakeTwo256::hash(&[]));
+    }
+
+    let mut levels = vec![level.clone()];
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                if pair.len() == 2 {
+                    BlakeTwo256::hash_of(&(pair[0], pair[1]))
+                } else {
+                    pair[0]
+                }
+            })
+            .collect();
+        levels.push(level.clone());
+    }
+    levels
+}
+
+pub fn mock_merkle_proof(levels: &[Vec<H256>], chunk_index: u64) -> Vec<H256> {
+    let mut index = chunk_index as usize;
+    let mut proof = vec![];
+    for level in &levels[..levels.len() - 1] {
+        let sibling = index ^ 1;
+        if sibling < level.len() {
+            proof.push(level[sibling]);
+        }
+        index /= 2;
+    }
+    proof
+}
+
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Test;
@@ -172,7 +273,12 @@ impl data_directory::Trait for Test {
     type ContentId = u64;
     type Roles = MockRoles;
     type IsActiveDataObjectType = AnyDataObjectTypeIsActive;
+    type DataObjectTypeConstraints = MockDataObjectTypeConstraints;
+    type ContentCurators = MockContentCurators;
+    type OnContentRemoved = data_object_storage_registry::Module<Test>;
     type SchemaId = u64;
+    type ChannelId = u64;
+    type ChannelOwners = MockChannelOwners;
 }
 
 impl data_object_storage_registry::Trait for Test {
@@ -180,6 +286,19 @@ impl data_object_storage_registry::Trait for Test {
     type DataObjectStorageRelationshipId = u64;
     type Roles = MockRoles;
     type ContentIdExists = MockContent;
+    type Randomness = MockRandomness;
+    type SlashDestination = ();
+}
+
+impl minting::Trait for Test {
+    type Currency = balances::Module<Self>;
+    type MintId = u64;
+}
+
+impl recurringrewards::Trait for Test {
+    type PayoutStatusHandler = ();
+    type RecipientId = u64;
+    type RewardRelationshipId = u64;
 }
 
 impl members::Trait for Test {
@@ -270,6 +389,8 @@ pub type TestDataDirectory = data_directory::Module<Test>;
 // pub type TestDataObject = data_directory::DataObject<Test>;
 pub type TestDataObjectStorageRegistry = data_object_storage_registry::Module<Test>;
 pub type TestActors = actors::Module<Test>;
+pub type Balances = balances::Module<Test>;
+pub type TestRecurringRewards = recurringrewards::Module<Test>;
 
 pub fn with_default_mock_builder<R, F: FnOnce() -> R>(f: F) -> R {
     ExtBuilder::default()
@@ -285,6 +406,17 @@ pub fn with_default_mock_builder<R, F: FnOnce() -> R>(f: F) -> R {
                 ""
             );
 
+            // Only the first liaison advertises capacity by default
+            let capacity = data_directory::StorageProviderCapacity {
+                free_bytes: 1 << 40,
+                max_concurrent_uploads: 100,
+            };
+            assert!(TestDataDirectory::set_storage_provider_capacity(
+                Origin::signed(TEST_MOCK_LIAISON),
+                capacity
+            )
+            .is_ok());
+
             f()
         })
 }
@@ -5,6 +5,14 @@ pub trait IsActiveDataObjectType<T: data_object_type_registry::Trait> {
     fn is_active_data_object_type(_which: &T::DataObjectTypeId) -> bool;
 }
 
+pub trait DataObjectTypeConstraints<T: data_object_type_registry::Trait> {
+    fn max_data_object_size(_which: &T::DataObjectTypeId) -> Option<u64>;
+
+    fn replication_factor(_which: &T::DataObjectTypeId) -> u32;
+
+    fn min_zones(_which: &T::DataObjectTypeId) -> u32;
+}
+
 pub trait ContentIdExists<T: data_directory::Trait> {
     fn has_content(_which: &T::ContentId) -> bool;
 
@@ -13,6 +21,18 @@ pub trait ContentIdExists<T: data_directory::Trait> {
     ) -> Result<data_directory::DataObject<T>, &'static str>;
 }
 
+pub trait ContentRemoved<T: data_directory::Trait> {
+    fn content_removed(_which: &T::ContentId, _data_object: &data_directory::DataObject<T>);
+}
+
+pub trait IsContentCurator<T: data_directory::Trait> {
+    fn is_content_curator(_account: &T::AccountId) -> bool;
+}
+
+pub trait IsChannelOwner<T: data_directory::Trait> {
+    fn is_channel_owner(_channel_id: &T::ChannelId, _account: &T::AccountId) -> bool;
+}
+
 pub trait ContentHasStorage<T: data_object_storage_registry::Trait> {
     fn has_storage_provider(_which: &T::ContentId) -> bool;
 
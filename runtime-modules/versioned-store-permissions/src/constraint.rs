@@ -1,8 +1,12 @@
+#[cfg(feature = "std")]
+use serde_derive::{Deserialize, Serialize};
+
 use codec::{Decode, Encode};
 use rstd::collections::btree_set::BTreeSet;
 
 /// Reference to a specific property of a specific class.
 #[derive(Encode, Decode, Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct PropertyOfClass<ClassId, PropertyIndex> {
     pub class_id: ClassId,
     pub property_index: PropertyIndex,
@@ -10,6 +14,7 @@ pub struct PropertyOfClass<ClassId, PropertyIndex> {
 
 /// The type of constraint imposed on referencing a class via class property of type "Internal".
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum ReferenceConstraint<ClassId: Ord, PropertyIndex: Ord> {
     /// No property can reference the class.
     NoReferencingAllowed,
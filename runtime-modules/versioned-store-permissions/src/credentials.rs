@@ -1,8 +1,12 @@
+#[cfg(feature = "std")]
+use serde_derive::{Deserialize, Serialize};
+
 use codec::{Decode, Encode};
 use rstd::collections::btree_set::BTreeSet;
 use rstd::prelude::*;
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct CredentialSet<Credential>(BTreeSet<Credential>);
 
 impl<Credential> From<Vec<Credential>> for CredentialSet<Credential>
@@ -34,6 +38,10 @@ impl<Credential: Ord> CredentialSet<Credential> {
         self.0.insert(value)
     }
 
+    pub fn remove(&mut self, value: &Credential) -> bool {
+        self.0.remove(value)
+    }
+
     pub fn contains(&self, value: &Credential) -> bool {
         self.0.contains(value)
     }
@@ -4,14 +4,15 @@
 use codec::Codec;
 use rstd::collections::btree_map::BTreeMap;
 use rstd::prelude::*;
-use runtime_primitives::traits::{MaybeSerialize, Member, SimpleArithmetic};
-use srml_support::{decl_module, decl_storage, dispatch, ensure, Parameter};
+use runtime_primitives::traits::{MaybeSerializeDeserialize, Member, SimpleArithmetic};
+use srml_support::{decl_event, decl_module, decl_storage, dispatch, ensure, Parameter};
 
 // EntityId, ClassId -> should be configured on versioned_store::Trait
 pub use versioned_store::{ClassId, ClassPropertyValue, EntityId, Property, PropertyValue};
 
 mod constraint;
 mod credentials;
+mod migration;
 mod mock;
 mod operations;
 mod permissions;
@@ -19,6 +20,7 @@ mod tests;
 
 pub use constraint::*;
 pub use credentials::*;
+pub use migration::*;
 pub use operations::*;
 pub use permissions::*;
 
@@ -59,9 +61,13 @@ impl<T: Trait> CreateClassPermissionsChecker<T> for () {
 pub type ClassPermissionsType<T> =
     ClassPermissions<ClassId, <T as Trait>::Credential, u16, <T as system::Trait>::BlockNumber>;
 
+pub type AccessLevelOf<T> = AccessLevel<<T as Trait>::Credential>;
+pub type CredentialSetOf<T> = CredentialSet<<T as Trait>::Credential>;
+pub type EntityPermissionsOf<T> = EntityPermissions<<T as Trait>::Credential>;
+pub type PropertyPermissionsOf<T> = PropertyPermissions<<T as Trait>::Credential>;
+
 pub trait Trait: system::Trait + versioned_store::Trait {
-    // type Event: ...
-    // Do we need Events?
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     /// Type that represents an actor or group of actors in the system.
     type Credential: Parameter
@@ -71,7 +77,7 @@ pub trait Trait: system::Trait + versioned_store::Trait {
         + Default
         + Copy
         + Clone
-        + MaybeSerialize
+        + MaybeSerializeDeserialize
         + Eq
         + PartialEq
         + Ord;
@@ -86,16 +92,56 @@ pub trait Trait: system::Trait + versioned_store::Trait {
 decl_storage! {
     trait Store for Module<T: Trait> as VersionedStorePermissions {
       /// ClassPermissions of corresponding Classes in the versioned store
-      pub ClassPermissionsByClassId get(class_permissions_by_class_id): linked_map ClassId => ClassPermissionsType<T>;
+      pub ClassPermissionsByClassId get(class_permissions_by_class_id) config(): linked_map ClassId => ClassPermissionsType<T>;
 
       /// Owner of an entity in the versioned store. If it is None then it is owned by the system.
-      pub EntityMaintainerByEntityId get(entity_maintainer_by_entity_id): linked_map EntityId => Option<T::Credential>;
+      pub EntityMaintainerByEntityId get(entity_maintainer_by_entity_id) config(): linked_map EntityId => Option<T::Credential>;
+
+      /// Credentials sharing the maintenance of an entity with its maintainer.
+      pub EntityCoMaintainersByEntityId get(entity_co_maintainers_by_entity_id) config(): linked_map EntityId => CredentialSet<T::Credential>;
     }
 }
 
+decl_event!(
+    pub enum Event<T>
+    where
+        AccountId = <T as system::Trait>::AccountId,
+        AccessLevel = AccessLevelOf<T>,
+        Credential = <T as Trait>::Credential,
+        CredentialSet = CredentialSetOf<T>,
+        EntityPermissions = EntityPermissionsOf<T>,
+        PropertyPermissions = PropertyPermissionsOf<T>,
+        ReferenceConstraint = ReferenceConstraint<ClassId, u16>,
+    {
+        // Every event carries the access level the change was made with.
+        /// Creating a class takes no credential, so the signer, if any, is carried as well.
+        ClassCreated(Option<AccountId>, AccessLevel, ClassId),
+        ClassAdminsUpdated(AccessLevel, ClassId, CredentialSet),
+        ClassEntityPermissionsUpdated(AccessLevel, ClassId, EntityPermissions),
+        ClassPropertyPermissionsUpdated(AccessLevel, ClassId, u16, Option<PropertyPermissions>),
+        ClassEntitiesCanBeCreatedUpdated(AccessLevel, ClassId, bool),
+        ClassAddSchemasSetUpdated(AccessLevel, ClassId, CredentialSet),
+        ClassCreateEntitiesSetUpdated(AccessLevel, ClassId, CredentialSet),
+        ClassReferenceConstraintUpdated(AccessLevel, ClassId, ReferenceConstraint),
+        ClassSchemaAdded(AccessLevel, ClassId, u16),
+        ClassSchemaStatusUpdated(AccessLevel, ClassId, u16, bool),
+
+        EntityCreated(AccessLevel, EntityId),
+        EntitySchemaAdded(AccessLevel, EntityId, u16),
+        /// Indexes of the updated properties in the class of the entity.
+        EntityPropertyValuesUpdated(AccessLevel, EntityId, Vec<u16>),
+        EntitySchemaMigrated(AccessLevel, EntityId, u16, u16),
+        EntityRemoved(AccessLevel, EntityId),
+        EntityMaintainerTransferred(AccessLevel, EntityId, Option<Credential>),
+        EntityCoMaintainersUpdated(AccessLevel, EntityId, CredentialSet),
+    }
+);
+
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
 
+        fn deposit_event() = default;
+
         /// Sets the admins for a class
         fn set_class_admins(
             origin,
@@ -104,16 +150,19 @@ decl_module! {
         ) -> dispatch::Result {
             let raw_origin = Self::ensure_root_or_signed(origin)?;
 
-            Self::mutate_class_permissions(
+            let access_level = Self::mutate_class_permissions(
                 &raw_origin,
                 None,
                 Self::is_system, // root origin
                 class_id,
                 |class_permissions| {
-                    class_permissions.admins = admins;
+                    class_permissions.admins = admins.clone();
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassAdminsUpdated(access_level, class_id, admins));
+            Ok(())
         }
 
         // Methods for updating concrete permissions
@@ -126,16 +175,19 @@ decl_module! {
         ) -> dispatch::Result {
             let raw_origin = Self::ensure_root_or_signed(origin)?;
 
-            Self::mutate_class_permissions(
+            let access_level = Self::mutate_class_permissions(
                 &raw_origin,
                 with_credential,
                 ClassPermissions::is_admin,
                 class_id,
                 |class_permissions| {
-                    class_permissions.entity_permissions = entity_permissions;
+                    class_permissions.entity_permissions = entity_permissions.clone();
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassEntityPermissionsUpdated(access_level, class_id, entity_permissions));
+            Ok(())
         }
 
         fn set_class_entities_can_be_created(
@@ -146,7 +198,7 @@ decl_module! {
         ) -> dispatch::Result {
             let raw_origin = Self::ensure_root_or_signed(origin)?;
 
-            Self::mutate_class_permissions(
+            let access_level = Self::mutate_class_permissions(
                 &raw_origin,
                 with_credential,
                 ClassPermissions::is_admin,
@@ -155,7 +207,10 @@ decl_module! {
                     class_permissions.entities_can_be_created = can_be_created;
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassEntitiesCanBeCreatedUpdated(access_level, class_id, can_be_created));
+            Ok(())
         }
 
         fn set_class_add_schemas_set(
@@ -166,16 +221,19 @@ decl_module! {
         ) -> dispatch::Result {
             let raw_origin = Self::ensure_root_or_signed(origin)?;
 
-            Self::mutate_class_permissions(
+            let access_level = Self::mutate_class_permissions(
                 &raw_origin,
                 with_credential,
                 ClassPermissions::is_admin,
                 class_id,
                 |class_permissions| {
-                    class_permissions.add_schemas = credential_set;
+                    class_permissions.add_schemas = credential_set.clone();
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassAddSchemasSetUpdated(access_level, class_id, credential_set));
+            Ok(())
         }
 
         fn set_class_create_entities_set(
@@ -186,16 +244,19 @@ decl_module! {
         ) -> dispatch::Result {
             let raw_origin = Self::ensure_root_or_signed(origin)?;
 
-            Self::mutate_class_permissions(
+            let access_level = Self::mutate_class_permissions(
                 &raw_origin,
                 with_credential,
                 ClassPermissions::is_admin,
                 class_id,
                 |class_permissions| {
-                    class_permissions.create_entities = credential_set;
+                    class_permissions.create_entities = credential_set.clone();
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassCreateEntitiesSetUpdated(access_level, class_id, credential_set));
+            Ok(())
         }
 
         fn set_class_reference_constraint(
@@ -206,36 +267,113 @@ decl_module! {
         ) -> dispatch::Result {
             let raw_origin = Self::ensure_root_or_signed(origin)?;
 
-            Self::mutate_class_permissions(
+            let access_level = Self::mutate_class_permissions(
                 &raw_origin,
                 with_credential,
                 ClassPermissions::is_admin,
                 class_id,
                 |class_permissions| {
-                    class_permissions.reference_constraint = constraint;
+                    class_permissions.reference_constraint = constraint.clone();
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassReferenceConstraintUpdated(access_level, class_id, constraint));
+            Ok(())
+        }
+
+        /// Sets who can update a property of entities of a class.
+        /// Passing None removes the property permissions, so that the property is governed
+        /// by the class entity permissions again.
+        fn set_class_property_permissions(
+            origin,
+            with_credential: Option<T::Credential>,
+            class_id: ClassId,
+            property_index: u16,
+            property_permissions: Option<PropertyPermissions<T::Credential>>
+        ) -> dispatch::Result {
+            let raw_origin = Self::ensure_root_or_signed(origin)?;
+
+            let access_level = Self::mutate_class_permissions(
+                &raw_origin,
+                with_credential,
+                ClassPermissions::is_admin,
+                class_id,
+                |class_permissions| {
+                    match property_permissions.clone() {
+                        Some(property_permissions) => {
+                            class_permissions.property_permissions.insert(property_index, property_permissions);
+                        },
+                        None => {
+                            class_permissions.property_permissions.remove(&property_index);
+                        }
+                    }
+                    Ok(())
+                }
+            )?;
+
+            Self::deposit_event(RawEvent::ClassPropertyPermissionsUpdated(access_level, class_id, property_index, property_permissions));
+            Ok(())
         }
 
-        // Setting a new maintainer for an entity may require having additional constraints.
-        // So for now it is disabled.
-        // pub fn set_entity_maintainer(
-        //     origin,
-        //     entity_id: EntityId,
-        //     new_maintainer: Option<T::Credential>
-        // ) -> dispatch::Result {
-        //     ensure_root(origin)?;
+        /// Hands an entity over to a new maintainer. Only the current maintainer
+        /// (not a co-maintainer) or class admins can do it.
+        pub fn transfer_entity_maintainer(
+            origin,
+            with_credential: Option<T::Credential>,
+            as_entity_maintainer: bool,
+            entity_id: EntityId,
+            new_maintainer: Option<T::Credential>
+        ) -> dispatch::Result {
+            let raw_origin = Self::ensure_root_or_signed(origin)?;
+
+            Self::if_entity_maintainer_or_class_admin(
+                &raw_origin,
+                with_credential,
+                as_entity_maintainer,
+                entity_id,
+                |access_level| {
+                    // Note: mutating value to None is equivalient to removing the value from storage map
+                    <EntityMaintainerByEntityId<T>>::mutate(entity_id, |maintainer| *maintainer = new_maintainer);
+
+                    // The new maintainer doesn't need to be a co-maintainer as well
+                    if let Some(new_maintainer) = new_maintainer {
+                        let mut co_maintainers = Self::entity_co_maintainers_by_entity_id(entity_id);
+                        if co_maintainers.remove(&new_maintainer) {
+                            Self::store_entity_co_maintainers(entity_id, co_maintainers);
+                        }
+                    }
+
+                    Self::deposit_event(RawEvent::EntityMaintainerTransferred(access_level, entity_id, new_maintainer));
+                    Ok(())
+                }
+            )
+        }
 
-        //     // ensure entity exists in the versioned store
-        //     let _ = Self::get_class_id_by_entity_id(entity_id)?;
+        /// Sets credentials sharing the maintenance of an entity. Only the maintainer
+        /// (not a co-maintainer) or class admins can do it.
+        pub fn set_entity_co_maintainers(
+            origin,
+            with_credential: Option<T::Credential>,
+            as_entity_maintainer: bool,
+            entity_id: EntityId,
+            co_maintainers: CredentialSet<T::Credential>
+        ) -> dispatch::Result {
+            let raw_origin = Self::ensure_root_or_signed(origin)?;
 
-        //     <EntityMaintainerByEntityId<T>>::mutate(entity_id, |maintainer| {
-        //         *maintainer = new_maintainer;
-        //     });
+            Self::if_entity_maintainer_or_class_admin(
+                &raw_origin,
+                with_credential,
+                as_entity_maintainer,
+                entity_id,
+                |access_level| {
+                    Self::store_entity_co_maintainers(entity_id, co_maintainers.clone());
 
-        //     Ok(())
-        // }
+                    Self::deposit_event(RawEvent::EntityCoMaintainersUpdated(access_level, entity_id, co_maintainers));
+                    Ok(())
+                }
+            )
+        }
 
         // Permissioned proxy calls to versioned store
 
@@ -247,12 +385,12 @@ decl_module! {
         ) -> dispatch::Result {
             let raw_origin = Self::ensure_root_or_signed(origin)?;
 
-            let can_create_class = match raw_origin {
-                system::RawOrigin::Root => true,
+            let (can_create_class, access_level, signer) = match raw_origin {
+                system::RawOrigin::Root => (true, AccessLevel::System, None),
                 system::RawOrigin::Signed(sender) => {
-                    T::CreateClassPermissionsChecker::account_can_create_class_permissions(&sender)
+                    (T::CreateClassPermissionsChecker::account_can_create_class_permissions(&sender), AccessLevel::Unspecified, Some(sender))
                 },
-                _ => false
+                _ => (false, AccessLevel::Unspecified, None)
             };
 
             if can_create_class {
@@ -262,6 +400,7 @@ decl_module! {
 
                 <ClassPermissionsByClassId<T>>::insert(&class_id, class_permissions);
 
+                Self::deposit_event(RawEvent::ClassCreated(signer, access_level, class_id));
                 Ok(())
             } else {
                 Err("NotPermittedToCreateClass")
@@ -291,12 +430,37 @@ decl_module! {
                 None,
                 ClassPermissions::can_add_class_schema,
                 class_id,
-                |_class_permissions, _access_level| {
+                |_class_permissions, access_level| {
                     // If a new property points at another class,
                     // at this point we don't enforce anything about reference constraints
                     // because of the chicken and egg problem. Instead enforcement is done
                     // at the time of creating an entity.
-                    let _schema_index = <versioned_store::Module<T>>::add_class_schema(class_id, existing_properties, new_properties)?;
+                    let schema_index = <versioned_store::Module<T>>::add_class_schema(class_id, existing_properties, new_properties)?;
+                    Self::deposit_event(RawEvent::ClassSchemaAdded(access_level.clone(), class_id, schema_index));
+                    Ok(())
+                }
+            )
+        }
+
+        /// Activates or deprecates a class schema. New entities cannot be added to a deprecated schema.
+        pub fn set_class_schema_status(
+            origin,
+            with_credential: Option<T::Credential>,
+            class_id: ClassId,
+            schema_id: u16,
+            is_active: bool
+        ) -> dispatch::Result {
+            let raw_origin = Self::ensure_root_or_signed(origin)?;
+
+            Self::if_class_permissions_satisfied(
+                &raw_origin,
+                with_credential,
+                None,
+                ClassPermissions::can_add_class_schema,
+                class_id,
+                |_class_permissions, access_level| {
+                    <versioned_store::Module<T>>::update_class_schema_status(class_id, schema_id, is_active)?;
+                    Self::deposit_event(RawEvent::ClassSchemaStatusUpdated(access_level.clone(), class_id, schema_id, is_active));
                     Ok(())
                 }
             )
@@ -337,6 +501,17 @@ decl_module! {
             Self::do_update_entity_property_values(&raw_origin, with_credential, as_entity_maintainer, entity_id, property_values)
         }
 
+        /// Removes an entity which is not referenced by other entities.
+        pub fn remove_entity(
+            origin,
+            with_credential: Option<T::Credential>,
+            as_entity_maintainer: bool,
+            entity_id: EntityId
+        ) -> dispatch::Result {
+            let raw_origin = Self::ensure_root_or_signed(origin)?;
+            Self::do_remove_entity(&raw_origin, with_credential, as_entity_maintainer, entity_id)
+        }
+
         pub fn transaction(origin, operations: Vec<Operation<T::Credential>>) -> dispatch::Result {
             // This map holds the EntityId of the entity created as a result of executing a CreateEntity Operation
             // keyed by the indexed of the operation, in the operations vector.
@@ -344,6 +519,27 @@ decl_module! {
 
             let raw_origin = Self::ensure_root_or_signed(origin)?;
 
+            // Assertions are preconditions on the state before the transaction. They are all checked
+            // before executing any operation, so that a failed assertion aborts the whole transaction.
+            for operation in operations.iter() {
+                match &operation.operation_type {
+                    OperationType::AssertPropertyValue(assert_property_value_operation) => {
+                        <versioned_store::Module<T>>::ensure_entity_property_value(
+                            assert_property_value_operation.entity_id,
+                            assert_property_value_operation.in_class_index,
+                            &assert_property_value_operation.expected_value
+                        )?;
+                    },
+                    OperationType::AssertEntityVersion(assert_entity_version_operation) => {
+                        <versioned_store::Module<T>>::ensure_entity_version(
+                            assert_entity_version_operation.entity_id,
+                            assert_entity_version_operation.expected_version
+                        )?;
+                    },
+                    _ => {}
+                }
+            }
+
             for (op_index, operation) in operations.into_iter().enumerate() {
                 match operation.operation_type {
                     OperationType::CreateEntity(create_entity_operation) => {
@@ -360,7 +556,18 @@ decl_module! {
                         let schema_id = add_schema_support_to_entity_operation.schema_id;
                         let property_values = operations::parametrized_property_values_to_property_values(&entity_created_in_operation, add_schema_support_to_entity_operation.parametrized_property_values)?;
                         Self::do_add_schema_support_to_entity(&raw_origin, operation.with_credential, operation.as_entity_maintainer, entity_id, schema_id, property_values)?;
-                    }
+                    },
+                    OperationType::MigrateEntitiesSchema(migrate_entities_schema_operation) => {
+                        let from_schema_id = migrate_entities_schema_operation.from_schema_id;
+                        let to_schema_id = migrate_entities_schema_operation.to_schema_id;
+                        let default_property_values = operations::parametrized_property_values_to_property_values(&entity_created_in_operation, migrate_entities_schema_operation.default_parametrized_property_values)?;
+                        for parametrized_entity in migrate_entities_schema_operation.entity_ids.into_iter() {
+                            let entity_id = operations::parametrized_entity_to_entity_id(&entity_created_in_operation, parametrized_entity)?;
+                            Self::do_migrate_entity_schema(&raw_origin, operation.with_credential, operation.as_entity_maintainer, entity_id, from_schema_id, to_schema_id, default_property_values.clone())?;
+                        }
+                    },
+                    // Already checked before executing any operation
+                    OperationType::AssertPropertyValue(_) | OperationType::AssertEntityVersion(_) => {}
                 }
             }
 
@@ -404,6 +611,7 @@ impl<T: Trait> Module<T> {
                     },
                 );
 
+                Self::deposit_event(RawEvent::EntityCreated(access_level.clone(), entity_id));
                 Ok(entity_id)
             },
         )
@@ -426,17 +634,34 @@ impl<T: Trait> Module<T> {
             None
         };
 
+        let property_indexes: Vec<u16> = property_values
+            .iter()
+            .map(|property_value| property_value.in_class_index)
+            .collect();
+
         Self::if_class_permissions_satisfied(
             raw_origin,
             with_credential,
             as_entity_maintainer,
-            ClassPermissions::can_update_entity,
+            |class_permissions, access_level| {
+                ClassPermissions::can_update_entity_properties(
+                    class_permissions,
+                    access_level,
+                    &property_indexes,
+                )
+            },
             class_id,
-            |_class_permissions, _access_level| {
+            |_class_permissions, access_level| {
                 <versioned_store::Module<T>>::update_entity_property_values(
                     entity_id,
                     property_values,
-                )
+                )?;
+                Self::deposit_event(RawEvent::EntityPropertyValuesUpdated(
+                    access_level.clone(),
+                    entity_id,
+                    property_indexes.clone(),
+                ));
+                Ok(())
             },
         )
     }
@@ -460,19 +685,171 @@ impl<T: Trait> Module<T> {
             None
         };
 
+        let property_indexes: Vec<u16> = property_values
+            .iter()
+            .map(|property_value| property_value.in_class_index)
+            .collect();
+
         Self::if_class_permissions_satisfied(
             raw_origin,
             with_credential,
             as_entity_maintainer,
-            ClassPermissions::can_update_entity,
+            |class_permissions, access_level| {
+                ClassPermissions::can_update_entity_properties(
+                    class_permissions,
+                    access_level,
+                    &property_indexes,
+                )
+            },
             class_id,
-            |_class_permissions, _access_level| {
+            |_class_permissions, access_level| {
                 <versioned_store::Module<T>>::add_schema_support_to_entity(
                     entity_id,
                     schema_id,
                     property_values,
+                )?;
+                Self::deposit_event(RawEvent::EntitySchemaAdded(
+                    access_level.clone(),
+                    entity_id,
+                    schema_id,
+                ));
+                Ok(())
+            },
+        )
+    }
+
+    fn do_migrate_entity_schema(
+        raw_origin: &system::RawOrigin<T::AccountId>,
+        with_credential: Option<T::Credential>,
+        as_entity_maintainer: bool,
+        entity_id: EntityId,
+        from_schema_id: u16,
+        to_schema_id: u16,
+        default_property_values: Vec<ClassPropertyValue>,
+    ) -> dispatch::Result {
+        let class_id = Self::get_class_id_by_entity_id(entity_id)?;
+
+        Self::ensure_internal_property_values_permitted(class_id, &default_property_values)?;
+
+        let as_entity_maintainer = if as_entity_maintainer {
+            Some(entity_id)
+        } else {
+            None
+        };
+
+        let property_indexes: Vec<u16> = default_property_values
+            .iter()
+            .map(|property_value| property_value.in_class_index)
+            .collect();
+
+        Self::if_class_permissions_satisfied(
+            raw_origin,
+            with_credential,
+            as_entity_maintainer,
+            |class_permissions, access_level| {
+                ClassPermissions::can_update_entity_properties(
+                    class_permissions,
+                    access_level,
+                    &property_indexes,
                 )
             },
+            class_id,
+            |_class_permissions, access_level| {
+                <versioned_store::Module<T>>::migrate_entity_schema(
+                    entity_id,
+                    from_schema_id,
+                    to_schema_id,
+                    default_property_values,
+                )?;
+                Self::deposit_event(RawEvent::EntitySchemaMigrated(
+                    access_level.clone(),
+                    entity_id,
+                    from_schema_id,
+                    to_schema_id,
+                ));
+                Ok(())
+            },
+        )
+    }
+
+    fn do_remove_entity(
+        raw_origin: &system::RawOrigin<T::AccountId>,
+        with_credential: Option<T::Credential>,
+        as_entity_maintainer: bool,
+        entity_id: EntityId,
+    ) -> dispatch::Result {
+        let class_id = Self::get_class_id_by_entity_id(entity_id)?;
+
+        let as_entity_maintainer = if as_entity_maintainer {
+            Some(entity_id)
+        } else {
+            None
+        };
+
+        Self::if_class_permissions_satisfied(
+            raw_origin,
+            with_credential,
+            as_entity_maintainer,
+            ClassPermissions::can_delete_entity,
+            class_id,
+            |_class_permissions, access_level| {
+                <versioned_store::Module<T>>::remove_entity(entity_id)?;
+                <EntityMaintainerByEntityId<T>>::remove(entity_id);
+                <EntityCoMaintainersByEntityId<T>>::remove(entity_id);
+                Self::deposit_event(RawEvent::EntityRemoved(access_level.clone(), entity_id));
+                Ok(())
+            },
+        )
+    }
+
+    fn store_entity_co_maintainers(
+        entity_id: EntityId,
+        co_maintainers: CredentialSet<T::Credential>,
+    ) {
+        if co_maintainers.is_empty() {
+            <EntityCoMaintainersByEntityId<T>>::remove(entity_id);
+        } else {
+            <EntityCoMaintainersByEntityId<T>>::insert(entity_id, co_maintainers);
+        }
+    }
+
+    /// Invokes the callback if the caller is the maintainer of the entity,
+    /// or an admin of its class when not acting as the entity maintainer.
+    fn if_entity_maintainer_or_class_admin<Callback>(
+        raw_origin: &system::RawOrigin<T::AccountId>,
+        with_credential: Option<T::Credential>,
+        as_entity_maintainer: bool,
+        entity_id: EntityId,
+        callback: Callback,
+    ) -> dispatch::Result
+    where
+        Callback: FnOnce(AccessLevel<T::Credential>) -> dispatch::Result,
+    {
+        let class_id = Self::get_class_id_by_entity_id(entity_id)?;
+
+        let as_entity_maintainer = if as_entity_maintainer {
+            Some(entity_id)
+        } else {
+            None
+        };
+
+        Self::if_class_permissions_satisfied(
+            raw_origin,
+            with_credential,
+            as_entity_maintainer,
+            |class_permissions, access_level| match access_level {
+                // co-maintainers are also given the EntityMaintainer access level
+                AccessLevel::EntityMaintainer => {
+                    if Self::entity_maintainer_by_entity_id(entity_id) == with_credential {
+                        Ok(())
+                    } else {
+                        Err("NotEnityMaintainer")
+                    }
+                }
+                _ => ClassPermissions::is_admin(class_permissions, access_level),
+            },
+            class_id,
+            |_class_permissions, access_level| callback(access_level.clone()),
         )
     }
 
@@ -489,19 +866,17 @@ impl<T: Trait> Module<T> {
                 if let Some(credential) = with_credential {
                     if T::CredentialChecker::account_has_credential(&account_id, credential) {
                         if let Some(entity_id) = as_entity_maintainer {
-                            // is entity maintained by system
-                            ensure!(
-                                <EntityMaintainerByEntityId<T>>::exists(entity_id),
-                                "NotEnityMaintainer"
-                            );
-                            // ensure entity maintainer matches
-                            match Self::entity_maintainer_by_entity_id(entity_id) {
-                                Some(maintainer_credential)
-                                    if credential == maintainer_credential =>
-                                {
-                                    Ok(AccessLevel::EntityMaintainer)
-                                }
-                                _ => Err("NotEnityMaintainer"),
+                            // ensure credential is the entity maintainer or one of its co-maintainers
+                            let is_maintainer =
+                                Self::entity_maintainer_by_entity_id(entity_id) == Some(credential);
+                            let is_co_maintainer =
+                                Self::entity_co_maintainers_by_entity_id(entity_id)
+                                    .contains(&credential);
+
+                            if is_maintainer || is_co_maintainer {
+                                Ok(AccessLevel::EntityMaintainer)
+                            } else {
+                                Err("NotEnityMaintainer")
                             }
                         } else {
                             Ok(AccessLevel::Credential(credential))
@@ -530,6 +905,7 @@ impl<T: Trait> Module<T> {
 
     /// Derives the access level of the caller.
     /// If the predicate passes, the mutate method is invoked.
+    /// Returns the access level the class permissions were mutated with.
     fn mutate_class_permissions<Predicate, Mutate>(
         raw_origin: &system::RawOrigin<T::AccountId>,
         with_credential: Option<T::Credential>,
@@ -539,7 +915,7 @@ impl<T: Trait> Module<T> {
         class_id: ClassId,
         // actual mutation to apply.
         mutate: Mutate,
-    ) -> dispatch::Result
+    ) -> Result<AccessLevel<T::Credential>, &'static str>
     where
         Predicate:
             FnOnce(&ClassPermissionsType<T>, &AccessLevel<T::Credential>) -> dispatch::Result,
@@ -552,7 +928,7 @@ impl<T: Trait> Module<T> {
         mutate(&mut class_permissions)?;
         class_permissions.last_permissions_update = <system::Module<T>>::block_number();
         <ClassPermissionsByClassId<T>>::insert(class_id, class_permissions);
-        Ok(())
+        Ok(access_level)
     }
 
     fn is_system(
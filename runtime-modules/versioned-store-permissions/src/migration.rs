@@ -0,0 +1,85 @@
+//! Layouts of stored values as they were before the entity delete permissions and the property
+//! permissions were introduced, and their migration to the current layouts.
+
+use crate::*;
+use codec::{Decode, Encode};
+use common::storage_migration::translate_linked_map_value;
+
+/// Entity permissions of a class, before `delete`.
+#[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+pub struct OldEntityPermissions<Credential>
+where
+    Credential: Ord,
+{
+    pub update: CredentialSet<Credential>,
+    pub maintainer_has_all_permissions: bool,
+}
+
+/// Class permissions, with the old entity permissions and before `property_permissions`.
+#[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+pub struct OldClassPermissions<ClassId, Credential, PropertyIndex, BlockNumber>
+where
+    ClassId: Ord,
+    Credential: Ord + Clone,
+    PropertyIndex: Ord,
+{
+    pub entity_permissions: OldEntityPermissions<Credential>,
+    pub entities_can_be_created: bool,
+    pub add_schemas: CredentialSet<Credential>,
+    pub create_entities: CredentialSet<Credential>,
+    pub reference_constraint: ReferenceConstraint<ClassId, PropertyIndex>,
+    pub admins: CredentialSet<Credential>,
+    pub last_permissions_update: BlockNumber,
+}
+
+pub type OldClassPermissionsType<T> =
+    OldClassPermissions<ClassId, <T as Trait>::Credential, u16, <T as system::Trait>::BlockNumber>;
+
+impl<Credential: Ord> From<OldEntityPermissions<Credential>> for EntityPermissions<Credential> {
+    fn from(old: OldEntityPermissions<Credential>) -> Self {
+        EntityPermissions {
+            update: old.update,
+            delete: CredentialSet::new(),
+            maintainer_has_all_permissions: old.maintainer_has_all_permissions,
+        }
+    }
+}
+
+impl<ClassId, Credential, PropertyIndex, BlockNumber>
+    From<OldClassPermissions<ClassId, Credential, PropertyIndex, BlockNumber>>
+    for ClassPermissions<ClassId, Credential, PropertyIndex, BlockNumber>
+where
+    ClassId: Ord,
+    Credential: Ord + Clone,
+    PropertyIndex: Ord,
+{
+    fn from(old: OldClassPermissions<ClassId, Credential, PropertyIndex, BlockNumber>) -> Self {
+        ClassPermissions {
+            entity_permissions: old.entity_permissions.into(),
+            property_permissions: BTreeMap::new(),
+            entities_can_be_created: old.entities_can_be_created,
+            add_schemas: old.add_schemas,
+            create_entities: old.create_entities,
+            reference_constraint: old.reference_constraint,
+            admins: old.admins,
+            last_permissions_update: old.last_permissions_update,
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Adds empty entity delete permissions and property permissions to the class permissions
+    /// stored before they were introduced. Must only run when upgrading from a runtime without them.
+    pub fn migrate_class_permissions() {
+        for class_id in 1..<versioned_store::Module<T>>::next_class_id() {
+            translate_linked_map_value::<
+                ClassPermissionsByClassId<T>,
+                _,
+                _,
+                OldClassPermissionsType<T>,
+                ClassPermissionsType<T>,
+                _,
+            >(&class_id, Into::into);
+        }
+    }
+}
@@ -9,13 +9,23 @@ use runtime_primitives::{
     traits::{BlakeTwo256, IdentityLookup},
     Perbill,
 };
-use srml_support::{impl_outer_origin, parameter_types};
+use srml_support::{impl_outer_event, impl_outer_origin, parameter_types};
 use versioned_store::InputValidationLengthConstraint;
 
 impl_outer_origin! {
     pub enum Origin for Runtime {}
 }
 
+mod lib {
+    pub use crate::Event;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Runtime {
+        lib<T>,
+    }
+}
+
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Runtime;
@@ -37,7 +47,7 @@ impl system::Trait for Runtime {
     type AccountId = u64;
     type Lookup = IdentityLookup<Self::AccountId>;
     type Header = Header;
-    type Event = ();
+    type Event = TestEvent;
     type BlockHashCount = BlockHashCount;
     type MaximumBlockWeight = MaximumBlockWeight;
     type MaximumBlockLength = MaximumBlockLength;
@@ -53,9 +63,11 @@ impl timestamp::Trait for Runtime {
 
 impl versioned_store::Trait for Runtime {
     type Event = ();
+    type ContentIdExists = ();
 }
 
 impl Trait for Runtime {
+    type Event = TestEvent;
     type Credential = u64;
     type CredentialChecker = MockCredentialChecker;
     type CreateClassPermissionsChecker = MockCreateClassPermissionsChecker;
@@ -158,7 +170,32 @@ pub fn with_test_externalities<R, F: FnOnce() -> R>(f: F) -> R {
     build_test_externalities(versioned_store_config).execute_with(f)
 }
 
-// pub type System = system::Module;
+pub type System = system::Module<Runtime>;
+
+pub type RawLibTestEvent = RawEvent<
+    <Runtime as system::Trait>::AccountId,
+    AccessLevelOf<Runtime>,
+    <Runtime as Trait>::Credential,
+    CredentialSetOf<Runtime>,
+    EntityPermissionsOf<Runtime>,
+    PropertyPermissionsOf<Runtime>,
+    ReferenceConstraint<ClassId, u16>,
+>;
+
+/// Events deposited by the module, in the order they were deposited.
+pub fn get_lib_events() -> Vec<RawLibTestEvent> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            TestEvent::lib(event) => Some(event),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn get_last_event_or_panic() -> RawLibTestEvent {
+    get_lib_events().pop().expect("No event deposited.")
+}
 
 /// Export module on a test runtime
 pub type Permissions = Module<Runtime>;
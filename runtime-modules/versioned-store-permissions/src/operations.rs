@@ -48,11 +48,38 @@ pub struct AddSchemaSupportToEntityOperation {
     pub parametrized_property_values: Vec<ParametrizedClassPropertyValue>,
 }
 
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
+pub struct MigrateEntitiesSchemaOperation {
+    pub entity_ids: Vec<ParameterizedEntity>,
+    pub from_schema_id: u16,
+    pub to_schema_id: u16,
+    /// Values for properties required by the target schema which the entities don't have yet.
+    pub default_parametrized_property_values: Vec<ParametrizedClassPropertyValue>,
+}
+
+/// Precondition on the current value of an entity property.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
+pub struct AssertPropertyValueOperation {
+    pub entity_id: EntityId,
+    pub in_class_index: u16,
+    pub expected_value: PropertyValue,
+}
+
+/// Precondition on the current version of an entity.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
+pub struct AssertEntityVersionOperation {
+    pub entity_id: EntityId,
+    pub expected_version: u32,
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
 pub enum OperationType {
     CreateEntity(CreateEntityOperation),
     UpdatePropertyValues(UpdatePropertyValuesOperation),
     AddSchemaSupportToEntity(AddSchemaSupportToEntityOperation),
+    MigrateEntitiesSchema(MigrateEntitiesSchemaOperation),
+    AssertPropertyValue(AssertPropertyValueOperation),
+    AssertEntityVersion(AssertEntityVersionOperation),
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
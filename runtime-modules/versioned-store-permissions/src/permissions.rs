@@ -1,4 +1,8 @@
+#[cfg(feature = "std")]
+use serde_derive::{Deserialize, Serialize};
+
 use codec::{Decode, Encode};
+use rstd::collections::btree_map::BTreeMap;
 use srml_support::dispatch;
 
 use crate::constraint::*;
@@ -6,6 +10,7 @@ use crate::credentials::*;
 
 /// Permissions for an instance of a Class in the versioned store.
 #[derive(Encode, Decode, Default, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct ClassPermissions<ClassId, Credential, PropertyIndex, BlockNumber>
 where
     ClassId: Ord,
@@ -17,6 +22,10 @@ where
     /// root origin can update entities of this class.
     pub entity_permissions: EntityPermissions<Credential>,
 
+    /// Permissions for updating specific properties of entities of this class. They take
+    /// precedence over `entity_permissions` for the properties they are defined for.
+    pub property_permissions: BTreeMap<PropertyIndex, PropertyPermissions<Credential>>,
+
     /// Wether new entities of this class be created or not. Is not enforced for root origin.
     pub entities_can_be_created: bool,
 
@@ -131,15 +140,87 @@ where
             _ => Err("UnknownActor"),
         }
     }
+
+    /// Checks that every property being written can be updated with the access level.
+    /// Properties without their own permissions fall back to `can_update_entity`.
+    pub fn can_update_entity_properties(
+        class_permissions: &Self,
+        access_level: &AccessLevel<Credential>,
+        property_indexes: &[PropertyIndex],
+    ) -> dispatch::Result {
+        if property_indexes.is_empty() {
+            return Self::can_update_entity(class_permissions, access_level);
+        }
+
+        for property_index in property_indexes.iter() {
+            match class_permissions.property_permissions.get(property_index) {
+                Some(property_permissions) => match access_level {
+                    AccessLevel::System => Ok(()),
+                    AccessLevel::Credential(credential) => {
+                        if property_permissions.update.contains(credential) {
+                            Ok(())
+                        } else {
+                            Err("CredentialNotInPropertyPermissionsUpdateSet")
+                        }
+                    }
+                    AccessLevel::EntityMaintainer => {
+                        if property_permissions.maintainer_can_update {
+                            Ok(())
+                        } else {
+                            Err("MaintainerCannotUpdateProperty")
+                        }
+                    }
+                    _ => Err("UnknownActor"),
+                },
+                None => Self::can_update_entity(class_permissions, access_level),
+            }?;
+        }
+
+        Ok(())
+    }
+
+    pub fn can_delete_entity(
+        class_permissions: &Self,
+        access_level: &AccessLevel<Credential>,
+    ) -> dispatch::Result {
+        match access_level {
+            AccessLevel::System => Ok(()),
+            AccessLevel::Credential(credential) => {
+                if class_permissions
+                    .entity_permissions
+                    .delete
+                    .contains(credential)
+                {
+                    Ok(())
+                } else {
+                    Err("CredentialNotInEntityPermissionsDeleteSet")
+                }
+            }
+            AccessLevel::EntityMaintainer => {
+                if class_permissions
+                    .entity_permissions
+                    .maintainer_has_all_permissions
+                {
+                    Ok(())
+                } else {
+                    Err("MaintainerNotGivenAllPermissions")
+                }
+            }
+            _ => Err("UnknownActor"),
+        }
+    }
 }
 
 #[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct EntityPermissions<Credential>
 where
     Credential: Ord,
 {
     // Principals permitted to update any entity of the class which this permission is associated with.
     pub update: CredentialSet<Credential>,
+    /// Principals permitted to remove any entity of the class which this permission is associated with.
+    pub delete: CredentialSet<Credential>,
     /// Wether the designated maintainer (if set) of an entity has permission to update it.
     pub maintainer_has_all_permissions: bool,
 }
@@ -149,6 +230,29 @@ impl<Credential: Ord> Default for EntityPermissions<Credential> {
         EntityPermissions {
             maintainer_has_all_permissions: true,
             update: CredentialSet::new(),
+            delete: CredentialSet::new(),
+        }
+    }
+}
+
+/// Who can update a specific property of entities of a class.
+#[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PropertyPermissions<Credential>
+where
+    Credential: Ord,
+{
+    /// Principals permitted to update the property of any entity of the class.
+    pub update: CredentialSet<Credential>,
+    /// Wether the designated maintainer (if set) of an entity has permission to update the property.
+    pub maintainer_can_update: bool,
+}
+
+impl<Credential: Ord> Default for PropertyPermissions<Credential> {
+    fn default() -> Self {
+        PropertyPermissions {
+            maintainer_can_update: true,
+            update: CredentialSet::new(),
         }
     }
 }
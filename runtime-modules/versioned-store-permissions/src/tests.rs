@@ -5,7 +5,8 @@ use crate::mock::*;
 use rstd::collections::btree_set::BTreeSet;
 use versioned_store::PropertyType;
 
-use srml_support::{assert_err, assert_ok};
+use common::storage_migration::replace_linked_map_value;
+use srml_support::{assert_err, assert_ok, StorageLinkedMap};
 
 fn simple_test_schema() -> Vec<Property> {
     vec![Property {
@@ -13,6 +14,9 @@ fn simple_test_schema() -> Vec<Property> {
         required: false,
         name: b"field1".to_vec(),
         description: b"Description field1".to_vec(),
+        unique: false,
+        indexed: false,
+        constraints: vec![],
     }]
 }
 
@@ -382,6 +386,7 @@ fn class_permissions_set_class_entity_permissions() {
 
         let entity_permissions1 = EntityPermissions {
             update: CredentialSet::from(vec![1]),
+            delete: CredentialSet::new(),
             maintainer_has_all_permissions: true,
         };
 
@@ -397,6 +402,7 @@ fn class_permissions_set_class_entity_permissions() {
 
         let entity_permissions2 = EntityPermissions {
             update: CredentialSet::from(vec![4]),
+            delete: CredentialSet::from(vec![4]),
             maintainer_has_all_permissions: true,
         };
         //admins
@@ -485,6 +491,231 @@ fn class_permissions_set_class_reference_constraint() {
     })
 }
 
+#[test]
+fn update_entity_property_values_with_property_permissions() {
+    with_test_externalities(|| {
+        const CREDENTIAL_ZERO: u64 = 0;
+        const CREDENTIAL_ONE: u64 = 1;
+        const PROPERTY_INDEX_ZERO: u16 = 0;
+        const PROPERTY_INDEX_ONE: u16 = 1;
+
+        let class_id = create_simple_class(ClassPermissions {
+            entity_permissions: EntityPermissions {
+                update: vec![CREDENTIAL_ZERO].into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        let mut properties = simple_test_schema();
+        properties.push(Property {
+            name: b"field2".to_vec(),
+            description: b"Description field2".to_vec(),
+            ..properties[0].clone()
+        });
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            vec![],
+            properties
+        ));
+
+        let entity_id = next_entity_id();
+        assert_ok!(Permissions::create_entity(Origin::ROOT, None, class_id));
+        assert_ok!(Permissions::add_schema_support_to_entity(
+            Origin::ROOT,
+            None,
+            false,
+            entity_id,
+            0,
+            vec![]
+        ));
+
+        let update = |account_id, credential, property_index| {
+            Permissions::update_entity_property_values(
+                Origin::signed(account_id),
+                Some(credential),
+                false,
+                entity_id,
+                vec![ClassPropertyValue {
+                    in_class_index: property_index,
+                    value: PropertyValue::Int64(1),
+                }],
+            )
+        };
+
+        // only credential one may update the second property
+        assert_ok!(Permissions::set_class_property_permissions(
+            Origin::ROOT,
+            None,
+            class_id,
+            PROPERTY_INDEX_ONE,
+            Some(PropertyPermissions {
+                update: vec![CREDENTIAL_ONE].into(),
+                maintainer_can_update: false,
+            })
+        ));
+
+        assert_ok!(update(
+            MEMBER_ONE_WITH_CREDENTIAL_ONE,
+            CREDENTIAL_ONE,
+            PROPERTY_INDEX_ONE
+        ));
+        assert_err!(
+            update(
+                MEMBER_ONE_WITH_CREDENTIAL_ONE,
+                CREDENTIAL_ONE,
+                PROPERTY_INDEX_ZERO
+            ),
+            "CredentialNotInEntityPermissionsUpdateSet"
+        );
+        assert_ok!(update(
+            MEMBER_ONE_WITH_CREDENTIAL_ZERO,
+            CREDENTIAL_ZERO,
+            PROPERTY_INDEX_ZERO
+        ));
+        assert_err!(
+            update(
+                MEMBER_ONE_WITH_CREDENTIAL_ZERO,
+                CREDENTIAL_ZERO,
+                PROPERTY_INDEX_ONE
+            ),
+            "CredentialNotInPropertyPermissionsUpdateSet"
+        );
+
+        // removing property permissions falls back to entity permissions
+        assert_ok!(Permissions::set_class_property_permissions(
+            Origin::ROOT,
+            None,
+            class_id,
+            PROPERTY_INDEX_ONE,
+            None
+        ));
+        assert_ok!(update(
+            MEMBER_ONE_WITH_CREDENTIAL_ZERO,
+            CREDENTIAL_ZERO,
+            PROPERTY_INDEX_ONE
+        ));
+        assert_err!(
+            update(
+                MEMBER_ONE_WITH_CREDENTIAL_ONE,
+                CREDENTIAL_ONE,
+                PROPERTY_INDEX_ONE
+            ),
+            "CredentialNotInEntityPermissionsUpdateSet"
+        );
+    })
+}
+
+#[test]
+fn add_and_migrate_entity_schema_with_property_permissions() {
+    with_test_externalities(|| {
+        const CREDENTIAL_ZERO: u64 = 0;
+        const CREDENTIAL_ONE: u64 = 1;
+        const PROPERTY_INDEX_ONE: u16 = 1;
+
+        let class_id = create_simple_class(ClassPermissions {
+            entity_permissions: EntityPermissions {
+                update: vec![CREDENTIAL_ZERO, CREDENTIAL_ONE].into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        // the second schema adds a property only credential one may update
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            vec![],
+            simple_test_schema()
+        ));
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            vec![0],
+            vec![Property {
+                name: b"field2".to_vec(),
+                description: b"Description field2".to_vec(),
+                ..simple_test_schema()[0].clone()
+            }]
+        ));
+        assert_ok!(Permissions::set_class_property_permissions(
+            Origin::ROOT,
+            None,
+            class_id,
+            PROPERTY_INDEX_ONE,
+            Some(PropertyPermissions {
+                update: vec![CREDENTIAL_ONE].into(),
+                maintainer_can_update: false,
+            })
+        ));
+
+        let protected_property_value = || ClassPropertyValue {
+            in_class_index: PROPERTY_INDEX_ONE,
+            value: PropertyValue::Int64(1),
+        };
+
+        let entity_id = next_entity_id();
+        assert_ok!(Permissions::create_entity(Origin::ROOT, None, class_id));
+
+        assert_err!(
+            Permissions::add_schema_support_to_entity(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+                Some(CREDENTIAL_ZERO),
+                false,
+                entity_id,
+                1,
+                vec![protected_property_value()]
+            ),
+            "CredentialNotInPropertyPermissionsUpdateSet"
+        );
+        assert_ok!(Permissions::add_schema_support_to_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+            Some(CREDENTIAL_ZERO),
+            false,
+            entity_id,
+            0,
+            simple_test_entity_property_values()
+        ));
+
+        let migrate_operation = |credential| Operation {
+            with_credential: Some(credential),
+            as_entity_maintainer: false,
+            operation_type: OperationType::MigrateEntitiesSchema(MigrateEntitiesSchemaOperation {
+                entity_ids: vec![ParameterizedEntity::ExistingEntity(entity_id)],
+                from_schema_id: 0,
+                to_schema_id: 1,
+                default_parametrized_property_values: vec![ParametrizedClassPropertyValue {
+                    in_class_index: PROPERTY_INDEX_ONE,
+                    value: ParametrizedPropertyValue::PropertyValue(PropertyValue::Int64(1)),
+                }],
+            }),
+        };
+
+        assert_err!(
+            Permissions::transaction(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+                vec![migrate_operation(CREDENTIAL_ZERO)]
+            ),
+            "CredentialNotInPropertyPermissionsUpdateSet"
+        );
+        assert_ok!(Permissions::transaction(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            vec![migrate_operation(CREDENTIAL_ONE)]
+        ));
+        assert_eq!(
+            versioned_store::EntityById::get(entity_id).values,
+            vec![
+                simple_test_entity_property_values()[0].clone(),
+                protected_property_value()
+            ]
+        );
+    })
+}
+
 #[test]
 fn batch_transaction_simple() {
     with_test_externalities(|| {
@@ -502,6 +733,9 @@ fn batch_transaction_simple() {
             required: true,
             name: b"entity".to_vec(),
             description: b"another entity of same class".to_vec(),
+            unique: false,
+            indexed: false,
+            constraints: vec![],
         }];
 
         assert_ok!(Permissions::add_class_schema(
@@ -586,6 +820,9 @@ fn batch_transaction_vector_of_entities() {
             required: true,
             name: b"entities".to_vec(),
             description: b"vector of entities of same class".to_vec(),
+            unique: false,
+            indexed: false,
+            constraints: vec![],
         }];
 
         assert_ok!(Permissions::add_class_schema(
@@ -663,3 +900,844 @@ fn batch_transaction_vector_of_entities() {
         );
     })
 }
+
+#[test]
+fn batch_transaction_migrate_entities_schema() {
+    with_test_externalities(|| {
+        const CREDENTIAL_ONE: u64 = 1;
+
+        let class_id = create_simple_class(ClassPermissions {
+            entities_can_be_created: true,
+            create_entities: vec![CREDENTIAL_ONE].into(),
+            ..Default::default()
+        });
+
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            vec![],
+            simple_test_schema()
+        ));
+
+        let new_properties = vec![Property {
+            prop_type: PropertyType::Bool,
+            required: true,
+            name: b"field2".to_vec(),
+            description: b"Description field2".to_vec(),
+            unique: false,
+            indexed: false,
+            constraints: vec![],
+        }];
+
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            vec![0],
+            new_properties
+        ));
+
+        let entity_id = next_entity_id();
+        for _ in 0..2 {
+            assert_ok!(Permissions::transaction(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                vec![
+                    Operation {
+                        with_credential: Some(CREDENTIAL_ONE),
+                        as_entity_maintainer: false,
+                        operation_type: OperationType::CreateEntity(CreateEntityOperation {
+                            class_id,
+                        }),
+                    },
+                    Operation {
+                        with_credential: Some(CREDENTIAL_ONE),
+                        as_entity_maintainer: true,
+                        operation_type: OperationType::AddSchemaSupportToEntity(
+                            AddSchemaSupportToEntityOperation {
+                                entity_id: ParameterizedEntity::InternalEntityJustAdded(0),
+                                schema_id: 0,
+                                parametrized_property_values: vec![],
+                            },
+                        ),
+                    },
+                ]
+            ));
+        }
+
+        // deprecate the first schema
+        assert_err!(
+            Permissions::set_class_schema_status(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                Some(CREDENTIAL_ONE),
+                class_id,
+                0,
+                false
+            ),
+            "NotInAddSchemasSet"
+        );
+        assert_ok!(Permissions::set_class_schema_status(
+            Origin::ROOT,
+            None,
+            class_id,
+            0,
+            false
+        ));
+
+        let migrate_operation = |default_parametrized_property_values| Operation {
+            with_credential: Some(CREDENTIAL_ONE),
+            as_entity_maintainer: true,
+            operation_type: OperationType::MigrateEntitiesSchema(MigrateEntitiesSchemaOperation {
+                entity_ids: vec![
+                    ParameterizedEntity::ExistingEntity(entity_id),
+                    ParameterizedEntity::ExistingEntity(entity_id + 1),
+                ],
+                from_schema_id: 0,
+                to_schema_id: 1,
+                default_parametrized_property_values,
+            }),
+        };
+
+        // default value for the new required property is missing
+        assert_err!(
+            Permissions::transaction(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                vec![migrate_operation(vec![])]
+            ),
+            "Some required property was not found when adding schema support to entity"
+        );
+
+        assert_ok!(Permissions::transaction(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            vec![migrate_operation(vec![ParametrizedClassPropertyValue {
+                in_class_index: 1,
+                value: ParametrizedPropertyValue::PropertyValue(PropertyValue::Bool(true)),
+            }])]
+        ));
+
+        for id in entity_id..entity_id + 2 {
+            let entity = versioned_store::EntityById::get(id);
+            assert_eq!(entity.in_class_schema_indexes, vec![1]);
+            assert_eq!(
+                entity.values,
+                vec![
+                    ClassPropertyValue {
+                        in_class_index: 0,
+                        value: PropertyValue::None,
+                    },
+                    ClassPropertyValue {
+                        in_class_index: 1,
+                        value: PropertyValue::Bool(true),
+                    },
+                ]
+            );
+        }
+    })
+}
+
+#[test]
+fn batch_transaction_with_assertions() {
+    with_test_externalities(|| {
+        const CREDENTIAL_ONE: u64 = 1;
+
+        let class_id = create_simple_class(ClassPermissions {
+            entities_can_be_created: true,
+            create_entities: vec![CREDENTIAL_ONE].into(),
+            ..Default::default()
+        });
+
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            vec![],
+            simple_test_schema()
+        ));
+
+        let entity_id = next_entity_id();
+        assert_ok!(Permissions::create_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(CREDENTIAL_ONE),
+            class_id,
+        ));
+        assert_ok!(Permissions::add_schema_support_to_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(CREDENTIAL_ONE),
+            true,
+            entity_id,
+            0,
+            simple_test_entity_property_values()
+        ));
+        assert_eq!(
+            <versioned_store::Module<Runtime>>::entity_version_by_id(entity_id),
+            1
+        );
+
+        let operations = |expected_value, expected_version| {
+            vec![
+                Operation {
+                    with_credential: Some(CREDENTIAL_ONE),
+                    as_entity_maintainer: true,
+                    operation_type: OperationType::UpdatePropertyValues(
+                        UpdatePropertyValuesOperation {
+                            entity_id: ParameterizedEntity::ExistingEntity(entity_id),
+                            new_parametrized_property_values: vec![
+                                ParametrizedClassPropertyValue {
+                                    in_class_index: 0,
+                                    value: ParametrizedPropertyValue::PropertyValue(
+                                        PropertyValue::Int64(42),
+                                    ),
+                                },
+                            ],
+                        },
+                    ),
+                },
+                // assertions are checked before the update above
+                Operation {
+                    with_credential: None,
+                    as_entity_maintainer: false,
+                    operation_type: OperationType::AssertPropertyValue(
+                        AssertPropertyValueOperation {
+                            entity_id,
+                            in_class_index: 0,
+                            expected_value: PropertyValue::Int64(expected_value),
+                        },
+                    ),
+                },
+                Operation {
+                    with_credential: None,
+                    as_entity_maintainer: false,
+                    operation_type: OperationType::AssertEntityVersion(
+                        AssertEntityVersionOperation {
+                            entity_id,
+                            expected_version,
+                        },
+                    ),
+                },
+            ]
+        };
+
+        assert_err!(
+            Permissions::transaction(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                operations(1337, 0)
+            ),
+            "Current entity version differs from the expected one"
+        );
+        assert_err!(
+            Permissions::transaction(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                operations(1, 1)
+            ),
+            "Current property value differs from the expected one"
+        );
+
+        // nothing has been updated by the aborted transactions
+        assert_eq!(
+            versioned_store::EntityById::get(entity_id).values,
+            simple_test_entity_property_values()
+        );
+
+        assert_ok!(Permissions::transaction(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            operations(1337, 1)
+        ));
+        assert_eq!(
+            versioned_store::EntityById::get(entity_id).values,
+            vec![ClassPropertyValue {
+                in_class_index: 0,
+                value: PropertyValue::Int64(42),
+            }]
+        );
+        assert_eq!(
+            <versioned_store::Module<Runtime>>::entity_version_by_id(entity_id),
+            2
+        );
+    })
+}
+
+#[test]
+fn transfer_entity_maintainer_and_set_co_maintainers() {
+    with_test_externalities(|| {
+        const CREDENTIAL_ZERO: u64 = 0;
+        const CREDENTIAL_ONE: u64 = 1;
+
+        let class_id = create_simple_class(ClassPermissions {
+            entities_can_be_created: true,
+            create_entities: vec![CREDENTIAL_ONE].into(),
+            admins: vec![CREDENTIAL_ZERO].into(),
+            ..Default::default()
+        });
+
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            vec![],
+            simple_test_schema()
+        ));
+
+        let entity_id = next_entity_id();
+        assert_ok!(Permissions::create_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(CREDENTIAL_ONE),
+            class_id,
+        ));
+
+        let update_as_maintainer = |account_id, credential| {
+            Permissions::add_schema_support_to_entity(
+                Origin::signed(account_id),
+                Some(credential),
+                true,
+                entity_id,
+                0,
+                simple_test_entity_property_values(),
+            )
+        };
+
+        // credential zero is not a maintainer yet
+        assert_err!(
+            update_as_maintainer(MEMBER_ONE_WITH_CREDENTIAL_ZERO, CREDENTIAL_ZERO),
+            "NotEnityMaintainer"
+        );
+
+        assert_ok!(Permissions::set_entity_co_maintainers(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(CREDENTIAL_ONE),
+            true,
+            entity_id,
+            vec![CREDENTIAL_ZERO].into()
+        ));
+
+        // co-maintainer can update the entity
+        assert_ok!(update_as_maintainer(
+            MEMBER_ONE_WITH_CREDENTIAL_ZERO,
+            CREDENTIAL_ZERO
+        ));
+
+        // but cannot hand it over
+        assert_err!(
+            Permissions::transfer_entity_maintainer(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+                Some(CREDENTIAL_ZERO),
+                true,
+                entity_id,
+                Some(CREDENTIAL_ZERO)
+            ),
+            "NotEnityMaintainer"
+        );
+
+        // non admins cannot transfer entities they don't maintain
+        assert_err!(
+            Permissions::transfer_entity_maintainer(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                Some(CREDENTIAL_ONE),
+                false,
+                entity_id,
+                Some(CREDENTIAL_ZERO)
+            ),
+            "NotInAdminsSet"
+        );
+
+        // class admin can override the maintainer
+        assert_ok!(Permissions::transfer_entity_maintainer(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+            Some(CREDENTIAL_ZERO),
+            false,
+            entity_id,
+            Some(CREDENTIAL_ZERO)
+        ));
+        assert_eq!(
+            Permissions::entity_maintainer_by_entity_id(entity_id),
+            Some(CREDENTIAL_ZERO)
+        );
+        assert!(
+            !Permissions::entity_co_maintainers_by_entity_id(entity_id).contains(&CREDENTIAL_ZERO)
+        );
+
+        // the previous maintainer is no longer a maintainer
+        assert_err!(
+            Permissions::transfer_entity_maintainer(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                Some(CREDENTIAL_ONE),
+                true,
+                entity_id,
+                Some(CREDENTIAL_ONE)
+            ),
+            "NotEnityMaintainer"
+        );
+
+        // new maintainer can hand the entity back
+        assert_ok!(Permissions::transfer_entity_maintainer(
+            Origin::signed(MEMBER_TWO_WITH_CREDENTIAL_ZERO),
+            Some(CREDENTIAL_ZERO),
+            true,
+            entity_id,
+            Some(CREDENTIAL_ONE)
+        ));
+        assert_eq!(
+            Permissions::entity_maintainer_by_entity_id(entity_id),
+            Some(CREDENTIAL_ONE)
+        );
+    })
+}
+
+#[test]
+fn remove_entity_with_entity_permissions() {
+    with_test_externalities(|| {
+        const CREDENTIAL_ONE: u64 = 1;
+
+        let class_id = create_simple_class(ClassPermissions {
+            entities_can_be_created: true,
+            create_entities: vec![CREDENTIAL_ONE].into(),
+            ..Default::default()
+        });
+
+        let entity_id = next_entity_id();
+        assert_ok!(Permissions::create_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(CREDENTIAL_ONE),
+            class_id,
+        ));
+
+        // credential not in the delete set
+        assert_err!(
+            Permissions::remove_entity(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+                Some(0),
+                false,
+                entity_id
+            ),
+            "CredentialNotInEntityPermissionsDeleteSet"
+        );
+
+        // maintainer has all permissions by default
+        assert_ok!(Permissions::remove_entity(
+            Origin::signed(MEMBER_TWO_WITH_CREDENTIAL_ONE),
+            Some(CREDENTIAL_ONE),
+            true,
+            entity_id
+        ));
+        assert!(!versioned_store::EntityById::exists(entity_id));
+        assert!(!<EntityMaintainerByEntityId<Runtime>>::exists(entity_id));
+
+        assert_err!(
+            Permissions::remove_entity(Origin::ROOT, None, false, entity_id),
+            "EntityNotFound"
+        );
+    })
+}
+
+#[test]
+fn class_permissions_setters_deposit_events() {
+    with_test_externalities(|| {
+        const CREDENTIAL_ZERO: u64 = 0;
+        const CREDENTIAL_ONE: u64 = 1;
+
+        let class_id = create_simple_class_with_default_permissions();
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::ClassCreated(
+                Some(CLASS_PERMISSIONS_CREATOR1),
+                AccessLevel::Unspecified,
+                class_id
+            )
+        );
+
+        let admins = CredentialSet::from(vec![CREDENTIAL_ZERO]);
+        assert_ok!(Permissions::set_class_admins(
+            Origin::ROOT,
+            class_id,
+            admins.clone()
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::ClassAdminsUpdated(AccessLevel::System, class_id, admins)
+        );
+
+        // the remaining setters are called by an admin
+        let admin_origin = || Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO);
+        let admin_access_level = AccessLevel::Credential(CREDENTIAL_ZERO);
+
+        let entity_permissions = EntityPermissions {
+            update: vec![CREDENTIAL_ONE].into(),
+            ..Default::default()
+        };
+        assert_ok!(Permissions::set_class_entity_permissions(
+            admin_origin(),
+            Some(CREDENTIAL_ZERO),
+            class_id,
+            entity_permissions.clone()
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::ClassEntityPermissionsUpdated(
+                admin_access_level.clone(),
+                class_id,
+                entity_permissions
+            )
+        );
+
+        assert_ok!(Permissions::set_class_entities_can_be_created(
+            admin_origin(),
+            Some(CREDENTIAL_ZERO),
+            class_id,
+            true
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::ClassEntitiesCanBeCreatedUpdated(admin_access_level.clone(), class_id, true)
+        );
+
+        let add_schemas_set = CredentialSet::from(vec![CREDENTIAL_ZERO]);
+        assert_ok!(Permissions::set_class_add_schemas_set(
+            admin_origin(),
+            Some(CREDENTIAL_ZERO),
+            class_id,
+            add_schemas_set.clone()
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::ClassAddSchemasSetUpdated(
+                admin_access_level.clone(),
+                class_id,
+                add_schemas_set
+            )
+        );
+
+        let create_entities_set = CredentialSet::from(vec![CREDENTIAL_ONE]);
+        assert_ok!(Permissions::set_class_create_entities_set(
+            admin_origin(),
+            Some(CREDENTIAL_ZERO),
+            class_id,
+            create_entities_set.clone()
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::ClassCreateEntitiesSetUpdated(
+                admin_access_level.clone(),
+                class_id,
+                create_entities_set
+            )
+        );
+
+        assert_ok!(Permissions::set_class_reference_constraint(
+            admin_origin(),
+            Some(CREDENTIAL_ZERO),
+            class_id,
+            ReferenceConstraint::NoConstraint
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::ClassReferenceConstraintUpdated(
+                admin_access_level.clone(),
+                class_id,
+                ReferenceConstraint::NoConstraint
+            )
+        );
+
+        let property_permissions = PropertyPermissions {
+            update: vec![CREDENTIAL_ONE].into(),
+            maintainer_can_update: false,
+        };
+        assert_ok!(Permissions::set_class_property_permissions(
+            admin_origin(),
+            Some(CREDENTIAL_ZERO),
+            class_id,
+            0,
+            Some(property_permissions.clone())
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::ClassPropertyPermissionsUpdated(
+                admin_access_level.clone(),
+                class_id,
+                0,
+                Some(property_permissions)
+            )
+        );
+
+        assert_ok!(Permissions::add_class_schema(
+            admin_origin(),
+            Some(CREDENTIAL_ZERO),
+            class_id,
+            vec![],
+            simple_test_schema()
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::ClassSchemaAdded(admin_access_level.clone(), class_id, 0)
+        );
+
+        assert_ok!(Permissions::set_class_schema_status(
+            admin_origin(),
+            Some(CREDENTIAL_ZERO),
+            class_id,
+            0,
+            false
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::ClassSchemaStatusUpdated(admin_access_level, class_id, 0, false)
+        );
+    })
+}
+
+#[test]
+fn entity_operations_deposit_events() {
+    with_test_externalities(|| {
+        const CREDENTIAL_ZERO: u64 = 0;
+        const CREDENTIAL_ONE: u64 = 1;
+
+        let class_id = create_simple_class(ClassPermissions {
+            entities_can_be_created: true,
+            create_entities: vec![CREDENTIAL_ONE].into(),
+            admins: vec![CREDENTIAL_ZERO].into(),
+            ..Default::default()
+        });
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            vec![],
+            simple_test_schema()
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::ClassSchemaAdded(AccessLevel::System, class_id, 0)
+        );
+
+        let entity_id = next_entity_id();
+        assert_ok!(Permissions::create_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(CREDENTIAL_ONE),
+            class_id
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::EntityCreated(AccessLevel::Credential(CREDENTIAL_ONE), entity_id)
+        );
+
+        assert_ok!(Permissions::add_schema_support_to_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(CREDENTIAL_ONE),
+            true,
+            entity_id,
+            0,
+            simple_test_entity_property_values()
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::EntitySchemaAdded(AccessLevel::EntityMaintainer, entity_id, 0)
+        );
+
+        assert_ok!(Permissions::update_entity_property_values(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(CREDENTIAL_ONE),
+            true,
+            entity_id,
+            simple_test_entity_property_values()
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::EntityPropertyValuesUpdated(
+                AccessLevel::EntityMaintainer,
+                entity_id,
+                vec![0]
+            )
+        );
+
+        let co_maintainers = CredentialSet::from(vec![CREDENTIAL_ZERO]);
+        assert_ok!(Permissions::set_entity_co_maintainers(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(CREDENTIAL_ONE),
+            true,
+            entity_id,
+            co_maintainers.clone()
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::EntityCoMaintainersUpdated(
+                AccessLevel::EntityMaintainer,
+                entity_id,
+                co_maintainers
+            )
+        );
+
+        // a class admin hands the entity over
+        assert_ok!(Permissions::transfer_entity_maintainer(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+            Some(CREDENTIAL_ZERO),
+            false,
+            entity_id,
+            None
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::EntityMaintainerTransferred(
+                AccessLevel::Credential(CREDENTIAL_ZERO),
+                entity_id,
+                None
+            )
+        );
+
+        assert_ok!(Permissions::remove_entity(
+            Origin::ROOT,
+            None,
+            false,
+            entity_id
+        ));
+        assert_eq!(
+            get_last_event_or_panic(),
+            RawEvent::EntityRemoved(AccessLevel::System, entity_id)
+        );
+    })
+}
+
+#[test]
+fn batch_transaction_deposits_events_of_operations() {
+    with_test_externalities(|| {
+        const CREDENTIAL_ONE: u64 = 1;
+
+        let class_id = create_simple_class(ClassPermissions {
+            entities_can_be_created: true,
+            create_entities: vec![CREDENTIAL_ONE].into(),
+            ..Default::default()
+        });
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            vec![],
+            simple_test_schema()
+        ));
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            vec![0],
+            vec![Property {
+                name: b"field2".to_vec(),
+                description: b"Description field2".to_vec(),
+                ..simple_test_schema()[0].clone()
+            }]
+        ));
+
+        let operation = |operation_type| Operation {
+            with_credential: Some(CREDENTIAL_ONE),
+            as_entity_maintainer: true,
+            operation_type,
+        };
+        let parametrized_property_values = || {
+            vec![ParametrizedClassPropertyValue {
+                in_class_index: 0,
+                value: ParametrizedPropertyValue::PropertyValue(PropertyValue::Int64(1337)),
+            }]
+        };
+
+        let entity_id = next_entity_id();
+        let events_before = get_lib_events().len();
+
+        assert_ok!(Permissions::transaction(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            vec![
+                Operation {
+                    with_credential: Some(CREDENTIAL_ONE),
+                    as_entity_maintainer: false,
+                    operation_type: OperationType::CreateEntity(CreateEntityOperation { class_id }),
+                },
+                operation(OperationType::AddSchemaSupportToEntity(
+                    AddSchemaSupportToEntityOperation {
+                        entity_id: ParameterizedEntity::InternalEntityJustAdded(0),
+                        schema_id: 0,
+                        parametrized_property_values: parametrized_property_values(),
+                    }
+                )),
+                operation(OperationType::UpdatePropertyValues(
+                    UpdatePropertyValuesOperation {
+                        entity_id: ParameterizedEntity::InternalEntityJustAdded(0),
+                        new_parametrized_property_values: parametrized_property_values(),
+                    }
+                )),
+                operation(OperationType::MigrateEntitiesSchema(
+                    MigrateEntitiesSchemaOperation {
+                        entity_ids: vec![ParameterizedEntity::InternalEntityJustAdded(0)],
+                        from_schema_id: 0,
+                        to_schema_id: 1,
+                        default_parametrized_property_values: vec![],
+                    }
+                )),
+            ]
+        ));
+
+        assert_eq!(
+            get_lib_events()[events_before..].to_vec(),
+            vec![
+                RawEvent::EntityCreated(AccessLevel::Credential(CREDENTIAL_ONE), entity_id),
+                RawEvent::EntitySchemaAdded(AccessLevel::EntityMaintainer, entity_id, 0),
+                RawEvent::EntityPropertyValuesUpdated(
+                    AccessLevel::EntityMaintainer,
+                    entity_id,
+                    vec![0]
+                ),
+                RawEvent::EntitySchemaMigrated(AccessLevel::EntityMaintainer, entity_id, 0, 1),
+            ]
+        );
+    })
+}
+
+#[test]
+fn migrate_class_permissions_stored_in_old_layout() {
+    with_test_externalities(|| {
+        const CREDENTIAL_ZERO: u64 = 0;
+        const CREDENTIAL_ONE: u64 = 1;
+
+        let class_id = create_simple_class_with_default_permissions();
+        let old_class_permissions: OldClassPermissionsType<Runtime> = OldClassPermissions {
+            entity_permissions: OldEntityPermissions {
+                update: vec![CREDENTIAL_ONE].into(),
+                maintainer_has_all_permissions: false,
+            },
+            entities_can_be_created: true,
+            add_schemas: vec![CREDENTIAL_ZERO].into(),
+            create_entities: vec![CREDENTIAL_ONE].into(),
+            reference_constraint: ReferenceConstraint::NoConstraint,
+            admins: vec![CREDENTIAL_ZERO].into(),
+            last_permissions_update: 5,
+        };
+        assert!(replace_linked_map_value::<
+            ClassPermissionsByClassId<Runtime>,
+            _,
+            _,
+            _,
+        >(&class_id, old_class_permissions.clone()));
+
+        Permissions::migrate_class_permissions();
+
+        assert_eq!(
+            Permissions::class_permissions_by_class_id(class_id),
+            ClassPermissions {
+                entity_permissions: EntityPermissions {
+                    update: old_class_permissions.entity_permissions.update,
+                    delete: CredentialSet::new(),
+                    maintainer_has_all_permissions: false,
+                },
+                property_permissions: BTreeMap::new(),
+                entities_can_be_created: true,
+                add_schemas: old_class_permissions.add_schemas,
+                create_entities: old_class_permissions.create_entities,
+                reference_constraint: ReferenceConstraint::NoConstraint,
+                admins: old_class_permissions.admins,
+                last_permissions_update: 5,
+            }
+        );
+
+        // The linkage of the class permissions is kept
+        assert_eq!(
+            <ClassPermissionsByClassId<Runtime>>::enumerate()
+                .map(|(class_id, _)| class_id)
+                .collect::<Vec<_>>(),
+            vec![class_id]
+        );
+    })
+}
@@ -30,6 +30,9 @@ fn create_podcast_class_schema() {
                 required: true,
                 name: b"atom:link".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 1
             Property {
@@ -37,6 +40,9 @@ fn create_podcast_class_schema() {
                 required: true,
                 name: b"title".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 2
             Property {
@@ -44,6 +50,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"pubDate".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 3
             Property {
@@ -51,6 +60,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"lastBuildDate".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 4
             Property {
@@ -58,6 +70,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"generator".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 5
             Property {
@@ -65,6 +80,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"link".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 6
             // Example: en-us
@@ -73,6 +91,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"language".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 7
             Property {
@@ -80,6 +101,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"copyright".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 8
             Property {
@@ -87,6 +111,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"docs".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 9
             Property {
@@ -94,6 +121,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"managingEditor".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 10
             Property {
@@ -101,6 +131,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"image/url".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 11
             Property {
@@ -108,6 +141,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"image/title".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 12
             Property {
@@ -115,6 +151,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"image/link".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 13
             Property {
@@ -122,6 +161,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:summary".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 14
             // TODO this could be Internal prop.
@@ -130,6 +172,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:author".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 15
             // TODO make this as a text vec?
@@ -138,6 +183,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:keywords".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 16
             Property {
@@ -145,6 +193,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:category".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 17
             Property {
@@ -152,6 +203,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:image".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 18
             Property {
@@ -159,6 +213,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:explicit".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 19
             Property {
@@ -166,6 +223,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:owner/itunes:name".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 20
             Property {
@@ -173,6 +233,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:owner/itunes:email".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 21
             Property {
@@ -180,6 +243,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"description".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 22
             Property {
@@ -187,6 +253,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:subtitle".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 23
             Property {
@@ -194,6 +263,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:type".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
         ];
 
@@ -207,6 +279,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"title".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 1
             Property {
@@ -214,6 +289,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:title".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 2
             Property {
@@ -221,6 +299,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"pubDate".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 3
             Property {
@@ -228,6 +309,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"guid".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 4
             Property {
@@ -235,6 +319,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"link".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 5
             Property {
@@ -242,6 +329,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:image".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 6
             Property {
@@ -249,6 +339,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"description".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 7
             Property {
@@ -256,6 +349,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"content:encoded".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 8
             Property {
@@ -263,6 +359,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"enclosure/length".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 9
             Property {
@@ -270,6 +369,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"enclosure/type".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 10
             Property {
@@ -277,6 +379,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"enclosure/url".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 11
             Property {
@@ -284,6 +389,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:duration".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 12
             Property {
@@ -291,6 +399,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:explicit".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 13
             // TODO make this as a text vec?
@@ -299,6 +410,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:keywords".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 14
             Property {
@@ -306,6 +420,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:subtitle".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 15
             Property {
@@ -313,6 +430,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:summary".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 16
             Property {
@@ -320,6 +440,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:season".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 17
             Property {
@@ -327,6 +450,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:episode".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 18
             Property {
@@ -334,6 +460,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:episodeType".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
             // 19
             // TODO this could be Internal prop.
@@ -342,6 +471,9 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:author".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
             },
         ];
 
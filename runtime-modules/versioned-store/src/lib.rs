@@ -15,14 +15,19 @@
 use serde_derive::{Deserialize, Serialize};
 
 use codec::{Decode, Encode};
+use rstd::collections::btree_map::BTreeMap;
 use rstd::collections::btree_set::BTreeSet;
 use rstd::prelude::*;
+use runtime_primitives::traits::Hash;
 use srml_support::{decl_event, decl_module, decl_storage, dispatch, ensure};
 
 mod example;
+mod migration;
 mod mock;
 mod tests;
 
+pub use migration::*;
+
 // Validation errors
 // --------------------------------------
 
@@ -48,9 +53,13 @@ const ERROR_CLASS_SCHEMA_REFERS_UNKNOWN_INTERNAL_ID: &str =
 const ERROR_NO_PROPS_IN_CLASS_SCHEMA: &str =
     "Cannot add a class schema with an empty list of properties";
 const ERROR_ENTITY_NOT_FOUND: &str = "Entity was not found by id";
-// const ERROR_ENTITY_ALREADY_DELETED: &str = "Entity is already deleted";
+const ERROR_ENTITY_IS_REFERENCED: &str =
+    "Cannot remove an entity that is referenced by other entities";
 const ERROR_SCHEMA_ALREADY_ADDED_TO_ENTITY: &str =
     "Cannot add a schema that is already added to this entity";
+const ERROR_CLASS_SCHEMA_NOT_ACTIVE: &str = "Class schema is not active";
+const ERROR_SCHEMA_NOT_ADDED_TO_ENTITY: &str = "Entity does not support the given schema";
+const ERROR_MIGRATION_TO_SAME_SCHEMA: &str = "Cannot migrate an entity to the same schema";
 const ERROR_PROP_VALUE_DONT_MATCH_TYPE: &str =
     "Some of the provided property values don't match the expected property type";
 const ERROR_PROP_NAME_NOT_UNIQUE_IN_CLASS: &str = "Property name is not unique within its class";
@@ -61,6 +70,20 @@ const ERROR_TEXT_PROP_IS_TOO_LONG: &str = "Text propery is too long";
 const ERROR_VEC_PROP_IS_TOO_LONG: &str = "Vector propery is too long";
 const ERROR_INTERNAL_RPOP_DOES_NOT_MATCH_ITS_CLASS: &str =
     "Internal property does not match its class";
+const ERROR_PROP_VALUE_NOT_UNIQUE_IN_CLASS: &str =
+    "Value of a unique property is already used by another entity of its class";
+const ERROR_EXTERNAL_CONTENT_NOT_FOUND: &str =
+    "External property refers to unknown content or content of another data object type";
+const ERROR_CONSTRAINT_DOES_NOT_MATCH_PROP_TYPE: &str =
+    "Property constraint cannot be applied to the type of its property";
+const ERROR_INVALID_RANGE_CONSTRAINT: &str = "Min of a range constraint is greater than its max";
+const ERROR_PROP_VALUE_OUT_OF_RANGE: &str = "Property value is out of its allowed range";
+const ERROR_PROP_VALUE_NOT_ALLOWED: &str = "Property value is not one of its allowed values";
+const ERROR_TEXT_PROP_DOES_NOT_MATCH_PATTERN: &str =
+    "Text property contains characters not allowed by its pattern";
+const ERROR_VEC_PROP_IS_EMPTY: &str = "Vector property cannot be empty";
+const ERROR_PROP_VALUE_CHANGED: &str = "Current property value differs from the expected one";
+const ERROR_ENTITY_VERSION_CHANGED: &str = "Current entity version differs from the expected one";
 
 /// Length constraint for input validation
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -102,6 +125,10 @@ impl InputValidationLengthConstraint {
 pub type ClassId = u64;
 pub type EntityId = u64;
 
+/// Id of content in the data directory of the storage module.
+pub type ContentId = primitives::H256;
+pub type DataObjectTypeId = u64;
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
 pub struct Class {
@@ -135,7 +162,6 @@ pub struct Entity {
     /// Values for properties on class that are used by some schema used by this entity!
     /// Length is no more than Class.properties.
     pub values: Vec<ClassPropertyValue>,
-    // pub deleted: bool,
 }
 
 /// A schema defines what properties describe an entity
@@ -144,6 +170,9 @@ pub struct Entity {
 pub struct ClassSchema {
     /// Indices into properties vector for the corresponding class.
     pub properties: Vec<u16>,
+
+    /// Deprecated schemas are not active and cannot be added to entities.
+    pub is_active: bool,
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -153,6 +182,22 @@ pub struct Property {
     pub required: bool,
     pub name: Vec<u8>,
     pub description: Vec<u8>,
+
+    /// No two entities of the class can have the same value of this property.
+    pub unique: bool,
+
+    /// Entities of the class can be looked up by the value of this property.
+    pub indexed: bool,
+
+    /// Restrictions on values of this property, checked on every write.
+    pub constraints: Vec<PropertyConstraint>,
+}
+
+impl Property {
+    /// Values of unique properties are indexed as well, to enforce the uniqueness.
+    pub fn is_indexed(&self) -> bool {
+        self.unique || self.indexed
+    }
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -189,8 +234,20 @@ pub enum PropertyType {
     /// The second ClassId value tells that an every element of this vector
     /// should be of a specific ClassId.
     InternalVec(u16, ClassId),
-    // External(ExternalProperty),
-    // ExternalVec(u16, ExternalProperty),
+
+    /// Content id of a data object in the data directory.
+    External(ExternalProperty),
+
+    /// The first u16 value is the max length of this vector.
+    ExternalVec(u16, ExternalProperty),
+}
+
+/// Content in the storage referenced by a property.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct ExternalProperty {
+    /// Data object type the referenced content is required to be of, if any.
+    pub data_object_type_id: Option<DataObjectTypeId>,
 }
 
 impl Default for PropertyType {
@@ -199,6 +256,90 @@ impl Default for PropertyType {
     }
 }
 
+impl PropertyType {
+    /// Type of every item of a vector type. A single value type is returned as is.
+    pub fn item_type(&self) -> PropertyType {
+        match self {
+            PropertyType::BoolVec(_) => PropertyType::Bool,
+            PropertyType::Uint16Vec(_) => PropertyType::Uint16,
+            PropertyType::Uint32Vec(_) => PropertyType::Uint32,
+            PropertyType::Uint64Vec(_) => PropertyType::Uint64,
+            PropertyType::Int16Vec(_) => PropertyType::Int16,
+            PropertyType::Int32Vec(_) => PropertyType::Int32,
+            PropertyType::Int64Vec(_) => PropertyType::Int64,
+            PropertyType::TextVec(_, text_max_len) => PropertyType::Text(*text_max_len),
+            PropertyType::InternalVec(_, class_id) => PropertyType::Internal(*class_id),
+            PropertyType::ExternalVec(_, external_prop) => {
+                PropertyType::External(external_prop.clone())
+            }
+            single_type => single_type.clone(),
+        }
+    }
+
+    pub fn is_vec(&self) -> bool {
+        *self != self.item_type()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        match self {
+            PropertyType::Uint16
+            | PropertyType::Uint32
+            | PropertyType::Uint64
+            | PropertyType::Int16
+            | PropertyType::Int32
+            | PropertyType::Int64 => true,
+            _ => false,
+        }
+    }
+}
+
+/// Declarative restriction on values of a property.
+/// Constraints of a vector property apply to every item of the vector.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum PropertyConstraint {
+    /// Inclusive min and max of an integer value. Wide enough to bound values
+    /// of both signed and unsigned 64 bit properties.
+    Range(i128, i128),
+
+    /// Value should be equal to one of these values.
+    OneOf(Vec<PropertyValue>),
+
+    /// Every character of a text value should belong to one of these classes.
+    TextPattern(Vec<CharClass>),
+
+    /// Vector value should have at least one item.
+    NonEmptyVec,
+}
+
+/// A class of ASCII characters allowed by a text pattern.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum CharClass {
+    /// a-z
+    Lowercase,
+    /// A-Z
+    Uppercase,
+    /// 0-9
+    Digit,
+    /// Space, tab and line breaks.
+    Whitespace,
+    /// Any of the listed characters.
+    Chars(Vec<u8>),
+}
+
+impl CharClass {
+    pub fn matches(&self, c: u8) -> bool {
+        match self {
+            CharClass::Lowercase => c.is_ascii_lowercase(),
+            CharClass::Uppercase => c.is_ascii_uppercase(),
+            CharClass::Digit => c.is_ascii_digit(),
+            CharClass::Whitespace => c.is_ascii_whitespace(),
+            CharClass::Chars(chars) => chars.contains(&c),
+        }
+    }
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
 pub enum PropertyValue {
@@ -225,8 +366,8 @@ pub enum PropertyValue {
     Int64Vec(Vec<i64>),
     TextVec(Vec<Vec<u8>>),
     InternalVec(Vec<EntityId>),
-    // External(ExternalPropertyType),
-    // ExternalVec(Vec<ExternalPropertyType>),
+    External(ContentId),
+    ExternalVec(Vec<ContentId>),
 }
 
 impl Default for PropertyValue {
@@ -235,6 +376,51 @@ impl Default for PropertyValue {
     }
 }
 
+impl PropertyValue {
+    /// Single values of every item of a vector value. A single value is returned as is.
+    pub fn items(&self) -> Vec<PropertyValue> {
+        fn wrap<T: Clone>(vec: &[T], f: fn(T) -> PropertyValue) -> Vec<PropertyValue> {
+            vec.iter().cloned().map(f).collect()
+        }
+
+        match self {
+            PropertyValue::BoolVec(vec) => wrap(vec, PropertyValue::Bool),
+            PropertyValue::Uint16Vec(vec) => wrap(vec, PropertyValue::Uint16),
+            PropertyValue::Uint32Vec(vec) => wrap(vec, PropertyValue::Uint32),
+            PropertyValue::Uint64Vec(vec) => wrap(vec, PropertyValue::Uint64),
+            PropertyValue::Int16Vec(vec) => wrap(vec, PropertyValue::Int16),
+            PropertyValue::Int32Vec(vec) => wrap(vec, PropertyValue::Int32),
+            PropertyValue::Int64Vec(vec) => wrap(vec, PropertyValue::Int64),
+            PropertyValue::TextVec(vec) => wrap(vec, PropertyValue::Text),
+            PropertyValue::InternalVec(vec) => wrap(vec, PropertyValue::Internal),
+            PropertyValue::ExternalVec(vec) => wrap(vec, PropertyValue::External),
+            single_value => vec![single_value.clone()],
+        }
+    }
+
+    /// Integer value widened to i128, so that every integer type can be compared with a range.
+    pub fn as_integer(&self) -> Option<i128> {
+        match *self {
+            PropertyValue::Uint16(value) => Some(value.into()),
+            PropertyValue::Uint32(value) => Some(value.into()),
+            PropertyValue::Uint64(value) => Some(value.into()),
+            PropertyValue::Int16(value) => Some(value.into()),
+            PropertyValue::Int32(value) => Some(value.into()),
+            PropertyValue::Int64(value) => Some(value.into()),
+            _ => None,
+        }
+    }
+
+    /// Ids of the entities referenced by this value, once per reference.
+    pub fn referenced_entity_ids(&self) -> Vec<EntityId> {
+        match self {
+            PropertyValue::Internal(entity_id) => vec![*entity_id],
+            PropertyValue::InternalVec(entity_ids) => entity_ids.clone(),
+            _ => vec![],
+        }
+    }
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
 pub struct ClassPropertyValue {
@@ -245,8 +431,31 @@ pub struct ClassPropertyValue {
     pub value: PropertyValue,
 }
 
+/// Trait for looking up content referenced by External property values in the storage,
+/// after the `ContentIdExists` trait of the storage module.
+pub trait ContentIdExists<T: Trait> {
+    fn has_content(_which: &ContentId) -> bool;
+
+    fn get_data_object_type_id(_which: &ContentId) -> Result<DataObjectTypeId, &'static str>;
+}
+
+/// An implementation where no content exists. Effectively External properties can only
+/// hold None values.
+impl<T: Trait> ContentIdExists<T> for () {
+    fn has_content(_which: &ContentId) -> bool {
+        false
+    }
+
+    fn get_data_object_type_id(_which: &ContentId) -> Result<DataObjectTypeId, &'static str> {
+        Err("Content not found")
+    }
+}
+
 pub trait Trait: system::Trait + Sized {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// External type for looking up the content referenced by External property values.
+    type ContentIdExists: ContentIdExists<Self>;
 }
 
 decl_storage! {
@@ -257,6 +466,56 @@ decl_storage! {
 
         pub EntityById get(entity_by_id) config(): map EntityId => Entity;
 
+        /// Version of an entity, incremented on every change of its schemas or property values.
+        /// Allows clients to detect concurrent changes of the entity.
+        pub EntityVersionById get(entity_version_by_id): map EntityId => u32;
+
+        /// Number of references to an entity from the Internal and InternalVec property values
+        /// of entities. Entities that are referenced cannot be removed.
+        pub ReferenceCountByEntityId get(reference_count_by_entity_id)
+            build(|config: &GenesisConfig| {
+                let mut counts = BTreeMap::new();
+                for (_, entity) in config.entity_by_id.iter() {
+                    for prop_value in entity.values.iter() {
+                        for entity_id in prop_value.value.referenced_entity_ids() {
+                            *counts.entry(entity_id).or_insert(0) += 1;
+                        }
+                    }
+                }
+                counts.into_iter().collect::<Vec<_>>()
+            }): map EntityId => u32;
+
+        /// Entities by the hash of a value of their unique or indexed property, keyed also by the
+        /// class and the index of the property in the class. None values are not indexed.
+        pub EntityIdsByPropertyValue get(entity_ids_by_property_value_hash)
+            build(|config: &GenesisConfig| {
+                let mut index: Vec<((ClassId, u16, T::Hash), Vec<EntityId>)> = vec![];
+                for (entity_id, entity) in config.entity_by_id.iter() {
+                    let class = config.class_by_id
+                        .iter()
+                        .find(|(class_id, _)| *class_id == entity.class_id)
+                        .map(|(_, class)| class);
+                    for prop_value in entity.values.iter() {
+                        let is_indexed = class
+                            .and_then(|class| class.properties.get(prop_value.in_class_index as usize))
+                            .map_or(false, |prop| prop.is_indexed());
+                        if !is_indexed || prop_value.value == PropertyValue::None {
+                            continue;
+                        }
+                        let key = (
+                            entity.class_id,
+                            prop_value.in_class_index,
+                            T::Hashing::hash_of(&prop_value.value),
+                        );
+                        match index.iter_mut().find(|(k, _)| *k == key) {
+                            Some((_, entity_ids)) => entity_ids.push(*entity_id),
+                            None => index.push((key, vec![*entity_id])),
+                        }
+                    }
+                }
+                index
+            }): map (ClassId, u16, T::Hash) => Vec<EntityId>;
+
         pub NextClassId get(next_class_id) config(): ClassId;
 
         pub NextEntityId get(next_entity_id) config(): EntityId;
@@ -282,11 +541,13 @@ decl_event!(
     {
         ClassCreated(ClassId),
         ClassSchemaAdded(ClassId, u16),
+        ClassSchemaStatusUpdated(ClassId, u16, bool),
 
         EntityCreated(EntityId),
-        // EntityDeleted(EntityId),
+        EntityRemoved(EntityId),
         EntityPropertiesUpdated(EntityId),
         EntitySchemaAdded(EntityId, u16),
+        EntitySchemaMigrated(EntityId, u16, u16),
 
         /// This is a fake event that uses AccountId type just to make Rust compiler happy to compile this module.
         FixCompilation(AccountId),
@@ -299,6 +560,17 @@ decl_module! {
     }
 }
 
+substrate_client::decl_runtime_apis! {
+    /// Lookup of entities by the values of their unique or indexed properties.
+    pub trait VersionedStoreApi {
+        fn entity_ids_by_property_value(
+            class_id: ClassId,
+            in_class_index: u16,
+            value: PropertyValue,
+        ) -> Vec<EntityId>;
+    }
+}
+
 // Shortcuts for faster readability of match expression:
 use PropertyType as PT;
 use PropertyValue as PV;
@@ -355,6 +627,7 @@ impl<T: Trait> Module<T> {
         for prop in new_properties.iter() {
             Self::ensure_property_name_is_valid(&prop.name)?;
             Self::ensure_property_description_is_valid(&prop.description)?;
+            Self::ensure_property_constraints_are_valid(prop)?;
 
             // Check that the name of a new property is unique within its class.
             ensure!(
@@ -389,6 +662,7 @@ impl<T: Trait> Module<T> {
 
         let mut schema = ClassSchema {
             properties: existing_properties,
+            is_active: true,
         };
 
         let mut updated_class_props = class.properties;
@@ -407,6 +681,31 @@ impl<T: Trait> Module<T> {
         Ok(schema_idx)
     }
 
+    /// Activates or deprecates a class schema. Entities that already support
+    /// a deprecated schema keep it until they are migrated to another one.
+    pub fn update_class_schema_status(
+        class_id: ClassId,
+        schema_id: u16,
+        is_active: bool,
+    ) -> dispatch::Result {
+        Self::ensure_known_class_id(class_id)?;
+
+        let class = ClassById::get(class_id);
+
+        // Check that schema_id is a valid index of class schemas vector:
+        let known_schema_id = schema_id < class.schemas.len() as u16;
+        ensure!(known_schema_id, ERROR_UNKNOWN_CLASS_SCHEMA_ID);
+
+        ClassById::mutate(class_id, |class| {
+            class.schemas[schema_id as usize].is_active = is_active;
+        });
+
+        Self::deposit_event(RawEvent::ClassSchemaStatusUpdated(
+            class_id, schema_id, is_active,
+        ));
+        Ok(())
+    }
+
     pub fn create_entity(class_id: ClassId) -> Result<EntityId, &'static str> {
         Self::ensure_known_class_id(class_id)?;
 
@@ -417,7 +716,6 @@ impl<T: Trait> Module<T> {
             class_id,
             in_class_schema_indexes: vec![],
             values: vec![],
-            // deleted: false,
         };
 
         // Save newly created entity:
@@ -443,6 +741,12 @@ impl<T: Trait> Module<T> {
         let known_schema_id = schema_id < class.schemas.len() as u16;
         ensure!(known_schema_id, ERROR_UNKNOWN_CLASS_SCHEMA_ID);
 
+        // Check that the schema has not been deprecated:
+        ensure!(
+            class.schemas[schema_id as usize].is_active,
+            ERROR_CLASS_SCHEMA_NOT_ACTIVE
+        );
+
         // Check that schema id is not yet added to this entity:
         let schema_not_added = entity
             .in_class_schema_indexes
@@ -482,6 +786,13 @@ impl<T: Trait> Module<T> {
                     } = new_prop;
 
                     Self::ensure_property_value_is_valid(new_value.clone(), class_prop.clone())?;
+                    Self::ensure_property_value_is_unique(
+                        entity.class_id,
+                        entity_id,
+                        *new_id,
+                        class_prop,
+                        new_value,
+                    )?;
 
                     appended_entity_values.push(ClassPropertyValue {
                         in_class_index: *new_id,
@@ -504,6 +815,10 @@ impl<T: Trait> Module<T> {
             }
         }
 
+        let appended_values = &appended_entity_values[current_entity_values.len()..];
+        Self::increment_references(appended_values);
+        Self::index_property_values(&class, entity_id, appended_values);
+
         EntityById::mutate(entity_id, |entity| {
             // Add a new schema to the list of schemas supported by this entity.
             entity.in_class_schema_indexes.push(schema_id);
@@ -514,10 +829,57 @@ impl<T: Trait> Module<T> {
             }
         });
 
+        Self::increment_entity_version(entity_id);
+
         Self::deposit_event(RawEvent::EntitySchemaAdded(entity_id, schema_id));
         Ok(())
     }
 
+    /// Moves an entity from one schema of its class to another.
+    /// Values of properties required by the target schema that the entity does not
+    /// have yet must be provided in `default_property_values`. Values of properties
+    /// that belong only to the source schema are left on the entity.
+    pub fn migrate_entity_schema(
+        entity_id: EntityId,
+        from_schema_id: u16,
+        to_schema_id: u16,
+        default_property_values: Vec<ClassPropertyValue>,
+    ) -> dispatch::Result {
+        Self::ensure_known_entity_id(entity_id)?;
+
+        ensure!(
+            from_schema_id != to_schema_id,
+            ERROR_MIGRATION_TO_SAME_SCHEMA
+        );
+
+        let entity = Self::entity_by_id(entity_id);
+
+        ensure!(
+            entity.in_class_schema_indexes.contains(&from_schema_id),
+            ERROR_SCHEMA_NOT_ADDED_TO_ENTITY
+        );
+
+        if entity.in_class_schema_indexes.contains(&to_schema_id) {
+            Self::increment_entity_version(entity_id);
+        } else {
+            // Increments the entity version as well
+            Self::add_schema_support_to_entity(entity_id, to_schema_id, default_property_values)?;
+        }
+
+        EntityById::mutate(entity_id, |entity| {
+            entity
+                .in_class_schema_indexes
+                .retain(|&schema_id| schema_id != from_schema_id);
+        });
+
+        Self::deposit_event(RawEvent::EntitySchemaMigrated(
+            entity_id,
+            from_schema_id,
+            to_schema_id,
+        ));
+        Ok(())
+    }
+
     pub fn update_entity_property_values(
         entity_id: EntityId,
         new_property_values: Vec<ClassPropertyValue>,
@@ -528,6 +890,7 @@ impl<T: Trait> Module<T> {
 
         // Get current property values of an entity as a mutable vector,
         // so we can update them if new values provided present in new_property_values.
+        let current_values = entity.values.clone();
         let mut updated_values = entity.values;
         let mut updates_count = 0;
 
@@ -557,6 +920,9 @@ impl<T: Trait> Module<T> {
                 // and check any additional constraints like the length of a vector
                 // if it's a vector property or the length of a text if it's a text property.
                 Self::ensure_property_value_is_valid(new_value.clone(), class_prop.clone())?;
+                Self::ensure_property_value_is_unique(
+                    class.id, entity_id, *valid_id, class_prop, new_value,
+                )?;
 
                 // Update a current prop value in a mutable vector, if a new value is valid.
                 *current_value = new_value.clone();
@@ -570,29 +936,127 @@ impl<T: Trait> Module<T> {
 
         // If at least one of the entity property values should be update:
         if updates_count > 0 {
+            Self::decrement_references(&current_values);
+            Self::increment_references(&updated_values);
+
+            // Reindex only the values that have changed
+            for (current_value, updated_value) in current_values.iter().zip(updated_values.iter()) {
+                if current_value != updated_value {
+                    Self::unindex_property_values(&class, entity_id, &[current_value.clone()]);
+                    Self::index_property_values(&class, entity_id, &[updated_value.clone()]);
+                }
+            }
+
             EntityById::mutate(entity_id, |entity| {
                 entity.values = updated_values;
             });
+            Self::increment_entity_version(entity_id);
             Self::deposit_event(RawEvent::EntityPropertiesUpdated(entity_id));
         }
 
         Ok(())
     }
 
-    // Commented out for now <- requested by Bedeho.
-    // pub fn delete_entity(entity_id: EntityId) -> dispatch::Result {
-    //     Self::ensure_known_entity_id(entity_id)?;
+    /// Removes an entity that is not referenced by other entities. References of the
+    /// entity to itself don't prevent the removal.
+    pub fn remove_entity(entity_id: EntityId) -> dispatch::Result {
+        Self::ensure_known_entity_id(entity_id)?;
+
+        let entity = Self::entity_by_id(entity_id);
+        let self_references = Self::referenced_entity_ids(&entity.values)
+            .iter()
+            .filter(|id| **id == entity_id)
+            .count() as u32;
+        ensure!(
+            Self::reference_count_by_entity_id(entity_id) <= self_references,
+            ERROR_ENTITY_IS_REFERENCED
+        );
+
+        Self::decrement_references(&entity.values);
+        Self::unindex_property_values(
+            &Self::class_by_id(entity.class_id),
+            entity_id,
+            &entity.values,
+        );
+        EntityById::remove(entity_id);
+        EntityVersionById::remove(entity_id);
+        ReferenceCountByEntityId::remove(entity_id);
+
+        Self::deposit_event(RawEvent::EntityRemoved(entity_id));
+        Ok(())
+    }
 
-    //     let is_deleted = EntityById::get(entity_id).deleted;
-    //     ensure!(!is_deleted, ERROR_ENTITY_ALREADY_DELETED);
+    /// Checks that an entity property still has the value the caller expects.
+    pub fn ensure_entity_property_value(
+        entity_id: EntityId,
+        in_class_index: u16,
+        expected_value: &PropertyValue,
+    ) -> dispatch::Result {
+        Self::ensure_known_entity_id(entity_id)?;
 
-    //     EntityById::mutate(entity_id, |x| {
-    //         x.deleted = true;
-    //     });
+        let entity = Self::entity_by_id(entity_id);
+        let current_value = entity
+            .values
+            .iter()
+            .find(|prop| prop.in_class_index == in_class_index)
+            .ok_or(ERROR_UNKNOWN_ENTITY_PROP_ID)?;
 
-    //     Self::deposit_event(RawEvent::EntityDeleted(entity_id));
-    //     Ok(())
-    // }
+        ensure!(
+            current_value.value == *expected_value,
+            ERROR_PROP_VALUE_CHANGED
+        );
+        Ok(())
+    }
+
+    /// Checks that an entity has not changed since the caller saw its version.
+    pub fn ensure_entity_version(entity_id: EntityId, expected_version: u32) -> dispatch::Result {
+        Self::ensure_known_entity_id(entity_id)?;
+        ensure!(
+            Self::entity_version_by_id(entity_id) == expected_version,
+            ERROR_ENTITY_VERSION_CHANGED
+        );
+        Ok(())
+    }
+
+    fn increment_entity_version(entity_id: EntityId) {
+        // Versions are only compared for equality, so wrapping around is fine.
+        EntityVersionById::mutate(entity_id, |version| *version = version.wrapping_add(1));
+    }
+
+    /// Entities of the class with the given value of a unique or indexed property.
+    pub fn entity_ids_by_property_value(
+        class_id: ClassId,
+        in_class_index: u16,
+        value: &PropertyValue,
+    ) -> Vec<EntityId> {
+        Self::entity_ids_by_property_value_hash(Self::property_value_key(
+            class_id,
+            in_class_index,
+            value,
+        ))
+    }
+
+    /// Recounts the references to all entities, replacing the stored counts. Initializes the
+    /// counts of entities stored before the reference counts were introduced.
+    pub fn initialize_reference_counts() {
+        let mut counts = BTreeMap::new();
+
+        for entity_id in 0..Self::next_entity_id() {
+            if EntityById::exists(entity_id) {
+                let values = Self::entity_by_id(entity_id).values;
+                for referenced_entity_id in Self::referenced_entity_ids(&values) {
+                    *counts.entry(referenced_entity_id).or_insert(0) += 1;
+                }
+            }
+        }
+
+        for entity_id in 0..Self::next_entity_id() {
+            match counts.get(&entity_id) {
+                Some(count) => ReferenceCountByEntityId::insert(entity_id, *count),
+                None => ReferenceCountByEntityId::remove(entity_id),
+            }
+        }
+    }
 
     // Helper functions:
     // ----------------------------------------------------------------
@@ -623,6 +1087,30 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    pub fn ensure_valid_external_prop(value: PropertyValue, prop: Property) -> dispatch::Result {
+        let (content_ids, external_prop) = match (value, prop.prop_type) {
+            (PV::External(content_id), PT::External(external_prop)) => {
+                (vec![content_id], external_prop)
+            }
+            (PV::ExternalVec(content_ids), PT::ExternalVec(_, external_prop)) => {
+                (content_ids, external_prop)
+            }
+            _ => return Ok(()),
+        };
+
+        for content_id in content_ids.iter() {
+            let type_id = T::ContentIdExists::get_data_object_type_id(content_id)
+                .map_err(|_| ERROR_EXTERNAL_CONTENT_NOT_FOUND)?;
+            ensure!(
+                external_prop
+                    .data_object_type_id
+                    .map_or(true, |expected_type_id| type_id == expected_type_id),
+                ERROR_EXTERNAL_CONTENT_NOT_FOUND
+            );
+        }
+        Ok(())
+    }
+
     pub fn is_unknown_internal_entity_id(id: PropertyValue) -> bool {
         if let PropertyValue::Internal(entity_id) = id {
             !EntityById::exists(entity_id)
@@ -631,6 +1119,89 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Ids of the entities referenced by Internal and InternalVec property values,
+    /// once per reference.
+    pub fn referenced_entity_ids(values: &[ClassPropertyValue]) -> Vec<EntityId> {
+        values
+            .iter()
+            .flat_map(|prop_value| prop_value.value.referenced_entity_ids())
+            .collect()
+    }
+
+    fn property_value_key(
+        class_id: ClassId,
+        in_class_index: u16,
+        value: &PropertyValue,
+    ) -> (ClassId, u16, T::Hash) {
+        (class_id, in_class_index, T::Hashing::hash_of(value))
+    }
+
+    pub fn ensure_property_value_is_unique(
+        class_id: ClassId,
+        entity_id: EntityId,
+        in_class_index: u16,
+        prop: &Property,
+        value: &PropertyValue,
+    ) -> dispatch::Result {
+        if !prop.unique || *value == PV::None {
+            return Ok(());
+        }
+
+        let used_by_other_entity =
+            Self::entity_ids_by_property_value(class_id, in_class_index, value)
+                .iter()
+                .any(|id| *id != entity_id);
+        ensure!(!used_by_other_entity, ERROR_PROP_VALUE_NOT_UNIQUE_IN_CLASS);
+        Ok(())
+    }
+
+    fn index_property_values(class: &Class, entity_id: EntityId, values: &[ClassPropertyValue]) {
+        for prop_value in Self::indexed_property_values(class, values) {
+            let key =
+                Self::property_value_key(class.id, prop_value.in_class_index, &prop_value.value);
+            <EntityIdsByPropertyValue<T>>::mutate(key, |entity_ids| entity_ids.push(entity_id));
+        }
+    }
+
+    fn unindex_property_values(class: &Class, entity_id: EntityId, values: &[ClassPropertyValue]) {
+        for prop_value in Self::indexed_property_values(class, values) {
+            let key =
+                Self::property_value_key(class.id, prop_value.in_class_index, &prop_value.value);
+            let mut entity_ids = Self::entity_ids_by_property_value_hash(&key);
+            entity_ids.retain(|id| *id != entity_id);
+            if entity_ids.is_empty() {
+                <EntityIdsByPropertyValue<T>>::remove(&key);
+            } else {
+                <EntityIdsByPropertyValue<T>>::insert(&key, entity_ids);
+            }
+        }
+    }
+
+    fn indexed_property_values<'a>(
+        class: &'a Class,
+        values: &'a [ClassPropertyValue],
+    ) -> impl Iterator<Item = &'a ClassPropertyValue> {
+        values.iter().filter(move |prop_value| {
+            prop_value.value != PV::None
+                && class
+                    .properties
+                    .get(prop_value.in_class_index as usize)
+                    .map_or(false, |prop| prop.is_indexed())
+        })
+    }
+
+    fn increment_references(values: &[ClassPropertyValue]) {
+        for entity_id in Self::referenced_entity_ids(values) {
+            ReferenceCountByEntityId::mutate(entity_id, |count| *count += 1);
+        }
+    }
+
+    fn decrement_references(values: &[ClassPropertyValue]) {
+        for entity_id in Self::referenced_entity_ids(values) {
+            ReferenceCountByEntityId::mutate(entity_id, |count| *count = count.saturating_sub(1));
+        }
+    }
+
     pub fn get_entity_and_class(entity_id: EntityId) -> (Entity, Class) {
         let entity = EntityById::get(entity_id);
         let class = ClassById::get(entity.class_id);
@@ -643,8 +1214,80 @@ impl<T: Trait> Module<T> {
     ) -> dispatch::Result {
         Self::ensure_prop_value_matches_its_type(value.clone(), prop.clone())?;
         Self::ensure_valid_internal_prop(value.clone(), prop.clone())?;
+        Self::ensure_valid_external_prop(value.clone(), prop.clone())?;
         Self::validate_max_len_if_text_prop(value.clone(), prop.clone())?;
-        Self::validate_max_len_if_vec_prop(value, prop)?;
+        Self::validate_max_len_if_vec_prop(value.clone(), prop.clone())?;
+        Self::ensure_property_value_satisfies_constraints(value, prop)?;
+        Ok(())
+    }
+
+    /// Checks that every constraint of a new property can be applied to its type.
+    pub fn ensure_property_constraints_are_valid(prop: &Property) -> dispatch::Result {
+        let item_type = prop.prop_type.item_type();
+        let item_prop = Property {
+            prop_type: item_type.clone(),
+            required: true,
+            ..Property::default()
+        };
+
+        for constraint in prop.constraints.iter() {
+            let matches_prop_type = match constraint {
+                PropertyConstraint::Range(min, max) => {
+                    ensure!(min <= max, ERROR_INVALID_RANGE_CONSTRAINT);
+                    item_type.is_integer()
+                }
+                PropertyConstraint::OneOf(values) => values.iter().all(|value| {
+                    Self::does_prop_value_match_type(value.clone(), item_prop.clone())
+                }),
+                PropertyConstraint::TextPattern(_) => match item_type {
+                    PropertyType::Text(_) => true,
+                    _ => false,
+                },
+                PropertyConstraint::NonEmptyVec => prop.prop_type.is_vec(),
+            };
+            ensure!(matches_prop_type, ERROR_CONSTRAINT_DOES_NOT_MATCH_PROP_TYPE);
+        }
+        Ok(())
+    }
+
+    pub fn ensure_property_value_satisfies_constraints(
+        value: PropertyValue,
+        prop: Property,
+    ) -> dispatch::Result {
+        // A value of a non required property can be removed regardless of its constraints.
+        if value == PV::None {
+            return Ok(());
+        }
+
+        let items = value.items();
+
+        for constraint in prop.constraints.iter() {
+            match constraint {
+                PropertyConstraint::Range(min, max) => {
+                    let is_in_range = items.iter().all(|item| match item.as_integer() {
+                        Some(int) => *min <= int && int <= *max,
+                        None => false,
+                    });
+                    ensure!(is_in_range, ERROR_PROP_VALUE_OUT_OF_RANGE);
+                }
+                PropertyConstraint::OneOf(allowed_values) => {
+                    let is_allowed = items.iter().all(|item| allowed_values.contains(item));
+                    ensure!(is_allowed, ERROR_PROP_VALUE_NOT_ALLOWED);
+                }
+                PropertyConstraint::TextPattern(char_classes) => {
+                    let matches_pattern = items.iter().all(|item| match item {
+                        PV::Text(text) => text
+                            .iter()
+                            .all(|&c| char_classes.iter().any(|class| class.matches(c))),
+                        _ => false,
+                    });
+                    ensure!(matches_pattern, ERROR_TEXT_PROP_DOES_NOT_MATCH_PATTERN);
+                }
+                PropertyConstraint::NonEmptyVec => {
+                    ensure!(!items.is_empty(), ERROR_VEC_PROP_IS_EMPTY);
+                }
+            }
+        }
         Ok(())
     }
 
@@ -685,6 +1328,7 @@ impl<T: Trait> Module<T> {
             (PV::Int16Vec(vec),    PT::Int16Vec(max_len))  => validate_vec_len(vec, max_len),
             (PV::Int32Vec(vec),    PT::Int32Vec(max_len))  => validate_vec_len(vec, max_len),
             (PV::Int64Vec(vec),    PT::Int64Vec(max_len))  => validate_vec_len(vec, max_len),
+            (PV::ExternalVec(vec), PT::ExternalVec(max_len, _)) => validate_vec_len(vec, max_len),
 
             (PV::TextVec(vec),     PT::TextVec(vec_max_len, text_max_len)) => {
                 if validate_vec_len_ref(&vec, vec_max_len) {
@@ -766,10 +1410,12 @@ impl<T: Trait> Module<T> {
             (PV::Int32Vec(_),    PT::Int32Vec(_)) |
             (PV::Int64Vec(_),    PT::Int64Vec(_)) |
             (PV::TextVec(_),     PT::TextVec(_, _)) |
-            (PV::InternalVec(_), PT::InternalVec(_, _)) => true,
+            (PV::InternalVec(_), PT::InternalVec(_, _)) |
+
+            // External content:
+            (PV::External(_),    PT::External(_)) |
+            (PV::ExternalVec(_), PT::ExternalVec(_, _)) => true,
 
-            // (PV::External(_), PT::External(_)) => true,
-            // (PV::ExternalVec(_), PT::ExternalVec(_, _)) => true,
             _ => false,
         }
     }
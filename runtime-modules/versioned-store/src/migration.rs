@@ -0,0 +1,76 @@
+//! Layouts of stored values as they were before the property constraints, unique and indexed
+//! properties and deprecated schemas were introduced, and their migration to the current layouts.
+
+use crate::*;
+use common::storage_migration::translate_map_value;
+
+/// Schema of a class, before `is_active`.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct OldClassSchema {
+    pub properties: Vec<u16>,
+}
+
+/// Property of a class, before `unique`, `indexed` and `constraints`.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct OldProperty {
+    pub prop_type: PropertyType,
+    pub required: bool,
+    pub name: Vec<u8>,
+    pub description: Vec<u8>,
+}
+
+/// Class, with the properties and schemas in their old layouts.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct OldClass {
+    pub id: ClassId,
+    pub properties: Vec<OldProperty>,
+    pub schemas: Vec<OldClassSchema>,
+    pub name: Vec<u8>,
+    pub description: Vec<u8>,
+}
+
+impl From<OldClassSchema> for ClassSchema {
+    fn from(old: OldClassSchema) -> Self {
+        ClassSchema {
+            properties: old.properties,
+            is_active: true,
+        }
+    }
+}
+
+impl From<OldProperty> for Property {
+    fn from(old: OldProperty) -> Self {
+        Property {
+            prop_type: old.prop_type,
+            required: old.required,
+            name: old.name,
+            description: old.description,
+            unique: false,
+            indexed: false,
+            constraints: vec![],
+        }
+    }
+}
+
+impl From<OldClass> for Class {
+    fn from(old: OldClass) -> Self {
+        Class {
+            id: old.id,
+            properties: old.properties.into_iter().map(Into::into).collect(),
+            schemas: old.schemas.into_iter().map(Into::into).collect(),
+            name: old.name,
+            description: old.description,
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Adds the new property fields and schema status to the classes stored before they were
+    /// introduced. Properties are neither unique nor indexed and have no constraints, all schemas
+    /// are active. Must only run when upgrading from a runtime without them.
+    pub fn migrate_classes() {
+        for class_id in 1..Self::next_class_id() {
+            translate_map_value::<ClassById, _, _, OldClass, Class, _>(&class_id, Into::into);
+        }
+    }
+}
@@ -52,6 +52,26 @@ impl timestamp::Trait for Runtime {
 
 impl Trait for Runtime {
     type Event = ();
+    type ContentIdExists = MockContent;
+}
+
+pub const EXISTING_CONTENT_ID: ContentId = H256([1; 32]);
+pub const EXISTING_CONTENT_DATA_OBJECT_TYPE_ID: DataObjectTypeId = 1;
+
+pub struct MockContent {}
+
+impl ContentIdExists<Runtime> for MockContent {
+    fn has_content(which: &ContentId) -> bool {
+        *which == EXISTING_CONTENT_ID
+    }
+
+    fn get_data_object_type_id(which: &ContentId) -> Result<DataObjectTypeId, &'static str> {
+        if Self::has_content(which) {
+            Ok(EXISTING_CONTENT_DATA_OBJECT_TYPE_ID)
+        } else {
+            Err("Content not found")
+        }
+    }
 }
 
 pub const UNKNOWN_CLASS_ID: ClassId = 111;
@@ -78,11 +98,29 @@ pub fn good_class_description() -> Vec<u8> {
 }
 
 impl Property {
-    fn required(&self) -> Property {
+    pub fn required(&self) -> Property {
         let mut new_self = self.clone();
         new_self.required = true;
         new_self
     }
+
+    pub fn unique(&self) -> Property {
+        let mut new_self = self.clone();
+        new_self.unique = true;
+        new_self
+    }
+
+    pub fn indexed(&self) -> Property {
+        let mut new_self = self.clone();
+        new_self.indexed = true;
+        new_self
+    }
+
+    pub fn constrained(&self, constraint: PropertyConstraint) -> Property {
+        let mut new_self = self.clone();
+        new_self.constraints.push(constraint);
+        new_self
+    }
 }
 
 pub fn good_prop_bool() -> Property {
@@ -91,6 +129,9 @@ pub fn good_prop_bool() -> Property {
         required: false,
         name: b"Name of a bool property".to_vec(),
         description: b"Description of a bool property".to_vec(),
+        unique: false,
+        indexed: false,
+        constraints: vec![],
     }
 }
 
@@ -100,6 +141,9 @@ pub fn good_prop_u32() -> Property {
         required: false,
         name: b"Name of a u32 property".to_vec(),
         description: b"Description of a u32 property".to_vec(),
+        unique: false,
+        indexed: false,
+        constraints: vec![],
     }
 }
 
@@ -109,6 +153,9 @@ pub fn good_prop_text() -> Property {
         required: false,
         name: b"Name of a text property".to_vec(),
         description: b"Description of a text property".to_vec(),
+        unique: false,
+        indexed: false,
+        constraints: vec![],
     }
 }
 
@@ -118,6 +165,9 @@ pub fn new_internal_class_prop(class_id: ClassId) -> Property {
         required: false,
         name: b"Name of a internal property".to_vec(),
         description: b"Description of a internal property".to_vec(),
+        unique: false,
+        indexed: false,
+        constraints: vec![],
     }
 }
 
@@ -201,6 +251,7 @@ pub fn assert_class_schemas(class_id: ClassId, expected_schema_prop_ids: Vec<Vec
         .iter()
         .map(|prop_ids| ClassSchema {
             properties: prop_ids.clone(),
+            is_active: true,
         })
         .collect();
     assert_eq!(class.schemas, schemas);
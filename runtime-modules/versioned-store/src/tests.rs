@@ -3,6 +3,7 @@
 use super::*;
 use crate::mock::*;
 
+use common::storage_migration::put_map_value;
 use srml_support::{assert_err, assert_ok};
 
 // Create class
@@ -374,6 +375,118 @@ fn should_add_schema_to_entity_when_some_optional_props_provided() {
     })
 }
 
+#[test]
+fn cannot_add_schema_to_entity_when_schema_is_deprecated() {
+    with_test_externalities(|| {
+        let (class_id, schema_id, entity_id) = create_class_with_schema_and_entity();
+        assert_ok!(TestModule::update_class_schema_status(
+            class_id, schema_id, false
+        ));
+        assert_err!(
+            TestModule::add_schema_support_to_entity(entity_id, schema_id, vec![bool_prop_value()]),
+            ERROR_CLASS_SCHEMA_NOT_ACTIVE
+        );
+
+        // Reactivated schema can be added again.
+        assert_ok!(TestModule::update_class_schema_status(
+            class_id, schema_id, true
+        ));
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            entity_id,
+            schema_id,
+            vec![bool_prop_value()]
+        ));
+    })
+}
+
+#[test]
+fn cannot_update_status_of_unknown_schema() {
+    with_test_externalities(|| {
+        let (class_id, schema_id, _) = create_class_with_schema_and_entity();
+        assert_err!(
+            TestModule::update_class_schema_status(class_id, schema_id + 1, false),
+            ERROR_UNKNOWN_CLASS_SCHEMA_ID
+        );
+    })
+}
+
+// Migrate entity schema
+// --------------------------------------
+
+const PROP_ID_TEXT: u16 = 3;
+
+fn create_entity_with_deprecated_schema() -> (ClassId, u16, EntityId) {
+    let entity_id = create_entity_with_schema_support();
+    let class_id = TestModule::entity_by_id(entity_id).class_id;
+    let new_schema_id = TestModule::add_class_schema(
+        class_id,
+        vec![PROP_ID_U32],
+        vec![good_prop_text().required()],
+    )
+    .unwrap();
+    assert_ok!(TestModule::update_class_schema_status(
+        class_id,
+        SCHEMA_ID_0,
+        false
+    ));
+    (class_id, new_schema_id, entity_id)
+}
+
+#[test]
+fn migrate_entity_schema_successfully() {
+    with_test_externalities(|| {
+        let (_, new_schema_id, entity_id) = create_entity_with_deprecated_schema();
+        let text_value = prop_value(PROP_ID_TEXT, PropertyValue::Text(b"default".to_vec()));
+
+        assert_ok!(TestModule::migrate_entity_schema(
+            entity_id,
+            SCHEMA_ID_0,
+            new_schema_id,
+            vec![text_value.clone()]
+        ));
+
+        let entity = TestModule::entity_by_id(entity_id);
+        assert_eq!(entity.in_class_schema_indexes, [new_schema_id]);
+        assert_eq!(
+            entity.values,
+            vec![
+                prop_value(PROP_ID_BOOL, PropertyValue::Bool(true)),
+                prop_value(PROP_ID_U32, PropertyValue::None),
+                prop_value(PROP_ID_INTERNAL, PropertyValue::None),
+                text_value,
+            ]
+        );
+    })
+}
+
+#[test]
+fn cannot_migrate_entity_schema_when_missing_required_prop() {
+    with_test_externalities(|| {
+        let (_, new_schema_id, entity_id) = create_entity_with_deprecated_schema();
+        assert_err!(
+            TestModule::migrate_entity_schema(entity_id, SCHEMA_ID_0, new_schema_id, vec![]),
+            ERROR_MISSING_REQUIRED_PROP
+        );
+        let entity = TestModule::entity_by_id(entity_id);
+        assert_eq!(entity.in_class_schema_indexes, [SCHEMA_ID_0]);
+    })
+}
+
+#[test]
+fn cannot_migrate_entity_from_schema_it_does_not_support() {
+    with_test_externalities(|| {
+        let (_, new_schema_id, entity_id) = create_entity_with_deprecated_schema();
+        assert_err!(
+            TestModule::migrate_entity_schema(entity_id, new_schema_id, SCHEMA_ID_0, vec![]),
+            ERROR_SCHEMA_NOT_ADDED_TO_ENTITY
+        );
+        assert_err!(
+            TestModule::migrate_entity_schema(entity_id, SCHEMA_ID_0, SCHEMA_ID_0, vec![]),
+            ERROR_MIGRATION_TO_SAME_SCHEMA
+        );
+    })
+}
+
 // Update entity properties
 // --------------------------------------
 
@@ -467,37 +580,706 @@ fn update_entity_props_successfully() {
 
 // TODO test vec max len
 
-// Delete entity
+// Unique and indexed properties
 // --------------------------------------
 
-// #[test]
-// fn delete_entity_successfully() {
-//     with_test_externalities(|| {
-//         let entity_id = create_entity();
-//         assert_ok!(
-//             TestModule::delete_entity(entity_id),
-//             ()
-//         );
-//     })
-// }
-
-// #[test]
-// fn cannot_delete_entity_when_entity_not_found() {
-//     with_test_externalities(|| {
-//         assert_entity_not_found(
-//             TestModule::delete_entity(UNKNOWN_ENTITY_ID)
-//         );
-//     })
-// }
-
-// #[test]
-// fn cannot_delete_already_deleted_entity() {
-//     with_test_externalities(|| {
-//         let entity_id = create_entity();
-//         let _ok = TestModule::delete_entity(entity_id);
-//         assert_err!(
-//             TestModule::delete_entity(entity_id),
-//             ERROR_ENTITY_ALREADY_DELETED
-//         );
-//     })
-// }
+const PROP_ID_UNIQUE_TEXT: u16 = 0;
+const PROP_ID_INDEXED_U32: u16 = 1;
+
+fn create_class_with_unique_and_indexed_props() -> (ClassId, u16) {
+    let class_id = create_class();
+    let schema_id = TestModule::add_class_schema(
+        class_id,
+        vec![],
+        vec![good_prop_text().unique(), good_prop_u32().indexed()],
+    )
+    .unwrap();
+    (class_id, schema_id)
+}
+
+fn create_entity_with_unique_and_indexed_values(
+    class_id: ClassId,
+    schema_id: u16,
+    text: &[u8],
+    number: u32,
+) -> dispatch::Result {
+    let entity_id = create_entity_of_class(class_id);
+    TestModule::add_schema_support_to_entity(
+        entity_id,
+        schema_id,
+        vec![
+            prop_value(PROP_ID_UNIQUE_TEXT, PropertyValue::Text(text.to_vec())),
+            prop_value(PROP_ID_INDEXED_U32, PropertyValue::Uint32(number)),
+        ],
+    )
+}
+
+#[test]
+fn cannot_add_schema_to_entity_when_unique_prop_value_is_used() {
+    with_test_externalities(|| {
+        let (class_id, schema_id) = create_class_with_unique_and_indexed_props();
+        assert_ok!(create_entity_with_unique_and_indexed_values(
+            class_id, schema_id, b"handle", 1
+        ));
+        assert_err!(
+            create_entity_with_unique_and_indexed_values(class_id, schema_id, b"handle", 2),
+            ERROR_PROP_VALUE_NOT_UNIQUE_IN_CLASS
+        );
+
+        // Values of indexed properties may be shared
+        assert_ok!(create_entity_with_unique_and_indexed_values(
+            class_id, schema_id, b"other", 1
+        ));
+    })
+}
+
+#[test]
+fn cannot_update_entity_props_when_unique_prop_value_is_used() {
+    with_test_externalities(|| {
+        let (class_id, schema_id) = create_class_with_unique_and_indexed_props();
+        let entity_id = TestModule::next_entity_id();
+        assert_ok!(create_entity_with_unique_and_indexed_values(
+            class_id, schema_id, b"handle", 1
+        ));
+        let other_entity_id = TestModule::next_entity_id();
+        assert_ok!(create_entity_with_unique_and_indexed_values(
+            class_id, schema_id, b"other", 1
+        ));
+
+        assert_err!(
+            TestModule::update_entity_property_values(
+                other_entity_id,
+                vec![prop_value(
+                    PROP_ID_UNIQUE_TEXT,
+                    PropertyValue::Text(b"handle".to_vec())
+                )]
+            ),
+            ERROR_PROP_VALUE_NOT_UNIQUE_IN_CLASS
+        );
+
+        // An entity can keep its own value
+        assert_ok!(TestModule::update_entity_property_values(
+            entity_id,
+            vec![prop_value(
+                PROP_ID_UNIQUE_TEXT,
+                PropertyValue::Text(b"handle".to_vec())
+            )]
+        ));
+
+        // A released value can be taken by another entity
+        assert_ok!(TestModule::update_entity_property_values(
+            entity_id,
+            vec![prop_value(
+                PROP_ID_UNIQUE_TEXT,
+                PropertyValue::Text(b"renamed".to_vec())
+            )]
+        ));
+        assert_ok!(TestModule::update_entity_property_values(
+            other_entity_id,
+            vec![prop_value(
+                PROP_ID_UNIQUE_TEXT,
+                PropertyValue::Text(b"handle".to_vec())
+            )]
+        ));
+    })
+}
+
+#[test]
+fn lookup_entities_by_indexed_prop_value() {
+    with_test_externalities(|| {
+        let (class_id, schema_id) = create_class_with_unique_and_indexed_props();
+        let entity_id = TestModule::next_entity_id();
+        assert_ok!(create_entity_with_unique_and_indexed_values(
+            class_id, schema_id, b"handle", 1
+        ));
+        let other_entity_id = TestModule::next_entity_id();
+        assert_ok!(create_entity_with_unique_and_indexed_values(
+            class_id, schema_id, b"other", 1
+        ));
+
+        assert_eq!(
+            TestModule::entity_ids_by_property_value(
+                class_id,
+                PROP_ID_INDEXED_U32,
+                &PropertyValue::Uint32(1)
+            ),
+            vec![entity_id, other_entity_id]
+        );
+        assert_eq!(
+            TestModule::entity_ids_by_property_value(
+                class_id,
+                PROP_ID_UNIQUE_TEXT,
+                &PropertyValue::Text(b"other".to_vec())
+            ),
+            vec![other_entity_id]
+        );
+
+        // Updated and removed entities leave the index
+        assert_ok!(TestModule::update_entity_property_values(
+            entity_id,
+            vec![prop_value(PROP_ID_INDEXED_U32, PropertyValue::Uint32(2))]
+        ));
+        assert_ok!(TestModule::remove_entity(other_entity_id));
+        assert!(TestModule::entity_ids_by_property_value(
+            class_id,
+            PROP_ID_INDEXED_U32,
+            &PropertyValue::Uint32(1)
+        )
+        .is_empty());
+        assert_eq!(
+            TestModule::entity_ids_by_property_value(
+                class_id,
+                PROP_ID_INDEXED_U32,
+                &PropertyValue::Uint32(2)
+            ),
+            vec![entity_id]
+        );
+    })
+}
+
+// External properties
+// --------------------------------------
+
+const PROP_ID_EXTERNAL: u16 = 0;
+const PROP_ID_EXTERNAL_VEC: u16 = 1;
+
+fn create_entity_with_external_props(data_object_type_id: Option<DataObjectTypeId>) -> EntityId {
+    let external_prop = ExternalProperty {
+        data_object_type_id,
+    };
+    let class_id = create_class();
+    let schema_id = TestModule::add_class_schema(
+        class_id,
+        vec![],
+        vec![
+            Property {
+                prop_type: PropertyType::External(external_prop.clone()),
+                required: false,
+                name: b"Name of an external property".to_vec(),
+                description: b"Description of an external property".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
+            },
+            Property {
+                prop_type: PropertyType::ExternalVec(2, external_prop),
+                required: false,
+                name: b"Name of an external vec property".to_vec(),
+                description: b"Description of an external vec property".to_vec(),
+                unique: false,
+                indexed: false,
+                constraints: vec![],
+            },
+        ],
+    )
+    .unwrap();
+    let entity_id = create_entity_of_class(class_id);
+    assert_ok!(TestModule::add_schema_support_to_entity(
+        entity_id,
+        schema_id,
+        vec![]
+    ));
+    entity_id
+}
+
+#[test]
+fn update_entity_props_with_external_content() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_with_external_props(None);
+        assert_ok!(TestModule::update_entity_property_values(
+            entity_id,
+            vec![
+                prop_value(
+                    PROP_ID_EXTERNAL,
+                    PropertyValue::External(EXISTING_CONTENT_ID)
+                ),
+                prop_value(
+                    PROP_ID_EXTERNAL_VEC,
+                    PropertyValue::ExternalVec(vec![EXISTING_CONTENT_ID, EXISTING_CONTENT_ID])
+                ),
+            ]
+        ));
+
+        assert_err!(
+            TestModule::update_entity_property_values(
+                entity_id,
+                vec![prop_value(
+                    PROP_ID_EXTERNAL_VEC,
+                    PropertyValue::ExternalVec(vec![EXISTING_CONTENT_ID; 3])
+                )]
+            ),
+            ERROR_VEC_PROP_IS_TOO_LONG
+        );
+    })
+}
+
+#[test]
+fn cannot_update_entity_props_when_external_content_not_found() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_with_external_props(None);
+        assert_err!(
+            TestModule::update_entity_property_values(
+                entity_id,
+                vec![prop_value(
+                    PROP_ID_EXTERNAL,
+                    PropertyValue::External(H256([2; 32]))
+                )]
+            ),
+            ERROR_EXTERNAL_CONTENT_NOT_FOUND
+        );
+        assert_err!(
+            TestModule::update_entity_property_values(
+                entity_id,
+                vec![prop_value(
+                    PROP_ID_EXTERNAL_VEC,
+                    PropertyValue::ExternalVec(vec![EXISTING_CONTENT_ID, H256([2; 32])])
+                )]
+            ),
+            ERROR_EXTERNAL_CONTENT_NOT_FOUND
+        );
+    })
+}
+
+#[test]
+fn cannot_update_entity_props_when_external_content_has_other_type() {
+    with_test_externalities(|| {
+        let entity_id =
+            create_entity_with_external_props(Some(EXISTING_CONTENT_DATA_OBJECT_TYPE_ID + 1));
+        assert_err!(
+            TestModule::update_entity_property_values(
+                entity_id,
+                vec![prop_value(
+                    PROP_ID_EXTERNAL,
+                    PropertyValue::External(EXISTING_CONTENT_ID)
+                )]
+            ),
+            ERROR_EXTERNAL_CONTENT_NOT_FOUND
+        );
+    })
+}
+
+// Property constraints
+// --------------------------------------
+
+fn good_prop_u32_vec() -> Property {
+    Property {
+        prop_type: PropertyType::Uint32Vec(5),
+        required: false,
+        name: b"Name of a u32 vec property".to_vec(),
+        description: b"Description of a u32 vec property".to_vec(),
+        unique: false,
+        indexed: false,
+        constraints: vec![],
+    }
+}
+
+fn create_entity_with_constrained_prop(prop: Property) -> EntityId {
+    let class_id = create_class();
+    let schema_id = TestModule::add_class_schema(class_id, vec![], vec![prop]).unwrap();
+    let entity_id = create_entity_of_class(class_id);
+    assert_ok!(TestModule::add_schema_support_to_entity(
+        entity_id,
+        schema_id,
+        vec![]
+    ));
+    entity_id
+}
+
+fn update_constrained_prop(entity_id: EntityId, value: PropertyValue) -> dispatch::Result {
+    TestModule::update_entity_property_values(entity_id, vec![prop_value(0, value)])
+}
+
+#[test]
+fn cannot_add_class_schema_when_constraint_does_not_match_prop_type() {
+    with_test_externalities(|| {
+        let class_id = create_class();
+        let mismatched_props = vec![
+            good_prop_text().constrained(PropertyConstraint::Range(0, 10)),
+            good_prop_u32().constrained(PropertyConstraint::TextPattern(vec![CharClass::Digit])),
+            good_prop_bool().constrained(PropertyConstraint::NonEmptyVec),
+            good_prop_u32().constrained(PropertyConstraint::OneOf(vec![PropertyValue::Bool(true)])),
+        ];
+        for prop in mismatched_props.into_iter() {
+            assert_err!(
+                TestModule::add_class_schema(class_id, vec![], vec![prop]),
+                ERROR_CONSTRAINT_DOES_NOT_MATCH_PROP_TYPE
+            );
+        }
+
+        assert_err!(
+            TestModule::add_class_schema(
+                class_id,
+                vec![],
+                vec![good_prop_u32().constrained(PropertyConstraint::Range(10, 0))]
+            ),
+            ERROR_INVALID_RANGE_CONSTRAINT
+        );
+    })
+}
+
+#[test]
+fn update_entity_props_with_range_constraint() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_with_constrained_prop(
+            good_prop_u32().constrained(PropertyConstraint::Range(10, 20)),
+        );
+        assert_ok!(update_constrained_prop(
+            entity_id,
+            PropertyValue::Uint32(10)
+        ));
+        assert_ok!(update_constrained_prop(
+            entity_id,
+            PropertyValue::Uint32(20)
+        ));
+        assert_err!(
+            update_constrained_prop(entity_id, PropertyValue::Uint32(9)),
+            ERROR_PROP_VALUE_OUT_OF_RANGE
+        );
+        assert_err!(
+            update_constrained_prop(entity_id, PropertyValue::Uint32(21)),
+            ERROR_PROP_VALUE_OUT_OF_RANGE
+        );
+
+        // A non required property can still be set to None.
+        assert_ok!(update_constrained_prop(entity_id, PropertyValue::None));
+    })
+}
+
+#[test]
+fn update_entity_props_with_range_constraint_beyond_i64() {
+    with_test_externalities(|| {
+        let min = i128::from(i64::max_value()) + 1;
+        let max = i128::from(u64::max_value());
+        let entity_id = create_entity_with_constrained_prop(
+            Property {
+                prop_type: PropertyType::Uint64,
+                ..good_prop_u32()
+            }
+            .constrained(PropertyConstraint::Range(min, max)),
+        );
+        assert_ok!(update_constrained_prop(
+            entity_id,
+            PropertyValue::Uint64(u64::max_value())
+        ));
+        assert_err!(
+            update_constrained_prop(entity_id, PropertyValue::Uint64(i64::max_value() as u64)),
+            ERROR_PROP_VALUE_OUT_OF_RANGE
+        );
+    })
+}
+
+#[test]
+fn cannot_add_schema_to_entity_when_vec_item_is_out_of_range() {
+    with_test_externalities(|| {
+        let class_id = create_class();
+        let schema_id = TestModule::add_class_schema(
+            class_id,
+            vec![],
+            vec![good_prop_u32_vec().constrained(PropertyConstraint::Range(0, 100))],
+        )
+        .unwrap();
+        let entity_id = create_entity_of_class(class_id);
+        assert_err!(
+            TestModule::add_schema_support_to_entity(
+                entity_id,
+                schema_id,
+                vec![prop_value(0, PropertyValue::Uint32Vec(vec![1, 101]))]
+            ),
+            ERROR_PROP_VALUE_OUT_OF_RANGE
+        );
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            entity_id,
+            schema_id,
+            vec![prop_value(0, PropertyValue::Uint32Vec(vec![1, 100]))]
+        ));
+    })
+}
+
+#[test]
+fn update_entity_props_with_one_of_constraint() {
+    with_test_externalities(|| {
+        let allowed_values = vec![
+            PropertyValue::Text(b"draft".to_vec()),
+            PropertyValue::Text(b"published".to_vec()),
+        ];
+        let entity_id = create_entity_with_constrained_prop(
+            good_prop_text().constrained(PropertyConstraint::OneOf(allowed_values)),
+        );
+        assert_ok!(update_constrained_prop(
+            entity_id,
+            PropertyValue::Text(b"published".to_vec())
+        ));
+        assert_err!(
+            update_constrained_prop(entity_id, PropertyValue::Text(b"archived".to_vec())),
+            ERROR_PROP_VALUE_NOT_ALLOWED
+        );
+    })
+}
+
+#[test]
+fn update_entity_props_with_text_pattern_constraint() {
+    with_test_externalities(|| {
+        let slug_pattern = PropertyConstraint::TextPattern(vec![
+            CharClass::Lowercase,
+            CharClass::Digit,
+            CharClass::Chars(b"-".to_vec()),
+        ]);
+        let entity_id =
+            create_entity_with_constrained_prop(good_prop_text().constrained(slug_pattern));
+        assert_ok!(update_constrained_prop(
+            entity_id,
+            PropertyValue::Text(b"my-slug-1".to_vec())
+        ));
+        assert_err!(
+            update_constrained_prop(entity_id, PropertyValue::Text(b"My slug".to_vec())),
+            ERROR_TEXT_PROP_DOES_NOT_MATCH_PATTERN
+        );
+    })
+}
+
+#[test]
+fn update_entity_props_with_non_empty_vec_constraint() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_with_constrained_prop(
+            good_prop_u32_vec().constrained(PropertyConstraint::NonEmptyVec),
+        );
+        assert_ok!(update_constrained_prop(
+            entity_id,
+            PropertyValue::Uint32Vec(vec![1])
+        ));
+        assert_err!(
+            update_constrained_prop(entity_id, PropertyValue::Uint32Vec(vec![])),
+            ERROR_VEC_PROP_IS_EMPTY
+        );
+    })
+}
+
+// Entity versions and assertions
+// --------------------------------------
+
+#[test]
+fn entity_version_is_incremented_on_every_change() {
+    with_test_externalities(|| {
+        let (_, schema_id, entity_id) = create_class_with_schema_and_entity();
+        assert_eq!(TestModule::entity_version_by_id(entity_id), 0);
+
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            entity_id,
+            schema_id,
+            vec![bool_prop_value()]
+        ));
+        assert_eq!(TestModule::entity_version_by_id(entity_id), 1);
+
+        assert_ok!(TestModule::update_entity_property_values(
+            entity_id,
+            vec![prop_value(PROP_ID_U32, PropertyValue::Uint32(123))]
+        ));
+        assert_eq!(TestModule::entity_version_by_id(entity_id), 2);
+
+        // Failed updates don't change the version
+        assert_err!(
+            TestModule::update_entity_property_values(
+                entity_id,
+                vec![prop_value(UNKNOWN_PROP_ID, PropertyValue::None)]
+            ),
+            ERROR_UNKNOWN_ENTITY_PROP_ID
+        );
+        assert_ok!(TestModule::ensure_entity_version(entity_id, 2));
+        assert_err!(
+            TestModule::ensure_entity_version(entity_id, 1),
+            ERROR_ENTITY_VERSION_CHANGED
+        );
+
+        assert_ok!(TestModule::remove_entity(entity_id));
+        assert_eq!(TestModule::entity_version_by_id(entity_id), 0);
+    })
+}
+
+#[test]
+fn ensure_entity_property_value_matches_current_value() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_with_schema_support();
+        assert_ok!(TestModule::ensure_entity_property_value(
+            entity_id,
+            PROP_ID_BOOL,
+            &PropertyValue::Bool(true)
+        ));
+        assert_err!(
+            TestModule::ensure_entity_property_value(
+                entity_id,
+                PROP_ID_BOOL,
+                &PropertyValue::Bool(false)
+            ),
+            ERROR_PROP_VALUE_CHANGED
+        );
+        assert_err!(
+            TestModule::ensure_entity_property_value(
+                entity_id,
+                UNKNOWN_PROP_ID,
+                &PropertyValue::None
+            ),
+            ERROR_UNKNOWN_ENTITY_PROP_ID
+        );
+        assert_entity_not_found(TestModule::ensure_entity_property_value(
+            UNKNOWN_ENTITY_ID,
+            PROP_ID_BOOL,
+            &PropertyValue::Bool(true),
+        ));
+    })
+}
+
+// Remove entity
+// --------------------------------------
+
+#[test]
+fn remove_entity_successfully() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_with_schema_support();
+        assert_ok!(TestModule::remove_entity(entity_id));
+        assert!(!EntityById::exists(entity_id));
+    })
+}
+
+#[test]
+fn cannot_remove_entity_when_entity_not_found() {
+    with_test_externalities(|| {
+        assert_entity_not_found(TestModule::remove_entity(UNKNOWN_ENTITY_ID));
+    })
+}
+
+#[test]
+fn cannot_remove_referenced_entity() {
+    with_test_externalities(|| {
+        let (class_id, schema_id, entity_id) = create_class_with_schema_and_entity();
+        let referencing_entity_id = create_entity_of_class(class_id);
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            referencing_entity_id,
+            schema_id,
+            vec![
+                prop_value(PROP_ID_BOOL, PropertyValue::Bool(true)),
+                prop_value(PROP_ID_INTERNAL, PropertyValue::Internal(entity_id)),
+            ]
+        ));
+        assert_eq!(TestModule::reference_count_by_entity_id(entity_id), 1);
+        assert_err!(
+            TestModule::remove_entity(entity_id),
+            ERROR_ENTITY_IS_REFERENCED
+        );
+
+        // Dropping the reference allows the removal
+        assert_ok!(TestModule::update_entity_property_values(
+            referencing_entity_id,
+            vec![prop_value(PROP_ID_INTERNAL, PropertyValue::None)]
+        ));
+        assert_eq!(TestModule::reference_count_by_entity_id(entity_id), 0);
+        assert_ok!(TestModule::remove_entity(entity_id));
+    })
+}
+
+#[test]
+fn remove_entity_referencing_itself() {
+    with_test_externalities(|| {
+        let (class_id, schema_id, entity_id) = create_class_with_schema_and_entity();
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            entity_id,
+            schema_id,
+            vec![
+                prop_value(PROP_ID_BOOL, PropertyValue::Bool(true)),
+                prop_value(PROP_ID_INTERNAL, PropertyValue::Internal(entity_id)),
+            ]
+        ));
+        let referencing_entity_id = create_entity_of_class(class_id);
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            referencing_entity_id,
+            schema_id,
+            vec![
+                prop_value(PROP_ID_BOOL, PropertyValue::Bool(true)),
+                prop_value(PROP_ID_INTERNAL, PropertyValue::Internal(entity_id)),
+            ]
+        ));
+        assert_eq!(TestModule::reference_count_by_entity_id(entity_id), 2);
+        assert_err!(
+            TestModule::remove_entity(entity_id),
+            ERROR_ENTITY_IS_REFERENCED
+        );
+
+        // The reference of the entity to itself doesn't prevent the removal
+        assert_ok!(TestModule::remove_entity(referencing_entity_id));
+        assert_eq!(TestModule::reference_count_by_entity_id(entity_id), 1);
+        assert_ok!(TestModule::remove_entity(entity_id));
+        assert_eq!(TestModule::reference_count_by_entity_id(entity_id), 0);
+    })
+}
+
+#[test]
+fn recounting_references_does_not_inflate_counts() {
+    with_test_externalities(|| {
+        let (class_id, schema_id, entity_id) = create_class_with_schema_and_entity();
+        let referencing_entity_id = create_entity_of_class(class_id);
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            referencing_entity_id,
+            schema_id,
+            vec![
+                prop_value(PROP_ID_BOOL, PropertyValue::Bool(true)),
+                prop_value(PROP_ID_INTERNAL, PropertyValue::Internal(entity_id)),
+            ]
+        ));
+        ReferenceCountByEntityId::insert(referencing_entity_id, 3);
+
+        TestModule::initialize_reference_counts();
+        TestModule::initialize_reference_counts();
+
+        assert_eq!(TestModule::reference_count_by_entity_id(entity_id), 1);
+        assert!(!ReferenceCountByEntityId::exists(referencing_entity_id));
+    })
+}
+
+// Migration
+// --------------------------------------
+
+#[test]
+fn migrate_classes_stored_in_old_layout() {
+    with_test_externalities(|| {
+        let class_id = create_class();
+        let old_property = OldProperty {
+            prop_type: PropertyType::Internal(class_id),
+            required: true,
+            name: good_prop_bool().name,
+            description: good_prop_bool().description,
+        };
+        let old_class = OldClass {
+            id: class_id,
+            properties: vec![old_property.clone()],
+            schemas: vec![OldClassSchema {
+                properties: vec![0],
+            }],
+            name: good_class_name(),
+            description: good_class_description(),
+        };
+        put_map_value::<ClassById, _, _, _>(&class_id, &old_class);
+
+        TestModule::migrate_classes();
+
+        assert_eq!(
+            TestModule::class_by_id(class_id),
+            Class {
+                id: class_id,
+                properties: vec![Property {
+                    prop_type: old_property.prop_type,
+                    required: true,
+                    name: old_property.name,
+                    description: old_property.description,
+                    unique: false,
+                    indexed: false,
+                    constraints: vec![],
+                }],
+                schemas: vec![ClassSchema {
+                    properties: vec![0],
+                    is_active: true,
+                }],
+                name: good_class_name(),
+                description: good_class_description(),
+            }
+        );
+    })
+}
@@ -42,6 +42,20 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                 roles::actors::Call::set_role_parameters(Role::StorageProvider, role_parameters),
             )
             .encode(),
+            ProposalDetails::SetStorageProviderRewardRate(reward_rate_per_byte) => {
+                Call::DataObjectStorageRegistry(
+                    storage::data_object_storage_registry::Call::set_reward_rate_per_byte(
+                        reward_rate_per_byte,
+                    ),
+                )
+                .encode()
+            }
+            ProposalDetails::UpholdSlashAppeal(stake_id, slash_id) => {
+                Call::Stake(stake::Call::uphold_appealed_slash(stake_id, slash_id)).encode()
+            }
+            ProposalDetails::CancelSlashAppeal(stake_id, slash_id) => {
+                Call::Stake(stake::Call::cancel_appealed_slash(stake_id, slash_id)).encode()
+            }
             ProposalDetails::RuntimeUpgrade(wasm_code) => Call::ProposalsCodex(
                 proposals_codex::Call::execute_runtime_upgrade_proposal(wasm_code),
             )
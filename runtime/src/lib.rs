@@ -53,8 +53,8 @@ pub use sr_primitives::BuildStorage;
 pub use sr_primitives::{Perbill, Permill};
 
 pub use srml_support::{
-    construct_runtime, parameter_types, traits::Currency, traits::Imbalance, traits::Randomness,
-    StorageLinkedMap, StorageMap, StorageValue,
+    construct_runtime, parameter_types, traits::Currency, traits::Imbalance, traits::OnUnbalanced,
+    traits::Randomness, StorageLinkedMap, StorageMap, StorageValue,
 };
 pub use staking::StakerStatus;
 pub use timestamp::Call as TimestampCall;
@@ -142,7 +142,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 6,
-    spec_version: 15,
+    spec_version: 17,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
 };
@@ -428,6 +428,7 @@ use governance::{council, election};
 use membership::members;
 use storage::{data_directory, data_object_storage_registry, data_object_type_registry};
 pub use versioned_store;
+pub use versioned_store_permissions;
 
 pub use content_working_group as content_wg;
 mod migration;
@@ -439,9 +440,26 @@ pub type ContentId = primitives::H256;
 
 impl versioned_store::Trait for Runtime {
     type Event = Event;
+    type ContentIdExists = DataDirectoryContent;
+}
+
+// External property values reference content in the data directory
+pub struct DataDirectoryContent {}
+impl versioned_store::ContentIdExists<Runtime> for DataDirectoryContent {
+    fn has_content(which: &ContentId) -> bool {
+        <DataDirectory as storage::traits::ContentIdExists<Runtime>>::has_content(which)
+    }
+
+    fn get_data_object_type_id(
+        which: &ContentId,
+    ) -> Result<versioned_store::DataObjectTypeId, &'static str> {
+        <DataDirectory as storage::traits::ContentIdExists<Runtime>>::get_data_object(which)
+            .map(|data_object| data_object.type_id)
+    }
 }
 
 impl versioned_store_permissions::Trait for Runtime {
+    type Event = Event;
     type Credential = Credential;
     type CredentialChecker = (ContentWorkingGroupCredentials, SudoKeyHasAllCredentials);
     type CreateClassPermissionsChecker = ContentLeadOrSudoKeyCanCreateClasses;
@@ -595,15 +613,26 @@ impl recurringrewards::Trait for Runtime {
 
 parameter_types! {
     pub const StakePoolId: [u8; 8] = *b"joystake";
+    pub const SlashReporterRewardPercentPts: u16 = 10;
 }
 
 impl stake::Trait for Runtime {
+    type Event = Event;
     type Currency = <Self as common::currency::GovernanceCurrency>::Currency;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = (
-        ContentWorkingGroupStakingEventHandler,
-        crate::integration::proposals::StakingEventsHandler<Self>,
+        stake::ContributionsStakingEventsHandler<Self>,
+        (
+            ContentWorkingGroupStakingEventHandler,
+            crate::integration::proposals::StakingEventsHandler<Self>,
+        ),
     );
+    type SlashAppellants = ContentWorkingGroupSlashAppellants;
+    // The council reviews appeals through the 'uphold slash appeal' and 'cancel slash appeal'
+    // proposals, which are executed with the root origin.
+    type SlashAppealAuthority = system::EnsureRoot<AccountId>;
+    type SlashDestination =
+        stake::RewardReporter<Self, SlashReporterRewardPercentPts, CouncilMintSlashDestination>;
     type StakeId = u64;
     type SlashId = u64;
 }
@@ -675,6 +704,63 @@ impl stake::StakingEventsHandler<Runtime> for ContentWorkingGroupStakingEventHan
     }
 }
 
+/// Allows the member who applied for a curator role to appeal slashes of their role stake
+/// using the member controller account.
+pub struct ContentWorkingGroupSlashAppellants {}
+impl stake::SlashAppellants<Runtime> for ContentWorkingGroupSlashAppellants {
+    fn can_appeal(
+        stake_id: &<Runtime as stake::Trait>::StakeId,
+        account_id: &<Runtime as system::Trait>::AccountId,
+    ) -> bool {
+        if !hiring::ApplicationIdByStakingId::<Runtime>::exists(stake_id) {
+            // Stake not related to a staked role managed by the hiring module
+            return false;
+        }
+
+        let application_id = hiring::ApplicationIdByStakingId::<Runtime>::get(stake_id);
+
+        if !content_wg::CuratorApplicationById::<Runtime>::exists(application_id) {
+            // Stake not for a Curator
+            return false;
+        }
+
+        let curator_application =
+            content_wg::CuratorApplicationById::<Runtime>::get(application_id);
+
+        membership::members::MemberProfile::<Runtime>::get(curator_application.member_id)
+            .map_or(false, |profile| profile.controller_account == *account_id)
+    }
+}
+
+/// Returns slashed funds to the council by adding them to the capacity of the council mint.
+/// The slashed funds themselves are burned, the mint issues them again when the council spends them.
+/// If there is no council mint the slashed funds are just burned.
+/// Also receives the role stake slashed from storage providers evicted for missed challenges.
+pub struct CouncilMintSlashDestination {}
+impl stake::SlashDestination<Runtime> for CouncilMintSlashDestination {
+    fn receive_slashed_funds(
+        _record: &stake::SlashRecordOf<Runtime>,
+        imbalance: stake::NegativeImbalance<Runtime>,
+    ) {
+        <Self as OnUnbalanced<actors::NegativeImbalance<Runtime>>>::on_unbalanced(imbalance);
+    }
+}
+
+impl OnUnbalanced<actors::NegativeImbalance<Runtime>> for CouncilMintSlashDestination {
+    fn on_unbalanced(imbalance: actors::NegativeImbalance<Runtime>) {
+        if let Some(mint_id) = governance::council::Module::<Runtime>::council_mint() {
+            if let Ok(capacity) = minting::Module::<Runtime>::get_mint_capacity(mint_id) {
+                let _ = minting::Module::<Runtime>::set_mint_capacity(
+                    mint_id,
+                    capacity + imbalance.peek(),
+                );
+            }
+        }
+
+        drop(imbalance);
+    }
+}
+
 impl content_wg::Trait for Runtime {
     type Event = Event;
 }
@@ -708,6 +794,36 @@ impl storage::data_directory::Trait for Runtime {
     type SchemaId = u64;
     type Roles = LookupRoles;
     type IsActiveDataObjectType = DataObjectTypeRegistry;
+    type DataObjectTypeConstraints = DataObjectTypeRegistry;
+    type ContentCurators = ContentWorkingGroupCurators;
+    type OnContentRemoved = DataObjectStorageRegistry;
+    type ChannelId = content_wg::ChannelId<Runtime>;
+    type ChannelOwners = ContentWorkingGroupChannelOwners;
+}
+
+// Channels are owned through their role account
+pub struct ContentWorkingGroupChannelOwners {}
+impl storage::traits::IsChannelOwner<Runtime> for ContentWorkingGroupChannelOwners {
+    fn is_channel_owner(channel_id: &content_wg::ChannelId<Runtime>, account: &AccountId) -> bool {
+        <content_wg::ChannelById<Runtime>>::exists(channel_id)
+            && <content_wg::ChannelById<Runtime>>::get(channel_id).role_account == *account
+    }
+}
+
+// Active curators and the curator lead may force the removal of rejected content
+pub struct ContentWorkingGroupCurators {}
+impl storage::traits::IsContentCurator<Runtime> for ContentWorkingGroupCurators {
+    fn is_content_curator(account: &AccountId) -> bool {
+        use versioned_store_permissions::CredentialChecker;
+
+        ContentWorkingGroupCredentials::account_has_credential(
+            account,
+            AnyActiveCuratorCredential::get(),
+        ) || ContentWorkingGroupCredentials::account_has_credential(
+            account,
+            CurrentLeadCredential::get(),
+        )
+    }
 }
 
 impl storage::data_object_storage_registry::Trait for Runtime {
@@ -715,6 +831,8 @@ impl storage::data_object_storage_registry::Trait for Runtime {
     type DataObjectStorageRelationshipId = u64;
     type Roles = LookupRoles;
     type ContentIdExists = DataDirectory;
+    type Randomness = RandomnessCollectiveFlip;
+    type SlashDestination = CouncilMintSlashDestination;
 }
 
 fn random_index(upper_bound: usize) -> usize {
@@ -742,12 +860,7 @@ impl roles::traits::Roles<Runtime> for LookupRoles {
     fn random_account_for_role(
         role: actors::Role,
     ) -> Result<<Runtime as system::Trait>::AccountId, &'static str> {
-        let ids = <actors::AccountIdsByRole<Runtime>>::get(role);
-
-        let live_ids: Vec<<Runtime as system::Trait>::AccountId> = ids
-            .into_iter()
-            .filter(|id| !<discovery::Module<Runtime>>::is_account_info_expired(id))
-            .collect();
+        let live_ids = Self::accounts_for_role(role);
 
         if live_ids.is_empty() {
             Err("no staked account found")
@@ -756,6 +869,14 @@ impl roles::traits::Roles<Runtime> for LookupRoles {
             Ok(live_ids[index].clone())
         }
     }
+
+    fn accounts_for_role(role: actors::Role) -> Vec<<Runtime as system::Trait>::AccountId> {
+        let ids = <actors::AccountIdsByRole<Runtime>>::get(role);
+
+        ids.into_iter()
+            .filter(|id| !<discovery::Module<Runtime>>::is_account_info_expired(id))
+            .collect()
+    }
 }
 
 impl members::Trait for Runtime {
@@ -919,8 +1040,8 @@ construct_runtime!(
         DataObjectStorageRegistry: data_object_storage_registry::{Module, Call, Storage, Event<T>, Config<T>},
         Discovery: discovery::{Module, Call, Storage, Event<T>},
         VersionedStore: versioned_store::{Module, Call, Storage, Event<T>, Config},
-        VersionedStorePermissions: versioned_store_permissions::{Module, Call, Storage},
-        Stake: stake::{Module, Call, Storage},
+        VersionedStorePermissions: versioned_store_permissions::{Module, Call, Storage, Event<T>, Config<T>},
+        Stake: stake::{Module, Call, Storage, Event<T>},
         Minting: minting::{Module, Call, Storage},
         RecurringRewards: recurringrewards::{Module, Call, Storage},
         Hiring: hiring::{Module, Call, Storage},
@@ -1074,6 +1195,22 @@ impl_runtime_apis! {
         }
     }
 
+    impl versioned_store::VersionedStoreApi<Block> for Runtime {
+        fn entity_ids_by_property_value(
+            class_id: versioned_store::ClassId,
+            in_class_index: u16,
+            value: versioned_store::PropertyValue,
+        ) -> Vec<versioned_store::EntityId> {
+            VersionedStore::entity_ids_by_property_value(class_id, in_class_index, &value)
+        }
+    }
+
+    impl data_directory::StorageQuotaApi<Block, u64> for Runtime {
+        fn remaining_quota(member_id: u64) -> (u64, u64) {
+            DataDirectory::remaining_quota(member_id)
+        }
+    }
+
     impl substrate_session::SessionKeys<Block> for Runtime {
         fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
             let seed = seed.as_ref().map(|s| rstd::str::from_utf8(&s).expect("Seed is an utf8 string"));
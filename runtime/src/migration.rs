@@ -6,22 +6,75 @@ use rstd::prelude::*;
 // use sr_primitives::{print, traits::Zero};
 use srml_support::{debug, decl_event, decl_module, decl_storage};
 
+/// Spec version of the runtime release which changed the layouts of the stored values migrated
+/// in `runtime_upgraded`.
+pub(crate) const STORAGE_LAYOUTS_CHANGED_IN_SPEC_VERSION: u32 = 17;
+
 impl<T: Trait> Module<T> {
     /// This method is called from on_initialize() when a runtime upgrade is detected. This
     /// happens when the runtime spec version is found to be higher than the stored value.
     /// Important to note this method should be carefully maintained, because it runs on every runtime
     /// upgrade.
-    fn runtime_upgraded() {
+    pub(crate) fn runtime_upgraded(previous_spec_version: Option<u32>) {
         debug::print!("Running runtime upgraded handler");
 
         // Add initialization of modules introduced in new runtime release. Typically this
         // would be any new storage values that need an initial value which would not
         // have been initialized with config() or build() chainspec construction mechanism.
         // Other tasks like resetting values, migrating values etc.
+
+        // Stored values are re-encoded from their previous layouts, so this must only happen when
+        // upgrading from a runtime which stored the previous layouts.
+        if previous_spec_version.map_or(true, |spec_version| {
+            spec_version < STORAGE_LAYOUTS_CHANGED_IN_SPEC_VERSION
+        }) {
+            <content_working_group::Module<T>>::migrate_early_exit_terms();
+            <storage::data_object_type_registry::Module<T>>::migrate_data_object_types();
+
+            // Data objects have to be readable before their relationships are converted. Pending
+            // and rejected data objects which can't be found here are migrated by root, see
+            // `migrate_data_objects_by_content_id`.
+            let content_ids =
+                <storage::data_object_storage_registry::Module<T>>::relationship_content_ids();
+            <storage::data_directory::Module<T>>::migrate_data_objects(content_ids);
+
+            // Retire the deprecated storage relationships in favour of the storage providers
+            // serving content
+            <storage::data_object_storage_registry::Module<T>>::migrate_relationships();
+
+            <versioned_store::Module<T>>::migrate_classes();
+            <versioned_store_permissions::Module<T>>::migrate_class_permissions();
+
+            // Count the references between the existing entities of the versioned store
+            <versioned_store::Module<T>>::initialize_reference_counts();
+        }
+
+        // Voting and grace periods for the 'set storage provider reward rate' proposal
+        let p = proposals_codex::ProposalsConfigParameters::default();
+        <proposals_codex::SetStorageProviderRewardRateProposalVotingPeriod<T>>::put(
+            T::BlockNumber::from(p.set_storage_provider_reward_rate_proposal_voting_period),
+        );
+        <proposals_codex::SetStorageProviderRewardRateProposalGracePeriod<T>>::put(
+            T::BlockNumber::from(p.set_storage_provider_reward_rate_proposal_grace_period),
+        );
+
+        // Voting and grace periods for the 'uphold slash appeal' and 'cancel slash appeal' proposals
+        <proposals_codex::SlashAppealProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.slash_appeal_proposal_voting_period,
+        ));
+        <proposals_codex::SlashAppealProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.slash_appeal_proposal_grace_period,
+        ));
     }
 }
 
-pub trait Trait: system::Trait {
+pub trait Trait:
+    system::Trait
+    + proposals_codex::Trait
+    + storage::data_object_storage_registry::Trait
+    + versioned_store_permissions::Trait
+    + content_working_group::Trait
+{
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 }
 
@@ -48,12 +101,14 @@ decl_module! {
         fn deposit_event() = default;
 
         fn on_initialize(_now: T::BlockNumber) {
-            if Self::spec_version().map_or(true, |spec_version| VERSION.spec_version > spec_version) {
+            let previous_spec_version = Self::spec_version();
+
+            if previous_spec_version.map_or(true, |spec_version| VERSION.spec_version > spec_version) {
                 // Mark store version with current version of the runtime
                 SpecVersion::put(VERSION.spec_version);
 
                 // Run migrations and store initializers
-                Self::runtime_upgraded();
+                Self::runtime_upgraded(previous_spec_version);
 
                 Self::deposit_event(RawEvent::Migrated(
                     <system::Module<T>>::block_number(),
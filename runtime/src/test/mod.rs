@@ -3,3 +3,4 @@
 #![cfg(test)]
 
 mod proposals_integration;
+mod runtime_upgrade;
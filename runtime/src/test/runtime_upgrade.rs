@@ -0,0 +1,247 @@
+//! Runtime upgrade tests - migration of the values stored by the previous runtime.
+
+#![cfg(test)]
+
+use crate::{migration, BlockNumber, ContentId, Runtime};
+use common::storage_migration::{put_map_value, replace_linked_map_value};
+use membership::members;
+use sr_primitives::traits::OnInitialize;
+use sr_primitives::AccountId32;
+use srml_support::{StorageMap, StorageValue};
+use storage::{data_directory, data_object_storage_registry};
+use system::RawOrigin;
+use versioned_store::{
+    Class, ClassPropertyValue, ClassSchema, Entity, Property, PropertyType, PropertyValue,
+};
+use versioned_store_permissions::{ClassPermissions, EntityPermissions};
+
+fn initial_test_ext() -> runtime_io::TestExternalities {
+    let t = system::GenesisConfig::default()
+        .build_storage::<Runtime>()
+        .unwrap();
+
+    t.into()
+}
+
+type Membership = membership::members::Module<Runtime>;
+type DataDirectory = data_directory::Module<Runtime>;
+type DataObjectStorageRegistry = data_object_storage_registry::Module<Runtime>;
+type Migration = migration::Module<Runtime>;
+type VersionedStore = versioned_store::Module<Runtime>;
+type VersionedStorePermissions = versioned_store_permissions::Module<Runtime>;
+
+/// Previous spec version, storing the values in their previous layouts.
+const PREVIOUS_SPEC_VERSION: u32 = 16;
+
+fn setup_member(account_id: AccountId32) -> u64 {
+    let authority_account_id = AccountId32::default();
+    Membership::set_screening_authority(RawOrigin::Root.into(), authority_account_id.clone())
+        .unwrap();
+
+    let member_id = Membership::members_created();
+    Membership::add_screened_member(
+        RawOrigin::Signed(authority_account_id).into(),
+        account_id.clone(),
+        members::UserInfo {
+            handle: Some(account_id.as_ref().to_vec()),
+            avatar_uri: None,
+            about: None,
+        },
+    )
+    .unwrap();
+
+    member_id
+}
+
+fn upgrade_runtime(block_number: BlockNumber) {
+    migration::SpecVersion::put(PREVIOUS_SPEC_VERSION);
+    <Migration as OnInitialize<BlockNumber>>::on_initialize(block_number);
+}
+
+#[test]
+fn data_objects_are_migrated_before_storage_relationships() {
+    initial_test_ext().execute_with(|| {
+        let owner: AccountId32 = [1; 32].into();
+        let storage_provider: AccountId32 = [2; 32].into();
+        let content_id = ContentId::from([3; 32]);
+        let member_id = setup_member(owner.clone());
+
+        // Data object and its relationship as stored by the previous runtime
+        let old_data_object = data_directory::OldDataObject::<Runtime> {
+            owner: owner.clone(),
+            added_at: data_directory::BlockAndTime { block: 1, time: 6 },
+            type_id: 1,
+            size: 1024,
+            liaison: storage_provider.clone(),
+            liaison_judgement: data_directory::LiaisonJudgement::Accepted,
+            ipfs_content_id: b"ipfs".to_vec(),
+        };
+        put_map_value::<data_directory::DataObjectByContentId<Runtime>, _, _, _>(
+            &content_id,
+            &old_data_object,
+        );
+
+        let relationship_id = DataObjectStorageRegistry::next_relationship_id();
+        <data_object_storage_registry::Relationships<Runtime>>::insert(
+            relationship_id,
+            data_object_storage_registry::DataObjectStorageRelationship {
+                content_id,
+                storage_provider: storage_provider.clone(),
+                ready: true,
+            },
+        );
+        <data_object_storage_registry::NextRelationshipId<Runtime>>::put(relationship_id + 1);
+
+        assert_eq!(DataDirectory::data_object_by_content_id(content_id), None);
+
+        upgrade_runtime(2);
+
+        assert_eq!(
+            DataDirectory::data_object_by_content_id(content_id),
+            Some(data_directory::DataObject {
+                owner,
+                owner_member_id: Some(member_id),
+                added_at: old_data_object.added_at,
+                type_id: old_data_object.type_id,
+                size: old_data_object.size,
+                liaison: old_data_object.liaison,
+                liaison_judgement: old_data_object.liaison_judgement,
+                ipfs_content_id: old_data_object.ipfs_content_id,
+                rejection_reason: None,
+                judgement_deadline: None,
+            })
+        );
+        assert_eq!(
+            DataDirectory::storage_usage_by_member_id(member_id),
+            data_directory::StorageUsage {
+                size_used: 1024,
+                objects_used: 1,
+            }
+        );
+
+        // The relationship found the migrated data object
+        assert_eq!(
+            DataObjectStorageRegistry::storage_providers_by_content_id(content_id),
+            vec![storage_provider.clone()]
+        );
+        assert_eq!(
+            DataObjectStorageRegistry::bytes_served_by_storage_provider(&storage_provider),
+            1024
+        );
+        assert!(DataObjectStorageRegistry::relationships(relationship_id).is_none());
+    });
+}
+
+#[test]
+fn versioned_store_is_migrated() {
+    initial_test_ext().execute_with(|| {
+        let class_id = VersionedStore::create_class(b"class".to_vec(), vec![]).unwrap();
+        <versioned_store_permissions::ClassPermissionsByClassId<Runtime>>::insert(
+            class_id,
+            ClassPermissions::default(),
+        );
+
+        // Class and its permissions as stored by the previous runtime
+        let old_property = versioned_store::OldProperty {
+            prop_type: PropertyType::Internal(class_id),
+            required: false,
+            name: b"internal".to_vec(),
+            description: vec![],
+        };
+        put_map_value::<versioned_store::ClassById, _, _, _>(
+            &class_id,
+            &versioned_store::OldClass {
+                id: class_id,
+                properties: vec![old_property.clone()],
+                schemas: vec![versioned_store::OldClassSchema {
+                    properties: vec![0],
+                }],
+                name: b"class".to_vec(),
+                description: vec![],
+            },
+        );
+        let old_class_permissions: versioned_store_permissions::OldClassPermissionsType<Runtime> =
+            versioned_store_permissions::OldClassPermissions {
+                entity_permissions: versioned_store_permissions::OldEntityPermissions {
+                    update: vec![1].into(),
+                    maintainer_has_all_permissions: true,
+                },
+                entities_can_be_created: true,
+                add_schemas: vec![].into(),
+                create_entities: vec![1].into(),
+                reference_constraint: Default::default(),
+                admins: vec![].into(),
+                last_permissions_update: 1,
+            };
+        assert!(replace_linked_map_value::<
+            versioned_store_permissions::ClassPermissionsByClassId<Runtime>,
+            _,
+            _,
+            _,
+        >(&class_id, old_class_permissions.clone()));
+
+        // Two entities, the second referencing the first, stored without the reference counts
+        let entity_id = VersionedStore::create_entity(class_id).unwrap();
+        let referencing_entity_id = VersionedStore::create_entity(class_id).unwrap();
+        versioned_store::EntityById::insert(
+            referencing_entity_id,
+            Entity {
+                id: referencing_entity_id,
+                class_id,
+                in_class_schema_indexes: vec![0],
+                values: vec![ClassPropertyValue {
+                    in_class_index: 0,
+                    value: PropertyValue::Internal(entity_id),
+                }],
+            },
+        );
+
+        upgrade_runtime(2);
+
+        assert_eq!(
+            VersionedStore::class_by_id(class_id),
+            Class {
+                id: class_id,
+                properties: vec![Property {
+                    prop_type: old_property.prop_type,
+                    required: false,
+                    name: old_property.name,
+                    description: old_property.description,
+                    unique: false,
+                    indexed: false,
+                    constraints: vec![],
+                }],
+                schemas: vec![ClassSchema {
+                    properties: vec![0],
+                    is_active: true,
+                }],
+                name: b"class".to_vec(),
+                description: vec![],
+            }
+        );
+        assert_eq!(
+            VersionedStorePermissions::class_permissions_by_class_id(class_id),
+            ClassPermissions {
+                entity_permissions: EntityPermissions {
+                    update: old_class_permissions.entity_permissions.update,
+                    delete: vec![].into(),
+                    maintainer_has_all_permissions: true,
+                },
+                property_permissions: Default::default(),
+                entities_can_be_created: true,
+                add_schemas: old_class_permissions.add_schemas,
+                create_entities: old_class_permissions.create_entities,
+                reference_constraint: old_class_permissions.reference_constraint,
+                admins: old_class_permissions.admins,
+                last_permissions_update: 1,
+            }
+        );
+        assert_eq!(VersionedStore::reference_count_by_entity_id(entity_id), 1);
+
+        // Upgrading to a later runtime doesn't migrate or count the references again
+        Migration::runtime_upgraded(Some(migration::STORAGE_LAYOUTS_CHANGED_IN_SPEC_VERSION));
+
+        assert_eq!(VersionedStore::class_by_id(class_id).properties.len(), 1);
+        assert_eq!(VersionedStore::reference_count_by_entity_id(entity_id), 1);
+    });
+}
@@ -128,10 +128,32 @@ export class Principal extends Enum {
   }
 };
 
+export type IEarlyExitTerms = {
+  minimum_service_period: BlockNumber,
+  slash_percentage: u8,
+};
+export class EarlyExitTerms extends JoyStruct<IEarlyExitTerms> {
+  constructor (value?: IEarlyExitTerms) {
+    super({
+      minimum_service_period: u32,
+      slash_percentage: u8,
+    }, value);
+  }
+
+  get minimum_service_period(): u32 {
+    return this.getField<u32>('minimum_service_period')
+  }
+
+  get slash_percentage(): u8 {
+    return this.getField<u8>('slash_percentage')
+  }
+};
+
 export type ICuratorRoleStakeProfile = {
   stake_id: StakeId,
   termination_unstaking_period: Option<BlockNumber>,
   exit_unstaking_period: Option<BlockNumber>,
+  early_exit_terms: Option<EarlyExitTerms>,
 };
 export class CuratorRoleStakeProfile extends JoyStruct<ICuratorRoleStakeProfile> {
   constructor (value?: ICuratorRoleStakeProfile) {
@@ -139,6 +161,7 @@ export class CuratorRoleStakeProfile extends JoyStruct<ICuratorRoleStakeProfile>
       stake_id: StakeId,
       termination_unstaking_period: Option.with(u32),
       exit_unstaking_period: Option.with(u32),
+      early_exit_terms: Option.with(EarlyExitTerms),
     }, value);
   }
 
@@ -336,6 +359,7 @@ export type IOpeningPolicyCommitment = {
   terminate_curator_role_stake_unstaking_period: Option<BlockNumber>,
   exit_curator_role_application_stake_unstaking_period: Option<BlockNumber>,
   exit_curator_role_stake_unstaking_period: Option<BlockNumber>,
+  exit_curator_role_early_exit_terms: Option<EarlyExitTerms>,
 };
 export class OpeningPolicyCommitment extends JoyStruct<IOpeningPolicyCommitment> {
   constructor (value?: IOpeningPolicyCommitment) {
@@ -352,6 +376,7 @@ export class OpeningPolicyCommitment extends JoyStruct<IOpeningPolicyCommitment>
       terminate_curator_role_stake_unstaking_period: Option.with(u32),
       exit_curator_role_application_stake_unstaking_period: Option.with(u32),
       exit_curator_role_stake_unstaking_period: Option.with(u32),
+      exit_curator_role_early_exit_terms: Option.with(EarlyExitTerms),
     }, value);
   }
 
@@ -402,6 +427,10 @@ export class OpeningPolicyCommitment extends JoyStruct<IOpeningPolicyCommitment>
   get exit_curator_role_stake_unstaking_period(): Option<u32> {
     return this.getField<Option<u32>>('exit_curator_role_stake_unstaking_period')
   }
+
+  get exit_curator_role_early_exit_terms(): Option<EarlyExitTerms> {
+    return this.getField<Option<EarlyExitTerms>>('exit_curator_role_early_exit_terms')
+  }
 };
 
 // Not entierly sure that using BTreeSet will work correctly when reading/decoding this type from chain state
@@ -530,6 +559,7 @@ export function registerContentWorkingGroupTypes () {
       Curator,
       CuratorApplication,
       CuratorOpening,
+      EarlyExitTerms,
       Lead,
       OpeningPolicyCommitment,
       Principal,
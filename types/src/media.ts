@@ -1,6 +1,9 @@
 import { Enum, Struct, Option, Vec as Vector, H256 } from '@polkadot/types';
-import { getTypeRegistry, u32, u64, bool, Text, GenericAccountId } from '@polkadot/types';
-import { BlockNumber, Moment, AccountId } from '@polkadot/types/interfaces';
+import { getTypeRegistry, u32, u64, u128, bool, Text, GenericAccountId } from '@polkadot/types';
+import { BlockNumber, Moment, AccountId, Balance, Hash } from '@polkadot/types/interfaces';
+
+import { MemberId } from './members';
+import { ChannelId } from './content-working-group';
 
 import { randomAsU8a } from '@polkadot/util-crypto';
 import { encodeAddress, decodeAddress } from '@polkadot/keyring';
@@ -70,6 +73,66 @@ export class VecContentId extends Vector.with(ContentId) {}
 export class OptionVecContentId extends Option.with(VecContentId) {}
 export class OptionSchemaId extends Option.with(SchemaId) {}
 export class OptionContentVisibility extends Option.with(ContentVisibility) {}
+
+export class ContentMetadataSchema extends Struct {
+  constructor (value?: any) {
+    super({
+      max_title_length: u32,
+      max_description_length: u32,
+      thumbnail_required: bool
+    }, value);
+  }
+
+  get max_title_length (): u32 {
+    return this.get('max_title_length') as u32;
+  }
+
+  get max_description_length (): u32 {
+    return this.get('max_description_length') as u32;
+  }
+
+  get thumbnail_required (): bool {
+    return this.get('thumbnail_required') as bool;
+  }
+}
+
+export class ContentMetadata extends Struct {
+  constructor (value?: any) {
+    super({
+      schema: SchemaId,
+      title: Text,
+      description: Text,
+      thumbnail: Option.with(ContentId),
+      visibility: ContentVisibility,
+      channel_id: Option.with(ChannelId)
+    }, value);
+  }
+
+  get schema (): SchemaId {
+    return this.get('schema') as SchemaId;
+  }
+
+  get title (): Text {
+    return this.get('title') as Text;
+  }
+
+  get description (): Text {
+    return this.get('description') as Text;
+  }
+
+  get thumbnail (): Option<ContentId> {
+    return this.get('thumbnail') as Option<ContentId>;
+  }
+
+  get visibility (): ContentVisibility {
+    return this.get('visibility') as ContentVisibility;
+  }
+
+  get channel_id (): Option<ChannelId> {
+    return this.get('channel_id') as Option<ChannelId>;
+  }
+}
+
 export type LiaisonJudgementKey = 'Pending' | 'Accepted' | 'Rejected';
 
 export class LiaisonJudgement extends Enum {
@@ -86,12 +149,15 @@ export class DataObject extends Struct {
   constructor (value?: any) {
     super({
       owner: GenericAccountId,
+      owner_member_id: Option.with(MemberId),
       added_at: BlockAndTime,
       type_id: DataObjectTypeId,
       size: u64,
       liaison: GenericAccountId,
       liaison_judgement: LiaisonJudgement,
       ipfs_content_id: Text,
+      rejection_reason: Option.with(Text),
+      judgement_deadline: Option.with(u32), // BlockNumber
     }, value);
   }
 
@@ -99,6 +165,10 @@ export class DataObject extends Struct {
     return this.get('owner') as AccountId;
   }
 
+  get owner_member_id (): Option<MemberId> {
+    return this.get('owner_member_id') as Option<MemberId>;
+  }
+
   get added_at (): BlockAndTime {
     return this.get('added_at') as BlockAndTime;
   }
@@ -123,6 +193,82 @@ export class DataObject extends Struct {
   get ipfs_content_id () : Text {
     return this.get('ipfs_content_id') as Text
   }
+
+  get rejection_reason () : Option<Text> {
+    return this.get('rejection_reason') as Option<Text>
+  }
+
+  get judgement_deadline () : Option<BlockNumber> {
+    return this.get('judgement_deadline') as Option<BlockNumber>
+  }
+}
+
+export class StorageProviderCapacity extends Struct {
+  constructor (value?: any) {
+    super({
+      free_bytes: u64,
+      max_concurrent_uploads: u32
+    }, value);
+  }
+
+  get free_bytes (): u64 {
+    return this.get('free_bytes') as u64;
+  }
+
+  get max_concurrent_uploads (): u32 {
+    return this.get('max_concurrent_uploads') as u32;
+  }
+}
+
+export class LiaisonLoad extends Struct {
+  constructor (value?: any) {
+    super({
+      pending_bytes: u64,
+      pending_uploads: u32
+    }, value);
+  }
+
+  get pending_bytes (): u64 {
+    return this.get('pending_bytes') as u64;
+  }
+
+  get pending_uploads (): u32 {
+    return this.get('pending_uploads') as u32;
+  }
+}
+
+export class QuotaLimits extends Struct {
+  constructor (value?: any) {
+    super({
+      size_limit: u64,
+      objects_limit: u64
+    }, value);
+  }
+
+  get size_limit (): u64 {
+    return this.get('size_limit') as u64;
+  }
+
+  get objects_limit (): u64 {
+    return this.get('objects_limit') as u64;
+  }
+}
+
+export class StorageUsage extends Struct {
+  constructor (value?: any) {
+    super({
+      size_used: u64,
+      objects_used: u64
+    }, value);
+  }
+
+  get size_used (): u64 {
+    return this.get('size_used') as u64;
+  }
+
+  get objects_used (): u64 {
+    return this.get('objects_used') as u64;
+  }
 }
 
 export class DataObjectStorageRelationship extends Struct {
@@ -147,11 +293,92 @@ export class DataObjectStorageRelationship extends Struct {
   }
 }
 
+export class ChallengeId extends u64 {}
+
+export class ChallengeParameters extends Struct {
+  constructor (value?: any) {
+    super({
+      challenge_period: u32, // BlockNumber
+      response_period: u32, // BlockNumber
+      challenges_per_round: u32,
+      max_missed_challenges: u32,
+      slash_amount: u128 // Balance
+    }, value);
+  }
+
+  get challenge_period (): BlockNumber {
+    return this.get('challenge_period') as BlockNumber;
+  }
+
+  get response_period (): BlockNumber {
+    return this.get('response_period') as BlockNumber;
+  }
+
+  get challenges_per_round (): u32 {
+    return this.get('challenges_per_round') as u32;
+  }
+
+  get max_missed_challenges (): u32 {
+    return this.get('max_missed_challenges') as u32;
+  }
+
+  get slash_amount (): Balance {
+    return this.get('slash_amount') as Balance;
+  }
+}
+
+export class ContentProofCommitment extends Struct {
+  constructor (value?: any) {
+    super({
+      merkle_root: H256, // Hash
+      chunk_size: u64
+    }, value);
+  }
+
+  get merkle_root (): Hash {
+    return this.get('merkle_root') as Hash;
+  }
+
+  get chunk_size (): u64 {
+    return this.get('chunk_size') as u64;
+  }
+}
+
+export class StorageChallenge extends Struct {
+  constructor (value?: any) {
+    super({
+      storage_provider: GenericAccountId,
+      content_id: ContentId,
+      chunk_index: u64,
+      deadline: u32 // BlockNumber
+    }, value);
+  }
+
+  get storage_provider (): AccountId {
+    return this.get('storage_provider') as AccountId;
+  }
+
+  get content_id (): ContentId {
+    return this.get('content_id') as ContentId;
+  }
+
+  get chunk_index (): u64 {
+    return this.get('chunk_index') as u64;
+  }
+
+  get deadline (): BlockNumber {
+    return this.get('deadline') as BlockNumber;
+  }
+}
+
 export class DataObjectType extends Struct {
   constructor (value?: any) {
     super({
       description: Text,
-      active: bool
+      active: bool,
+      max_size: Option.with(u64),
+      replication_factor: u32,
+      min_zones: u32
     }, value);
   }
 
@@ -162,6 +389,18 @@ export class DataObjectType extends Struct {
   get active (): bool {
     return this.get('active') as bool;
   }
+
+  get max_size (): Option<u64> {
+    return this.get('max_size') as Option<u64>;
+  }
+
+  get replication_factor (): u32 {
+    return this.get('replication_factor') as u32;
+  }
+
+  get min_zones (): u32 {
+    return this.get('min_zones') as u32;
+  }
 }
 
 export type DownloadStateKey = 'Started' | 'Ended';
@@ -217,6 +456,42 @@ export class DownloadSession extends Struct {
   }
 }
 
+export class ContentChunk extends Struct {
+  constructor (value?: any) {
+    super({
+      hash: H256,
+      size: u64
+    }, value);
+  }
+
+  /** Actually it's 'hash', but 'hash' is already reserved by a parent class. */
+  get chunk_hash (): H256 {
+    return this.get('hash') as H256;
+  }
+
+  /** Actually it's 'size', but 'size' is already reserved by a parent class. */
+  get size_in_bytes (): u64 {
+    return this.get('size') as u64;
+  }
+}
+
+export class ContentManifest extends Struct {
+  constructor (value?: any) {
+    super({
+      root_hash: H256,
+      chunks: Vector.with(ContentChunk)
+    }, value);
+  }
+
+  get root_hash (): H256 {
+    return this.get('root_hash') as H256;
+  }
+
+  get chunks (): Vector<ContentChunk> {
+    return this.get('chunks') as Vector<ContentChunk>;
+  }
+}
+
 export function registerMediaTypes () {
   try {
     getTypeRegistry().register({
@@ -225,10 +500,22 @@ export function registerMediaTypes () {
       // SchemaId, // This isn't required? (its what caused issue with type mismatch in permissions module!)
       ContentId,
       ContentVisibility,
+      ContentMetadataSchema,
+      ContentMetadata,
       LiaisonJudgement,
       DataObject,
+      ContentChunk,
+      ContentManifest,
+      StorageProviderCapacity,
+      LiaisonLoad,
+      QuotaLimits,
+      StorageUsage,
       DataObjectStorageRelationshipId,
       DataObjectStorageRelationship,
+      ChallengeId,
+      ChallengeParameters,
+      ContentProofCommitment,
+      StorageChallenge,
       DataObjectTypeId,
       DataObjectType,
       DownloadState,
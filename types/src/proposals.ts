@@ -2,7 +2,7 @@ import { Text, u32, Enum, getTypeRegistry, Tuple, GenericAccountId, u8, Vec, Opt
 import { BlockNumber, Balance } from "@polkadot/types/interfaces";
 import { MemberId } from "./members";
 import { ThreadId } from "./forum";
-import { StakeId } from "./stake";
+import { StakeId, SlashId } from "./stake";
 import AccountId from "@polkadot/types/primitive/Generic/AccountId";
 import { JoyStruct } from "./JoyStruct";
 
@@ -326,6 +326,12 @@ class SetLeadParams extends Tuple {
 
 export class SetLead extends Option.with(SetLeadParams) {}
 
+export class SlashAppealParams extends Tuple {
+  constructor(value?: any) {
+    super([StakeId, SlashId], value);
+  }
+}
+
 export class ProposalDetails extends Enum {
   constructor(value?: any, index?: number) {
     super(
@@ -338,7 +344,10 @@ export class ProposalDetails extends Enum {
         SetContentWorkingGroupMintCapacity: "Balance",
         EvictStorageProvider: "AccountId",
         SetValidatorCount: "u32",
-        SetStorageRoleParameters: RoleParameters
+        SetStorageRoleParameters: RoleParameters,
+        SetStorageProviderRewardRate: "Balance",
+        UpholdSlashAppeal: SlashAppealParams,
+        CancelSlashAppeal: SlashAppealParams
       },
       value,
       index
@@ -1,6 +1,6 @@
-import { getTypeRegistry, u32, u64, u128, Enum, Null, BTreeMap, bool } from '@polkadot/types';
+import { getTypeRegistry, u32, u64, u128, Enum, Null, BTreeMap, bool, Bytes, GenericAccountId, Option } from '@polkadot/types';
 import { JoyStruct } from '../JoyStruct';
-import { BlockNumber, Balance } from '@polkadot/types/interfaces';
+import { AccountId, BlockNumber, Balance } from '@polkadot/types/interfaces';
 
 export class StakeId extends u64 {};
 export class SlashId extends u64 {};
@@ -22,6 +22,107 @@ export class Slash extends JoyStruct<ISlash> {
     }
 };
 
+export class SlashRecordId extends u64 {};
+
+export type ISlashDetails = {
+    reason: Bytes,
+    reporter: Option<AccountId>,
+};
+export class SlashDetails extends JoyStruct<ISlashDetails> {
+    constructor (value?: ISlashDetails) {
+        super({
+            reason: Bytes,
+            reporter: Option.with(GenericAccountId),
+        }, value);
+    }
+
+    get reason(): Bytes {
+      return this.getField<Bytes>('reason')
+    }
+
+    get reporter(): Option<AccountId> {
+      return this.getField<Option<AccountId>>('reporter')
+    }
+};
+
+export type ISlashRecord = {
+    stake_id: StakeId,
+    slash_id: Option<SlashId>,
+    slashed_amount: Balance,
+    reason: Bytes,
+    reporter: Option<AccountId>,
+    executed_at_block: BlockNumber,
+};
+export class SlashRecord extends JoyStruct<ISlashRecord> {
+    constructor (value?: ISlashRecord) {
+        super({
+            stake_id: StakeId,
+            slash_id: Option.with(SlashId),
+            slashed_amount: u128,
+            reason: Bytes,
+            reporter: Option.with(GenericAccountId),
+            executed_at_block: u32,
+        }, value);
+    }
+
+    get stake_id(): StakeId {
+      return this.getField<StakeId>('stake_id')
+    }
+
+    get slash_id(): Option<SlashId> {
+      return this.getField<Option<SlashId>>('slash_id')
+    }
+
+    get slashed_amount(): u128 {
+      return this.getField<u128>('slashed_amount')
+    }
+
+    get reason(): Bytes {
+      return this.getField<Bytes>('reason')
+    }
+
+    get reporter(): Option<AccountId> {
+      return this.getField<Option<AccountId>>('reporter')
+    }
+
+    get executed_at_block(): u32 {
+      return this.getField<u32>('executed_at_block')
+    }
+};
+
+export type ISlashAppeal = {
+    appellant: AccountId,
+    filed_at_block: BlockNumber,
+    rationale: Bytes,
+    slash_was_active: bool,
+};
+export class SlashAppeal extends JoyStruct<ISlashAppeal> {
+    constructor (value?: ISlashAppeal) {
+        super({
+            appellant: GenericAccountId,
+            filed_at_block: u32,
+            rationale: Bytes,
+            slash_was_active: bool,
+        }, value);
+    }
+
+    get appellant(): AccountId {
+      return this.getField<AccountId>('appellant')
+    }
+
+    get filed_at_block(): u32 {
+      return this.getField<u32>('filed_at_block')
+    }
+
+    get rationale(): Bytes {
+      return this.getField<Bytes>('rationale')
+    }
+
+    get slash_was_active(): bool {
+      return this.getField<bool>('slash_was_active')
+    }
+};
+
 export type IUnstakingState = {
     started_at_block: BlockNumber,
     is_active: bool,
@@ -120,7 +221,12 @@ export function registerStakeTypes () {
     try {
       getTypeRegistry().register({
         StakeId: 'u64',
+        SlashId: 'u64',
         Stake,
+        SlashAppeal,
+        SlashRecordId: 'u64',
+        SlashDetails,
+        SlashRecord,
       });
     } catch (err) {
       console.error('Failed to register custom types of stake module', err);
@@ -90,6 +90,7 @@ function plainToSubstrate(propType: string, value: any): PropertyValue {
     case 'Int64':       return ok(new PV.Int64(value as string))
     case 'Text':        return ok(new PV.Text(value as string))
     case 'Internal':    return ok(new PV.Internal(value as string))
+    case 'External':    return ok(new PV.External(value as string))
 
     // Vectors:
 
@@ -102,6 +103,7 @@ function plainToSubstrate(propType: string, value: any): PropertyValue {
     case 'Int64Vec':    return ok(new PV.Int64Vec(valueAsStrArr()))
     case 'TextVec':     return ok(new PV.TextVec(valueAsStrArr()))
     case 'InternalVec': return ok(new PV.InternalVec(valueAsArr()))
+    case 'ExternalVec': return ok(new PV.ExternalVec(valueAsStrArr()))
 
     default: {
       throw new Error(`Unknown property type name: ${propType}`)
@@ -0,0 +1,71 @@
+import { i128, Null, Bytes } from '@polkadot/types';
+import { Enum, Tuple, Vec as Vector } from '@polkadot/types/codec';
+import PropertyValue from './PropertyValue';
+
+// Character classes:
+
+export class Lowercase extends Null {}
+export class Uppercase extends Null {}
+export class Digit extends Null {}
+export class Whitespace extends Null {}
+export class Chars extends Bytes {}
+
+export type CharClassEnum =
+  Lowercase |
+  Uppercase |
+  Digit |
+  Whitespace |
+  Chars
+;
+
+type CharClassEnumValue = {
+  [typeName: string]: CharClassEnum;
+};
+
+export class CharClass extends Enum {
+  constructor (value?: CharClassEnumValue, index?: number) {
+    super({
+      Lowercase,
+      Uppercase,
+      Digit,
+      Whitespace,
+      Chars
+    }, value, index);
+  }
+}
+
+// Constraints:
+
+export class Range extends Tuple.with([i128, i128]) {
+  static newTypesafe (min: i128 | number, max: i128 | number) {
+    return new this([ min, max ]);
+  }
+}
+
+export class OneOf extends Vector.with(PropertyValue) {}
+export class TextPattern extends Vector.with(CharClass) {}
+export class NonEmptyVec extends Null {}
+
+export type PropertyConstraintEnum =
+  Range |
+  OneOf |
+  TextPattern |
+  NonEmptyVec
+;
+
+type PropertyConstraintEnumValue = {
+  [typeName: string]: PropertyConstraintEnum;
+};
+
+export class PropertyConstraint extends Enum {
+  constructor (value?: PropertyConstraintEnumValue, index?: number) {
+    super({
+      Range,
+      OneOf,
+      TextPattern,
+      NonEmptyVec
+    }, value, index);
+  }
+}
+
+export default PropertyConstraint;
@@ -1,7 +1,24 @@
-import { u16, Null } from '@polkadot/types';
+import { u16, u64, Null, Option } from '@polkadot/types';
 import { Enum, Tuple } from '@polkadot/types/codec';
+import { JoyStruct } from '../JoyStruct';
 import ClassId from './ClassId';
 
+export type ExternalPropertyType = {
+  data_object_type_id: Option<u64>
+};
+
+export class ExternalProperty extends JoyStruct<ExternalPropertyType> {
+  constructor (value?: ExternalPropertyType) {
+    super({
+      data_object_type_id: Option.with(u64)
+    }, value);
+  }
+
+  get data_object_type_id (): u64 | undefined {
+    return this.unwrapOrUndefined('data_object_type_id');
+  }
+}
+
 export class None extends Null {}
 
 // Single values:
@@ -15,6 +32,7 @@ export class Int32 extends Null {}
 export class Int64 extends Null {}
 export class Text extends u16 {}
 export class Internal extends ClassId {}
+export class External extends ExternalProperty {}
 
 // Vectors:
 
@@ -38,6 +56,12 @@ export class InternalVec extends Tuple.with([u16, ClassId]) {
   }
 }
 
+export class ExternalVec extends Tuple.with([u16, ExternalProperty]) {
+  static newTypesafe (maxItems: u16 | number, externalProp: ExternalProperty) {
+    return new this([ maxItems, externalProp ]);
+  }
+}
+
 export type PropertyTypeEnum =
   None |
 
@@ -61,7 +85,9 @@ export type PropertyTypeEnum =
   Int32Vec |
   Int64Vec |
   TextVec |
-  InternalVec
+  InternalVec |
+  External |
+  ExternalVec
 ;
 
 type PropertyTypeEnumValue = {
@@ -93,7 +119,9 @@ export class PropertyType extends Enum {
       Int32Vec,
       Int64Vec,
       TextVec,
-      InternalVec
+      InternalVec,
+      External,
+      ExternalVec
     }, value, index);
   }
 }
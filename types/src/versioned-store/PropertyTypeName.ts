@@ -20,7 +20,9 @@ export type PropertyTypeName =
   'Int32Vec' |
   'Int64Vec' |
   'TextVec' |
-  'InternalVec'
+  'InternalVec' |
+  'External' |
+  'ExternalVec'
 ;
 
 export default PropertyTypeName;
@@ -1,6 +1,7 @@
 import { Vec as Vector, Text as PolkaText, bool as PolkaBool, Null, u16, u32, u64, i16, i32, i64 } from '@polkadot/types';
 import { Enum } from '@polkadot/types/codec';
 import EntityId from './EntityId';
+import { ContentId } from '../media';
 
 export class None extends Null {}
 
@@ -29,6 +30,9 @@ export class Int64Vec extends Vector.with(i64) {}
 export class TextVec extends Vector.with(PolkaText) {}
 export class InternalVec extends Vector.with(EntityId) {}
 
+export class External extends ContentId {}
+export class ExternalVec extends Vector.with(ContentId) {}
+
 export type PropertyValueEnum =
   None |
 
@@ -52,7 +56,9 @@ export type PropertyValueEnum =
   Int32Vec |
   Int64Vec |
   TextVec |
-  InternalVec
+  InternalVec |
+  External |
+  ExternalVec
 ;
 
 export type PropertyValueEnumValue = {
@@ -84,7 +90,9 @@ export class PropertyValue extends Enum {
       Int32Vec,
       Int64Vec,
       TextVec,
-      InternalVec
+      InternalVec,
+      External,
+      ExternalVec
     }, value, index);
   }
 }
@@ -1,8 +1,9 @@
 import { getTypeRegistry, u16, Text, bool as Bool } from '@polkadot/types';
 import { Vec as Vector } from '@polkadot/types/codec';
 import { JoyStruct } from '../JoyStruct';
-import PropertyType from './PropertyType';
+import PropertyType, { ExternalProperty } from './PropertyType';
 import PropertyValue from './PropertyValue';
+import PropertyConstraint, { CharClass } from './PropertyConstraint';
 import ClassId from './ClassId';
 import EntityId from './EntityId';
 import { camelCase, upperFirst } from 'lodash'
@@ -11,7 +12,8 @@ export {
   ClassId,
   EntityId,
   PropertyType,
-  PropertyValue
+  PropertyValue,
+  PropertyConstraint
 }
 
 export type InputValidationLengthConstraintType = {
@@ -40,11 +42,16 @@ export class InputValidationLengthConstraint extends JoyStruct<InputValidationLe
   }
 }
 
+export class VecPropertyConstraint extends Vector.with(PropertyConstraint) {}
+
 export type PropertyTsType = {
   prop_type: PropertyType,
   required: Bool,
   name: Text,
-  description: Text
+  description: Text,
+  unique: Bool,
+  indexed: Bool,
+  constraints: VecPropertyConstraint
 };
 
 export class Property extends JoyStruct<PropertyTsType> {
@@ -53,7 +60,10 @@ export class Property extends JoyStruct<PropertyTsType> {
       prop_type: PropertyType,
       required: Bool,
       name: Text,
-      description: Text
+      description: Text,
+      unique: Bool,
+      indexed: Bool,
+      constraints: VecPropertyConstraint
     }, value);
   }
 
@@ -72,6 +82,18 @@ export class Property extends JoyStruct<PropertyTsType> {
   get description (): string {
     return this.getString('description');
   }
+
+  get unique (): boolean {
+    return this.getBoolean('unique');
+  }
+
+  get indexed (): boolean {
+    return this.getBoolean('indexed');
+  }
+
+  get constraints (): VecPropertyConstraint {
+    return this.getField('constraints');
+  }
 }
 
 export class VecProperty extends Vector.with(Property) {}
@@ -79,19 +101,25 @@ export class VecProperty extends Vector.with(Property) {}
 export class VecU16 extends Vector.with(u16) {}
 
 export type ClassSchemaType = {
-  properties: VecU16
+  properties: VecU16,
+  is_active: Bool
 };
 
 export class ClassSchema extends JoyStruct<ClassSchemaType> {
   constructor (value: ClassSchemaType) {
     super({
-      properties: VecU16
+      properties: VecU16,
+      is_active: Bool
     }, value);
   }
 
   get properties (): VecU16 {
     return this.getField('properties');
   }
+
+  get is_active (): boolean {
+    return this.getBoolean('is_active');
+  }
 }
 
 export class VecClassSchema extends Vector.with(ClassSchema) {}
@@ -231,6 +259,9 @@ export function registerVersionedStoreTypes () {
       Entity,
       ClassSchema,
       Property,
+      ExternalProperty,
+      CharClass,
+      PropertyConstraint,
       PropertyType,
       PropertyValue,
       ClassPropertyValue
@@ -1,12 +1,16 @@
 import { JoyStruct } from '../../JoyStruct';
-import { u32, bool } from '@polkadot/types';
+import { u16, u32, bool, BTreeMap } from '@polkadot/types';
 import EntityPermissions from './EntityPermissions';
+import PropertyPermissions from './PropertyPermissions';
 import { CredentialSet } from './credentials';
 import { ReferenceConstraint } from './reference-constraint';
 import { BlockNumber } from '@polkadot/types/interfaces';
 
+export class PropertyPermissionsByIndex extends BTreeMap.with(u16, PropertyPermissions) {}
+
 type IClassPermissions = {
     entity_permissions: EntityPermissions,
+    property_permissions: PropertyPermissionsByIndex,
     entities_can_be_created: bool,
     add_schemas: CredentialSet,
     create_entities: CredentialSet,
@@ -19,6 +23,7 @@ export default class ClassPermissionsType extends JoyStruct<IClassPermissions> {
     constructor (value: IClassPermissions) {
         super({
             entity_permissions: EntityPermissions,
+            property_permissions: PropertyPermissionsByIndex,
             entities_can_be_created: bool,
             add_schemas: CredentialSet,
             create_entities: CredentialSet,
@@ -33,6 +38,10 @@ export default class ClassPermissionsType extends JoyStruct<IClassPermissions> {
         return this.getField('entity_permissions');
     }
 
+    get property_permissions() : PropertyPermissionsByIndex {
+        return this.getField('property_permissions');
+    }
+
     get entities_can_be_created() : bool {
         return this.getField('entities_can_be_created');
     }
@@ -4,6 +4,7 @@ import { CredentialSet } from './credentials';
 
 type IEntityPermissions = {
     update: CredentialSet,
+    delete: CredentialSet,
     maintainer_has_all_permissions: bool
 };
 
@@ -11,6 +12,7 @@ export default class EntityPermissions extends JoyStruct<IEntityPermissions> {
     constructor (value: IEntityPermissions) {
         super({
             update: CredentialSet,
+            delete: CredentialSet,
             maintainer_has_all_permissions: bool
         }, value);
     }
@@ -19,6 +21,10 @@ export default class EntityPermissions extends JoyStruct<IEntityPermissions> {
         return this.getField('update');
     }
 
+    get delete () : CredentialSet {
+        return this.getField('delete');
+    }
+
     get maintainer_has_all_permissions() : bool {
         return this.getField('maintainer_has_all_permissions');
     }
@@ -0,0 +1,25 @@
+import { JoyStruct } from '../../JoyStruct';
+import { bool } from '@polkadot/types';
+import { CredentialSet } from './credentials';
+
+type IPropertyPermissions = {
+    update: CredentialSet,
+    maintainer_can_update: bool
+};
+
+export default class PropertyPermissions extends JoyStruct<IPropertyPermissions> {
+    constructor (value: IPropertyPermissions) {
+        super({
+            update: CredentialSet,
+            maintainer_can_update: bool
+        }, value);
+    }
+
+    get update () : CredentialSet {
+        return this.getField('update');
+    }
+
+    get maintainer_can_update () : bool {
+        return this.getField('maintainer_can_update');
+    }
+}
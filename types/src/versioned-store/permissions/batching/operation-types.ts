@@ -1,13 +1,18 @@
-import { Enum, u16 } from '@polkadot/types';
-import { CreateEntityOperation, UpdatePropertyValuesOperation, AddSchemaSupportToEntityOperation, ParameterizedClassPropertyValues } from './operations'
+import { Enum, u16, u32 } from '@polkadot/types';
+import { CreateEntityOperation, UpdatePropertyValuesOperation, AddSchemaSupportToEntityOperation, MigrateEntitiesSchemaOperation, AssertPropertyValueOperation, AssertEntityVersionOperation, ParameterizedClassPropertyValues, VecParametrizedEntity } from './operations'
 import ClassId from '../../ClassId';
+import EntityId from '../../EntityId';
+import PropertyValue from '../../PropertyValue';
 import { ParametrizedEntity } from './parametrized-entity';
 
 export class CreateEntity extends CreateEntityOperation {}
 export class UpdatePropertyValues extends UpdatePropertyValuesOperation {}
 export class AddSchemaSupportToEntity extends AddSchemaSupportToEntityOperation {}
+export class MigrateEntitiesSchema extends MigrateEntitiesSchemaOperation {}
+export class AssertPropertyValue extends AssertPropertyValueOperation {}
+export class AssertEntityVersion extends AssertEntityVersionOperation {}
 
-export type OperationTypeVariant = CreateEntity | UpdatePropertyValues | AddSchemaSupportToEntity;
+export type OperationTypeVariant = CreateEntity | UpdatePropertyValues | AddSchemaSupportToEntity | MigrateEntitiesSchema | AssertPropertyValue | AssertEntityVersion;
 
 type OperationTypeVariantValue = {
     [typeName: string]: OperationTypeVariant;
@@ -19,6 +24,9 @@ export class OperationType extends Enum {
         CreateEntity,
         UpdatePropertyValues,
         AddSchemaSupportToEntity,
+        MigrateEntitiesSchema,
+        AssertPropertyValue,
+        AssertEntityVersion,
     }, value, index);
   }
 
@@ -43,4 +51,31 @@ export class OperationType extends Enum {
     });
     return new OperationType({'AddSchemaSupportToEntity': value});
   }
+
+  static MigrateEntitiesSchema (entity_ids: VecParametrizedEntity, from_schema_id: u16, to_schema_id: u16, default_parametrized_property_values: ParameterizedClassPropertyValues) : OperationType {
+    let value = new MigrateEntitiesSchema({
+      entity_ids,
+      from_schema_id,
+      to_schema_id,
+      default_parametrized_property_values
+    });
+    return new OperationType({'MigrateEntitiesSchema': value});
+  }
+
+  static AssertPropertyValue (entity_id: EntityId, in_class_index: u16, expected_value: PropertyValue) : OperationType {
+    let value = new AssertPropertyValue({
+      entity_id,
+      in_class_index,
+      expected_value
+    });
+    return new OperationType({'AssertPropertyValue': value});
+  }
+
+  static AssertEntityVersion (entity_id: EntityId, expected_version: u32) : OperationType {
+    let value = new AssertEntityVersion({
+      entity_id,
+      expected_version
+    });
+    return new OperationType({'AssertEntityVersion': value});
+  }
 }
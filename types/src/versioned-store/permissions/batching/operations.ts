@@ -1,7 +1,9 @@
 import ClassId from "../../ClassId";
 import { JoyStruct } from '../../../JoyStruct';
 import { ParametrizedEntity } from "./parametrized-entity";
-import { Vec, u16 } from "@polkadot/types";
+import { Vec, u16, u32 } from "@polkadot/types";
+import EntityId from "../../EntityId";
+import PropertyValue from "../../PropertyValue";
 import ParametrizedClassPropertyValue from "./ParametrizedClassPropertyValue";
 
 // TODO Rename to ParametrizedClassPropertyValue
@@ -22,6 +24,26 @@ export type IAddSchemaSupportToEntityOperation = {
     parametrized_property_values: ParameterizedClassPropertyValues
 };
 
+export class VecParametrizedEntity extends Vec.with(ParametrizedEntity) {}
+
+export type IMigrateEntitiesSchemaOperation = {
+    entity_ids: VecParametrizedEntity,
+    from_schema_id: u16,
+    to_schema_id: u16,
+    default_parametrized_property_values: ParameterizedClassPropertyValues
+};
+
+export type IAssertPropertyValueOperation = {
+    entity_id: EntityId,
+    in_class_index: u16,
+    expected_value: PropertyValue
+};
+
+export type IAssertEntityVersionOperation = {
+    entity_id: EntityId,
+    expected_version: u32
+};
+
 export class CreateEntityOperation extends JoyStruct<ICreateEntityOperation> {
     constructor (value: ICreateEntityOperation) {
         super({
@@ -71,4 +93,70 @@ export class AddSchemaSupportToEntityOperation extends JoyStruct<IAddSchemaSuppo
     get schema_id() : u16 {
         return this.getField('schema_id');
     }
+}
+
+export class MigrateEntitiesSchemaOperation extends JoyStruct<IMigrateEntitiesSchemaOperation> {
+    constructor (value: IMigrateEntitiesSchemaOperation) {
+        super({
+            entity_ids: VecParametrizedEntity,
+            from_schema_id: u16,
+            to_schema_id: u16,
+            default_parametrized_property_values: ParameterizedClassPropertyValues
+        }, value);
+    }
+
+    get entity_ids() : VecParametrizedEntity {
+        return this.getField('entity_ids');
+    }
+
+    get from_schema_id() : u16 {
+        return this.getField('from_schema_id');
+    }
+
+    get to_schema_id() : u16 {
+        return this.getField('to_schema_id');
+    }
+
+    get default_property_values() : ParameterizedClassPropertyValues {
+        return this.getField('default_parametrized_property_values');
+    }
+}
+
+export class AssertPropertyValueOperation extends JoyStruct<IAssertPropertyValueOperation> {
+    constructor (value: IAssertPropertyValueOperation) {
+        super({
+            entity_id: EntityId,
+            in_class_index: u16,
+            expected_value: PropertyValue
+        }, value);
+    }
+
+    get entity_id() : EntityId {
+        return this.getField('entity_id');
+    }
+
+    get in_class_index() : u16 {
+        return this.getField('in_class_index');
+    }
+
+    get expected_value() : PropertyValue {
+        return this.getField('expected_value');
+    }
+}
+
+export class AssertEntityVersionOperation extends JoyStruct<IAssertEntityVersionOperation> {
+    constructor (value: IAssertEntityVersionOperation) {
+        super({
+            entity_id: EntityId,
+            expected_version: u32
+        }, value);
+    }
+
+    get entity_id() : EntityId {
+        return this.getField('entity_id');
+    }
+
+    get expected_version() : u32 {
+        return this.getField('expected_version');
+    }
 }
\ No newline at end of file
@@ -1,4 +1,30 @@
-import { u64, Vec } from '@polkadot/types';
+import { u64, Vec, Enum, Null } from '@polkadot/types';
 
 export class Credential extends u64 {}
-export class CredentialSet extends Vec.with(Credential) {} // BtreeSet ?
\ No newline at end of file
+export class CredentialSet extends Vec.with(Credential) {} // BtreeSet ?
+
+export class System extends Null {}
+export class EntityMaintainer extends Null {}
+export class Unspecified extends Null {}
+
+export type AccessLevelVariant =
+  System |
+  EntityMaintainer |
+  Credential |
+  Unspecified
+;
+
+export type AccessLevelValue = {
+  [typeName: string]: AccessLevelVariant;
+};
+
+export class AccessLevel extends Enum {
+  constructor (value?: AccessLevelValue, index?: number) {
+    super({
+      System,
+      EntityMaintainer,
+      Credential,
+      Unspecified
+    }, value, index);
+  }
+}
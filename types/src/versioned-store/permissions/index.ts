@@ -1,7 +1,8 @@
 import { getTypeRegistry } from '@polkadot/types';
 
-import { Credential, CredentialSet } from './credentials';
+import { Credential, CredentialSet, AccessLevel } from './credentials';
 import EntityPermissions from './EntityPermissions';
+import PropertyPermissions from './PropertyPermissions';
 import { ReferenceConstraint} from './reference-constraint';
 import ClassPermissionsType from './ClassPermissions';
 import { Operation } from './batching/';
@@ -11,7 +12,9 @@ export function registerVersionedStorePermissionsTypes () {
       getTypeRegistry().register({
         Credential,
         CredentialSet,
+        AccessLevel,
         EntityPermissions,
+        PropertyPermissions,
         ReferenceConstraint,
         ClassPermissionsType,
         Operation,
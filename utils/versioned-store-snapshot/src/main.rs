@@ -0,0 +1,339 @@
+// Copyright 2020 Joystream Contributors
+// This file is part of Joystream node.
+
+// Joystream node is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Joystream node is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Joystream node.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::{
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use codec::{Decode, FullCodec};
+use serde::Deserialize;
+use srml_support::storage::{generator, unhashed};
+use structopt::StructOpt;
+
+use joystream_node::versioned_store_config::VersionedStoreData;
+use node_runtime::{
+    versioned_store::{ClassById, EntityById, NextClassId, NextEntityId},
+    versioned_store_permissions::{
+        ClassPermissionsByClassId, EntityCoMaintainersByEntityId, EntityMaintainerByEntityId,
+    },
+    Runtime,
+};
+
+/// A utility to export the versioned store and its permissions from a chain's state dump
+/// into the snapshot format loaded by the node's chain spec (`node/res/versioned_store_data.json`).
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+struct Opt {
+    /// Path of the state dump: the JSON response of the `state_getPairs` RPC call
+    /// made with an empty prefix, or just its `result` array.
+    #[structopt(long, short)]
+    state_dump_path: PathBuf,
+    /// The path where the snapshot should be saved.
+    #[structopt(long, short, default_value = "./versioned_store_data.json")]
+    output_path: PathBuf,
+}
+
+/// Hex encoded storage key/value pairs.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StateDump {
+    Response { result: Vec<(String, String)> },
+    Pairs(Vec<(String, String)>),
+}
+
+impl StateDump {
+    fn into_pairs(self) -> Vec<(String, String)> {
+        match self {
+            StateDump::Response { result } => result,
+            StateDump::Pairs(pairs) => pairs,
+        }
+    }
+}
+
+fn decode_hex(encoded: &str) -> Result<Vec<u8>, String> {
+    hex::decode(encoded.trim_start_matches("0x"))
+        .map_err(|err| format!("Failed to parse hex string {}: {}", encoded, err))
+}
+
+fn parse_state_dump(path: &Path) -> Result<Vec<(Vec<u8>, Vec<u8>)>, String> {
+    let data = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let dump: StateDump = serde_json::from_str(&data)
+        .map_err(|err| format!("Failed to parse state dump: {}", err))?;
+
+    dump.into_pairs()
+        .iter()
+        .map(|(key, value)| Ok((decode_hex(key)?, decode_hex(value)?)))
+        .collect()
+}
+
+/// Linkage stored after the value of a linked map entry.
+#[derive(Decode)]
+struct Linkage<K> {
+    _previous: Option<K>,
+    _next: Option<K>,
+}
+
+/// Decodes a whole stored value. Values stored by a runtime with other layouts fail to decode
+/// or leave bytes over, instead of being read as defaults like the storage getters do.
+fn decode_all<V: Decode>(raw: &[u8]) -> Result<V, String> {
+    let mut input = raw;
+    let value = V::decode(&mut input).map_err(|err| err.what().to_string())?;
+
+    if input.is_empty() {
+        Ok(value)
+    } else {
+        Err(format!("{} bytes left over", input.len()))
+    }
+}
+
+fn value<Value, V>(name: &str) -> Result<Option<V>, String>
+where
+    Value: generator::StorageValue<V>,
+    V: FullCodec,
+{
+    unhashed::get_raw(Value::storage_value_final_key().as_ref())
+        .map(|raw| decode_all(&raw).map_err(|err| format!("Failed to decode {}: {}", name, err)))
+        .transpose()
+}
+
+fn map_value<Map, K, V>(name: &str, key: &K) -> Result<Option<V>, String>
+where
+    Map: generator::StorageMap<K, V>,
+    K: FullCodec + Debug,
+    V: FullCodec,
+{
+    unhashed::get_raw(Map::storage_map_final_key(key).as_ref())
+        .map(|raw| {
+            decode_all(&raw).map_err(|err| format!("Failed to decode {} {:?}: {}", name, key, err))
+        })
+        .transpose()
+}
+
+fn linked_map_value<Map, K, V>(name: &str, key: &K) -> Result<Option<V>, String>
+where
+    Map: generator::StorageLinkedMap<K, V>,
+    K: FullCodec + Debug,
+    V: FullCodec,
+{
+    unhashed::get_raw(Map::storage_linked_map_final_key(key).as_ref())
+        .map(|raw| {
+            decode_all::<(V, Linkage<K>)>(&raw)
+                .map(|(value, _)| value)
+                .map_err(|err| format!("Failed to decode {} {:?}: {}", name, key, err))
+        })
+        .transpose()
+}
+
+/// Reads the versioned store from the storage of the current externalities. Ids are
+/// assigned sequentially, so every id below the next one is looked up.
+fn export_versioned_store() -> Result<VersionedStoreData, String> {
+    let next_class_id = value::<NextClassId, _>("next class id")?.unwrap_or(1);
+    let next_entity_id = value::<NextEntityId, _>("next entity id")?.unwrap_or(1);
+
+    let mut snapshot = VersionedStoreData {
+        classes: vec![],
+        entities: vec![],
+        class_permissions: vec![],
+        entity_maintainers: vec![],
+        entity_co_maintainers: vec![],
+    };
+
+    for class_id in 1..next_class_id {
+        if let Some(class) = map_value::<ClassById, _, _>("class", &class_id)? {
+            snapshot.classes.push((class_id, class));
+        }
+        if let Some(class_permissions) = linked_map_value::<ClassPermissionsByClassId<Runtime>, _, _>(
+            "class permissions",
+            &class_id,
+        )? {
+            snapshot
+                .class_permissions
+                .push((class_id, class_permissions));
+        }
+    }
+
+    for entity_id in 1..next_entity_id {
+        if let Some(entity) = map_value::<EntityById, _, _>("entity", &entity_id)? {
+            snapshot.entities.push((entity_id, entity));
+        }
+        if let Some(maintainer) = linked_map_value::<EntityMaintainerByEntityId<Runtime>, _, _>(
+            "entity maintainer",
+            &entity_id,
+        )? {
+            snapshot.entity_maintainers.push((entity_id, maintainer));
+        }
+        if let Some(co_maintainers) = linked_map_value::<
+            EntityCoMaintainersByEntityId<Runtime>,
+            _,
+            _,
+        >("entity co-maintainers", &entity_id)?
+        {
+            snapshot
+                .entity_co_maintainers
+                .push((entity_id, co_maintainers));
+        }
+    }
+
+    Ok(snapshot)
+}
+
+/// Exports the versioned store from storage key/value pairs.
+fn export_versioned_store_from_pairs(
+    pairs: &[(Vec<u8>, Vec<u8>)],
+) -> Result<VersionedStoreData, String> {
+    let mut externalities = sr_io::TestExternalities::new(Default::default());
+    externalities.execute_with(|| {
+        for (key, value) in pairs.iter() {
+            sr_io::storage::set(key, value);
+        }
+        export_versioned_store()
+    })
+}
+
+fn main() -> Result<(), String> {
+    let opt = Opt::from_args();
+
+    let pairs = parse_state_dump(&opt.state_dump_path)?;
+
+    let snapshot = export_versioned_store_from_pairs(&pairs)?;
+
+    println!(
+        "Exported {} classes, {} entities and permissions of {} classes",
+        snapshot.classes.len(),
+        snapshot.entities.len(),
+        snapshot.class_permissions.len()
+    );
+
+    let json = serde_json::to_string_pretty(&snapshot).map_err(|err| err.to_string())?;
+
+    fs::write(opt.output_path, json).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use joystream_node::versioned_store_config::{
+        versioned_store_config_from, versioned_store_permissions_config_from,
+    };
+    use node_runtime::{
+        versioned_store::{
+            Class, ClassPropertyValue, ClassSchema, Entity, Property, PropertyType, PropertyValue,
+        },
+        versioned_store_permissions::{ClassPermissionsType, CredentialSet},
+    };
+    use srml_support::storage::generator::StorageMap;
+
+    fn class(id: u64) -> Class {
+        Class {
+            id,
+            properties: vec![Property {
+                prop_type: PropertyType::Text(20),
+                required: true,
+                name: b"name".to_vec(),
+                description: b"description".to_vec(),
+                ..Default::default()
+            }],
+            schemas: vec![ClassSchema {
+                properties: vec![0],
+                is_active: true,
+            }],
+            name: b"class".to_vec(),
+            description: b"class description".to_vec(),
+        }
+    }
+
+    fn entity(id: u64, class_id: u64) -> Entity {
+        Entity {
+            id,
+            class_id,
+            in_class_schema_indexes: vec![0],
+            values: vec![ClassPropertyValue {
+                in_class_index: 0,
+                value: PropertyValue::Text(b"value".to_vec()),
+            }],
+        }
+    }
+
+    fn snapshot() -> VersionedStoreData {
+        let mut class_permissions = ClassPermissionsType::<Runtime>::default();
+        class_permissions.admins = CredentialSet::from(vec![1]);
+
+        VersionedStoreData {
+            classes: vec![(4, class(4)), (1, class(1))],
+            entities: vec![(7, entity(7, 4)), (2, entity(2, 1)), (3, entity(3, 1))],
+            class_permissions: vec![(4, class_permissions.clone()), (1, class_permissions)],
+            entity_maintainers: vec![(7, 2), (2, 1)],
+            entity_co_maintainers: vec![(7, CredentialSet::from(vec![3, 4]))],
+        }
+    }
+
+    fn sorted_by_id(mut data: VersionedStoreData) -> VersionedStoreData {
+        data.classes.sort_by_key(|(id, _)| *id);
+        data.entities.sort_by_key(|(id, _)| *id);
+        data.class_permissions.sort_by_key(|(id, _)| *id);
+        data.entity_maintainers.sort_by_key(|(id, _)| *id);
+        data.entity_co_maintainers.sort_by_key(|(id, _)| *id);
+        data
+    }
+
+    fn genesis_pairs(data: VersionedStoreData) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let mut storage = Default::default();
+
+        versioned_store_config_from(data.clone())
+            .assimilate_storage(&mut storage)
+            .unwrap();
+        versioned_store_permissions_config_from(data)
+            .assimilate_storage(&mut storage)
+            .unwrap();
+
+        storage.0.into_iter().collect()
+    }
+
+    #[test]
+    fn export_round_trips_through_genesis() {
+        let data = snapshot();
+
+        let exported = export_versioned_store_from_pairs(&genesis_pairs(data.clone())).unwrap();
+
+        assert_eq!(exported, sorted_by_id(data));
+
+        let json = serde_json::to_string_pretty(&exported).unwrap();
+        let reloaded: VersionedStoreData = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(reloaded, exported);
+
+        // Exporting the genesis built from the export gives the same snapshot again.
+        assert_eq!(
+            export_versioned_store_from_pairs(&genesis_pairs(reloaded)).unwrap(),
+            exported
+        );
+    }
+
+    #[test]
+    fn export_fails_on_values_stored_in_another_layout() {
+        let mut pairs = genesis_pairs(snapshot());
+
+        let class_key = ClassById::storage_map_final_key(&4).as_ref().to_vec();
+        let (_, class_value) = pairs.iter_mut().find(|(key, _)| *key == class_key).unwrap();
+        class_value.push(0);
+
+        let err = export_versioned_store_from_pairs(&pairs).unwrap_err();
+
+        assert!(err.starts_with("Failed to decode class 4"));
+    }
+}